@@ -0,0 +1,724 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::core::errors::FrameworkError;
+use crate::core::paths::{absolute, config_dir, find_ancestor, state_dir};
+use crate::core::trust::{TRUST_FILE, TrustDatabase};
+use crate::core::types::{EnvPolicy, Environment};
+
+// CONFIG_FILE
+//
+// The project configuration, found by walking up from the file at hand.
+pub(crate) const CONFIG_FILE: &str = ".examen.toml";
+
+// USER_CONFIG_FILE
+//
+// The user configuration, relative to the examen configuration directory.
+pub(crate) const USER_CONFIG_FILE: &str = "config.toml";
+
+// Config
+//
+// Per repository settings, e.g.
+//
+// cwd = "services/api"
+// disabled_frameworks = ["Delve"]
+// env_files = [".env"]
+// unset_env = ["GOFLAGS"]
+// inherit_env = true
+//
+// [env]
+// GOFLAGS = "-count=1"
+//
+// [go]
+// flags = ["-race", "-count=1"]
+// build_tags = ["integration"]
+//
+// [rust]
+// nextest_profile = "ci"
+//
+// [executor]
+// backend = "tmux"
+// target = "tests"
+// policy = "queue"
+// max_jobs = 2
+//
+// The project file is merged over the user file: `cwd` and `env` entries
+// of the project win, lists are concatenated. A variable is set or unset
+// as the last file naming it in `env` or `unset_env` says, `env` winning
+// within a file. `executor.policy` and
+// `executor.max_jobs` govern every run of the editor and belong to the
+// user file only. Variables of `env_files`,
+// read in order, come before those of `env`. A project file is ignored
+// until its contents and those of its `env_files` are trusted, see
+// `TrustDatabase`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    // working directory of the commands, relative to the file declaring it
+    #[serde(default)]
+    pub(crate) cwd: Option<PathBuf>,
+    #[serde(default)]
+    pub(crate) env: BTreeMap<String, String>,
+    // dotenv files, relative to the file declaring them
+    #[serde(default)]
+    pub(crate) env_files: Vec<PathBuf>,
+    // variables removed from the inherited environment
+    #[serde(default)]
+    pub(crate) unset_env: Vec<String>,
+    // whether commands start from the editor's environment, true by default
+    #[serde(default)]
+    pub(crate) inherit_env: Option<bool>,
+    // names of the frameworks never detected, e.g. "Delve"
+    #[serde(default)]
+    pub(crate) disabled_frameworks: Vec<String>,
+    #[serde(default)]
+    pub(crate) go: GoConfig,
+    #[serde(default)]
+    pub(crate) rust: RustConfig,
+    #[serde(default)]
+    pub(crate) executor: ExecutorConfig,
+    // files the configuration was read from, user file first
+    #[serde(skip)]
+    pub(crate) sources: Vec<PathBuf>,
+    // project files ignored for not being trusted
+    #[serde(skip)]
+    pub(crate) untrusted: Vec<FrameworkError>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct GoConfig {
+    // extra `go test` flags, e.g. "-race"
+    #[serde(default)]
+    pub(crate) flags: Vec<String>,
+    // build tags always included
+    #[serde(default)]
+    pub(crate) build_tags: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RustConfig {
+    // profile of `.config/nextest.toml` tests run with, nextest's `default`
+    // when unset
+    #[serde(default)]
+    pub(crate) nextest_profile: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExecutorConfig {
+    // where commands run, the editor's own processes by default
+    #[serde(default)]
+    pub(crate) backend: Option<Backend>,
+    // tmux window commands are sent to, `[session:]window`
+    #[serde(default)]
+    pub(crate) target: Option<String>,
+    // what happens to a run started while another one tests the same
+    // runnable or package, `replace` by default
+    #[serde(default)]
+    pub(crate) policy: Option<ConcurrencyPolicy>,
+    // runs allowed at once across the editor, later ones wait
+    #[serde(default)]
+    pub(crate) max_jobs: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Backend {
+    Process,
+    Tmux,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ConcurrencyPolicy {
+    // cancel the runs in the way, then start
+    #[default]
+    Replace,
+    // wait for the runs in the way to finish
+    Queue,
+    // refuse to start
+    Reject,
+    // start anyway
+    Parallel,
+}
+
+// ConfigSource
+//
+// The files providers read the configuration of a file from when building
+// its commands: the user configuration and the trust database, read anew
+// every time so trust granted since applies. The default source reads no
+// file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct ConfigSource {
+    pub(crate) user: Option<PathBuf>,
+    // nothing is trusted when unset
+    pub(crate) trust: Option<PathBuf>,
+}
+
+impl ConfigSource {
+    // user
+    //
+    // The files of the user, see `config_dir` and `state_dir`.
+    pub(crate) fn user() -> Self {
+        Self {
+            user: config_dir().map(|dir| dir.join(USER_CONFIG_FILE)),
+            trust: state_dir().map(|dir| dir.join(TRUST_FILE)),
+        }
+    }
+
+    // load
+    //
+    // The configuration applying to `filepath`, see `Config::load`.
+    pub(crate) fn load(&self, filepath: &Path) -> Result<Config, FrameworkError> {
+        let trust = match &self.trust {
+            Some(path) => TrustDatabase::load(path)?,
+            None => TrustDatabase::default(),
+        };
+        Config::load(filepath, self.user.as_deref(), &trust)
+    }
+}
+
+impl Config {
+    // discover
+    //
+    // The configuration applying to `filepath`: the user configuration merged
+    // with the nearest trusted `.examen.toml` above the file, if any.
+    pub(crate) fn discover(filepath: &Path, trust: &TrustDatabase) -> Result<Self, FrameworkError> {
+        let user = config_dir().map(|dir| dir.join(USER_CONFIG_FILE));
+        Self::load(filepath, user.as_deref(), trust)
+    }
+
+    // user
+    //
+    // The configuration of the user alone, for settings shared by every
+    // project, e.g. how many jobs run at once.
+    pub(crate) fn user() -> Result<Self, FrameworkError> {
+        let user = config_dir().map(|dir| dir.join(USER_CONFIG_FILE));
+        Self::read_user(user.as_deref())
+    }
+
+    pub(crate) fn load(
+        filepath: &Path,
+        user: Option<&Path>,
+        trust: &TrustDatabase,
+    ) -> Result<Self, FrameworkError> {
+        let mut res = Self::read_user(user)?;
+        if let Some(project) = Self::project_file(filepath) {
+            let contents = Self::read(&project)?;
+            let parsed = trust
+                .check(&project, &contents)
+                .and_then(|()| Self::parse(&contents, &project, Some(trust)));
+            match parsed {
+                Ok(config) => res = res.merge(config),
+                Err(e @ FrameworkError::UntrustedError(_)) => res.untrusted.push(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(res)
+    }
+
+    // project_file
+    //
+    // The nearest `.examen.toml`, starting from the directory of `filepath`.
+    pub(crate) fn project_file(filepath: &Path) -> Option<PathBuf> {
+        let filepath = absolute(filepath);
+        let start = filepath.parent().unwrap_or(&filepath);
+        find_ancestor(start, |dir| dir.join(CONFIG_FILE).is_file()).map(|dir| dir.join(CONFIG_FILE))
+    }
+
+    fn read_user(user: Option<&Path>) -> Result<Self, FrameworkError> {
+        match user.filter(|path| path.is_file()) {
+            Some(path) => Self::parse(&Self::read(path)?, path, None),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn read(path: &Path) -> Result<String, FrameworkError> {
+        fs::read_to_string(path).map_err(|e| error(path, e.to_string()))
+    }
+
+    // parse
+    //
+    // Parses and validates the configuration read from `path`, resolving
+    // `cwd` against its directory. Project files come with the trust
+    // database their `env_files` are checked against. Only they may use a
+    // relative `cwd`, the user file applies to every repository.
+    pub(crate) fn parse(
+        contents: &str,
+        path: &Path,
+        trust: Option<&TrustDatabase>,
+    ) -> Result<Self, FrameworkError> {
+        let mut res: Self = toml::from_str(contents).map_err(|e| error(path, e.to_string()))?;
+        res.validate().map_err(|details| error(path, details))?;
+        if trust.is_some() {
+            for (name, set) in [
+                ("policy", res.executor.policy.is_some()),
+                ("max_jobs", res.executor.max_jobs.is_some()),
+            ] {
+                if set {
+                    return Err(error(
+                        path,
+                        format!(
+                            "`executor.{}` applies to every project, set it in the user config",
+                            name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(cwd) = res.cwd.take() {
+            let cwd = match (cwd.is_absolute(), trust.is_some()) {
+                (true, _) => cwd,
+                (false, true) => absolute(&path.parent().unwrap_or(Path::new(".")).join(cwd)),
+                (false, false) => {
+                    return Err(error(
+                        path,
+                        format!(
+                            "`cwd` `{}` must be absolute in the user config",
+                            cwd.display()
+                        ),
+                    ));
+                }
+            };
+            if !cwd.is_dir() {
+                return Err(error(
+                    path,
+                    format!("`cwd` `{}` is not a directory", cwd.display()),
+                ));
+            }
+            res.cwd = Some(cwd);
+        }
+
+        let mut env = BTreeMap::new();
+        for (i, file) in std::mem::take(&mut res.env_files).into_iter().enumerate() {
+            let file = absolute(&path.parent().unwrap_or(Path::new(".")).join(file));
+            let contents = fs::read_to_string(&file).map_err(|_| {
+                error(
+                    path,
+                    format!("`env_files[{}]` `{}` does not exist", i, file.display()),
+                )
+            })?;
+            // the variables end up in every command, like the config itself
+            if let Some(trust) = trust {
+                trust.check(&file, &contents)?;
+            }
+            env.extend(read_env_file(&contents).map_err(|details| {
+                error(
+                    path,
+                    format!("`env_files[{}]` `{}` {}", i, file.display(), details),
+                )
+            })?);
+            res.env_files.push(file);
+        }
+        env.extend(std::mem::take(&mut res.env));
+        res.env = env;
+        res.sources.push(path.to_path_buf());
+        Ok(res)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for name in self.env.keys() {
+            if name.is_empty() || name.contains(['=', '\0']) {
+                return Err(format!("invalid `env` name `{}`", name));
+            }
+        }
+        for name in self.unset_env.iter() {
+            if name.is_empty() || name.contains(['=', '\0']) {
+                return Err(format!("invalid `unset_env` name `{}`", name));
+            }
+        }
+        for (i, framework) in self.disabled_frameworks.iter().enumerate() {
+            if framework.trim().is_empty() {
+                return Err(format!("`disabled_frameworks[{}]` must not be empty", i));
+            }
+        }
+        for (i, flag) in self.go.flags.iter().enumerate() {
+            if !flag.starts_with('-') {
+                return Err(format!(
+                    "`go.flags[{}]` `{}` is not a flag, flags start with `-`",
+                    i, flag
+                ));
+            }
+            let name = flag
+                .trim_start_matches('-')
+                .split('=')
+                .next()
+                .unwrap_or_default();
+            match name {
+                "tags" => {
+                    return Err(format!(
+                        "`go.flags[{}]` sets `-tags`, list them in `go.build_tags` instead",
+                        i
+                    ));
+                }
+                "run" => {
+                    return Err(format!(
+                        "`go.flags[{}]` sets `-run`, the test pattern comes from the runnable",
+                        i
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if self
+            .rust
+            .nextest_profile
+            .as_ref()
+            .is_some_and(|p| p.trim().is_empty())
+        {
+            return Err("`rust.nextest_profile` must not be empty".to_string());
+        }
+        if let Some(target) = &self.executor.target {
+            if target.trim().is_empty() {
+                return Err("`executor.target` must not be empty".to_string());
+            }
+            if target.contains('.') || target.matches(':').count() > 1 {
+                return Err(format!(
+                    "`executor.target` `{}` must be a tmux `[session:]window`",
+                    target
+                ));
+            }
+        }
+        if self.executor.max_jobs == Some(0) {
+            return Err("`executor.max_jobs` must be at least 1".to_string());
+        }
+        for (i, tag) in self.go.build_tags.iter().enumerate() {
+            if tag.is_empty() || tag.contains(|c: char| c == ',' || c.is_whitespace()) {
+                return Err(format!(
+                    "`go.build_tags[{}]` `{}` is not a build tag",
+                    i, tag
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // merge
+    //
+    // Applies `other` over the configuration, `other` winning on conflicts.
+    pub(crate) fn merge(mut self, other: Self) -> Self {
+        self.cwd = other.cwd.or(self.cwd);
+        // a variable `other` unsets is no longer set, and the reverse
+        self.env.retain(|name, _| !other.unset_env.contains(name));
+        self.unset_env.retain(|name| !other.env.contains_key(name));
+        self.env.extend(other.env);
+        self.env_files.extend(other.env_files);
+        extend_unique(&mut self.unset_env, other.unset_env);
+        self.inherit_env = other.inherit_env.or(self.inherit_env);
+        extend_unique(&mut self.disabled_frameworks, other.disabled_frameworks);
+        self.go.flags.extend(other.go.flags);
+        extend_unique(&mut self.go.build_tags, other.go.build_tags);
+        self.rust.nextest_profile = other.rust.nextest_profile.or(self.rust.nextest_profile);
+        self.executor.backend = other.executor.backend.or(self.executor.backend);
+        self.executor.target = other.executor.target.or(self.executor.target);
+        self.sources.extend(other.sources);
+        self.untrusted.extend(other.untrusted);
+        self
+    }
+
+    // environment
+    //
+    // The environment commands run with.
+    pub(crate) fn environment(&self) -> Environment {
+        let mut res = Environment::default();
+        if self.inherit_env == Some(false) {
+            res.policy = EnvPolicy::Clear;
+        }
+        for name in self.unset_env.iter() {
+            res.unset(name);
+        }
+        for (name, value) in self.env.iter() {
+            res.set(name, value);
+        }
+        res
+    }
+
+    pub(crate) fn is_disabled(&self, framework: &str) -> bool {
+        self.disabled_frameworks.iter().any(|f| f == framework)
+    }
+}
+
+fn extend_unique(values: &mut Vec<String>, other: Vec<String>) {
+    for value in other {
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+}
+
+// read_env_file
+//
+// Variables of a dotenv file, `KEY=value` lines with optional quotes and
+// `export` prefixes.
+fn read_env_file(contents: &str) -> Result<Vec<(String, String)>, String> {
+    dotenvy::from_read_iter(contents.as_bytes())
+        .collect::<Result<Vec<(String, String)>, dotenvy::Error>>()
+        .map_err(|e| format!("is not a valid dotenv file: {}", e))
+}
+
+fn error(path: &Path, details: String) -> FrameworkError {
+    FrameworkError::ConfigError(format!("{}: {}", path.display(), details))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::Config;
+    use crate::core::errors::FrameworkError;
+    use crate::core::trust::TrustDatabase;
+    use crate::core::types::EnvPolicy;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(format!(
+            "{}/lib/fixtures/config/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        ))
+    }
+
+    #[gtest]
+    fn merges_project_over_user_config() {
+        // arrange
+        let filepath = fixture("project/pkg/app/app_test.go");
+        let user = fixture("user/config.toml");
+        let mut trust = TrustDatabase::default();
+        trust.allow(&fixture("project/.examen.toml")).unwrap();
+        trust.allow(&fixture("project/.env")).unwrap();
+
+        // act
+        let res = Config::load(&filepath, Some(&user), &trust).unwrap();
+
+        // assert
+        assert_that!(res.cwd, some(eq(&fixture("project/pkg"))));
+        assert_that!(
+            res.env.get("GOFLAGS").map(String::as_str),
+            some(eq("-count=1"))
+        );
+        assert_that!(res.env.get("EDITOR").map(String::as_str), some(eq("nvim")));
+        assert_that!(
+            res.go.flags,
+            container_eq(vec!["-v".to_string(), "-race".to_string()])
+        );
+        assert_that!(
+            res.go.build_tags,
+            container_eq(vec!["unit".to_string(), "integration".to_string()])
+        );
+        assert_that!(
+            res.env.get("DATABASE_URL").map(String::as_str),
+            some(eq("postgres://localhost:5432/app?sslmode=disable"))
+        );
+        assert_that!(res.rust.nextest_profile.as_deref(), some(eq("ci")));
+        assert_that!(res.is_disabled("Delve"), eq(true));
+        assert_that!(
+            res.sources,
+            container_eq(vec![user, fixture("project/.examen.toml")])
+        );
+    }
+
+    #[gtest]
+    fn environment_applies_env_policy() {
+        // arrange
+        let path = fixture("project/.examen.toml");
+        let config = Config::parse(
+            "inherit_env = false\nunset_env = [\"GOPATH\"]\n[env]\nCGO_ENABLED = \"0\"",
+            &path,
+            Some(&TrustDatabase::default()),
+        )
+        .unwrap();
+
+        // act
+        let res = config.environment();
+
+        // assert
+        assert_that!(res.policy, eq(&EnvPolicy::Clear));
+        assert_that!(res.unset.contains("GOPATH"), eq(true));
+        assert_that!(
+            res.set.get("CGO_ENABLED").map(String::as_str),
+            some(eq("0"))
+        );
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("[env]\nGOPATH = \"/go\"", "unset_env = [\"GOPATH\"]", None)]
+    #[case("unset_env = [\"GOPATH\"]", "[env]\nGOPATH = \"/go\"", Some("/go"))]
+    fn environment_follows_the_last_file_naming_a_variable(
+        #[case] user: &str,
+        #[case] project: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        // arrange
+        let user = Config::parse(user, &fixture("user/config.toml"), None).unwrap();
+        let project = Config::parse(
+            project,
+            &fixture("project/.examen.toml"),
+            Some(&TrustDatabase::default()),
+        )
+        .unwrap();
+
+        // act
+        let res = user.merge(project).environment();
+
+        // assert
+        assert_that!(res.set.get("GOPATH").map(String::as_str), eq(expected));
+        assert_that!(res.unset.contains("GOPATH"), eq(expected.is_none()));
+    }
+
+    #[gtest]
+    fn ignores_untrusted_project_config() {
+        // arrange
+        let filepath = fixture("project/pkg/app/app_test.go");
+        let user = fixture("user/config.toml");
+
+        // act
+        let res = Config::load(&filepath, Some(&user), &TrustDatabase::default()).unwrap();
+
+        // assert
+        assert_that!(res.cwd, none());
+        assert_that!(res.go.flags, container_eq(vec!["-v".to_string()]));
+        assert_that!(res.sources, container_eq(vec![user]));
+        assert_that!(
+            res.untrusted,
+            elements_are![matches_pattern!(FrameworkError::UntrustedError(
+                contains_substring(".examen.toml is not trusted")
+            ))]
+        );
+    }
+
+    #[gtest]
+    fn ignores_project_config_once_env_file_changes() {
+        // arrange
+        let dir = std::env::temp_dir().join(format!("examen-config-env-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(".examen.toml"), "env_files = [\".env\"]\n").unwrap();
+        fs::write(dir.join(".env"), "GOFLAGS=-count=1\n").unwrap();
+        let mut trust = TrustDatabase::default();
+        trust.allow(&dir.join(".examen.toml")).unwrap();
+        trust.allow(&dir.join(".env")).unwrap();
+        let filepath = dir.join("app_test.go");
+        let before = Config::load(&filepath, None, &trust).unwrap();
+
+        // act
+        fs::write(dir.join(".env"), "GOFLAGS=-toolexec=/tmp/evil\n").unwrap();
+        let res = Config::load(&filepath, None, &trust).unwrap();
+
+        // assert
+        assert_that!(
+            before.env.get("GOFLAGS").map(String::as_str),
+            some(eq("-count=1"))
+        );
+        assert_that!(res.env, is_empty());
+        assert_that!(
+            res.untrusted,
+            elements_are![matches_pattern!(FrameworkError::UntrustedError(
+                contains_substring(".env changed since it was trusted")
+            ))]
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[gtest]
+    fn defaults_without_config_files() {
+        let res = Config::load(Path::new("/"), None, &TrustDatabase::default());
+
+        assert_that!(res, ok(eq(&Config::default())));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("flag = true", "unknown field `flag`")]
+    #[case("[env]\n\"A=B\" = \"c\"", "invalid `env` name `A=B`")]
+    #[case(
+        "disabled_frameworks = [\" \"]",
+        "`disabled_frameworks[0]` must not be empty"
+    )]
+    #[case(
+        "[go]\nflags = [\"-v\", \"race\"]",
+        "`go.flags[1]` `race` is not a flag, flags start with `-`"
+    )]
+    #[case(
+        "[go]\nflags = [\"-tags=e2e\"]",
+        "`go.flags[0]` sets `-tags`, list them in `go.build_tags` instead"
+    )]
+    #[case(
+        "[go]\nflags = [\"-run\", \"TestA\"]",
+        "`go.flags[0]` sets `-run`, the test pattern comes from the runnable"
+    )]
+    #[case(
+        "[go]\nbuild_tags = [\"a,b\"]",
+        "`go.build_tags[0]` `a,b` is not a build tag"
+    )]
+    #[case("cwd = \"missing\"", "is not a directory")]
+    #[case("unset_env = [\"\"]", "invalid `unset_env` name ``")]
+    #[case(
+        "[executor]\nbackend = \"screen\"",
+        "unknown variant `screen`, expected `process` or `tmux`"
+    )]
+    #[case(
+        "[executor]\ntarget = \"work:tests.1\"",
+        "`executor.target` `work:tests.1` must be a tmux `[session:]window`"
+    )]
+    #[case(
+        "[executor]\npolicy = \"kill\"",
+        "unknown variant `kill`, expected one of `replace`, `queue`, `reject`, `parallel`"
+    )]
+    #[case(
+        "[rust]\nnextest_profile = \"\"",
+        "`rust.nextest_profile` must not be empty"
+    )]
+    #[case("[executor]\nmax_jobs = 0", "`executor.max_jobs` must be at least 1")]
+    #[case("env_files = [\"missing.env\"]", "`env_files[0]`")]
+    #[case(
+        "[executor]\npolicy = \"queue\"",
+        "`executor.policy` applies to every project, set it in the user config"
+    )]
+    #[case(
+        "[executor]\nmax_jobs = 4",
+        "`executor.max_jobs` applies to every project, set it in the user config"
+    )]
+    fn rejects_invalid_config(#[case] contents: &str, #[case] expected: &str) {
+        // arrange
+        let path = fixture("project/.examen.toml");
+
+        // act
+        let res = Config::parse(contents, &path, Some(&TrustDatabase::default()));
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::ConfigError(all!(
+                starts_with(path.display().to_string()),
+                contains_substring(expected)
+            ))))
+        );
+    }
+
+    #[gtest]
+    fn rejects_relative_cwd_in_user_config() {
+        // arrange
+        let path = fixture("user/config.toml");
+
+        // act
+        let res = Config::parse("cwd = \"pkg\"", &path, None);
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::ConfigError(
+                contains_substring("`cwd` `pkg` must be absolute in the user config")
+            )))
+        );
+    }
+}
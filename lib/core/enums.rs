@@ -11,6 +11,18 @@ pub enum Language {
     Rust,
     #[serde(rename = "python")]
     Python,
+    #[serde(rename = "javascript")]
+    JavaScript,
+    #[serde(rename = "typescript")]
+    TypeScript,
+    #[serde(rename = "lua")]
+    Lua,
+    #[serde(rename = "cpp")]
+    Cpp,
+    #[serde(rename = "java")]
+    Java,
+    #[serde(rename = "ruby")]
+    Ruby,
 }
 
 impl Language {
@@ -19,6 +31,12 @@ impl Language {
             Language::Golang => vec!["go", "golang"],
             Language::Rust => vec!["rust", "rs"],
             Language::Python => vec!["python", "py"],
+            Language::JavaScript => vec!["javascript", "js", "jsx", "mjs", "cjs"],
+            Language::TypeScript => vec!["typescript", "ts", "tsx", "mts", "cts"],
+            Language::Lua => vec!["lua"],
+            Language::Cpp => vec!["cpp", "c++", "cc", "cxx"],
+            Language::Java => vec!["java"],
+            Language::Ruby => vec!["ruby", "rb"],
             _ => vec![],
         }
     }
@@ -32,12 +50,18 @@ impl FromStr for Language {
             "go" | "golang" => Ok(Language::Golang),
             "rust" | "rs" => Ok(Language::Rust),
             "python" | "py" => Ok(Language::Python),
+            "javascript" | "js" | "jsx" | "mjs" | "cjs" => Ok(Language::JavaScript),
+            "typescript" | "ts" | "tsx" | "mts" | "cts" => Ok(Language::TypeScript),
+            "lua" => Ok(Language::Lua),
+            "cpp" | "c++" | "cc" | "cxx" => Ok(Language::Cpp),
+            "java" => Ok(Language::Java),
+            "ruby" | "rb" => Ok(Language::Ruby),
             _ => Err(format!("Unknown language: {}", s)),
         }
     }
 }
 
-#[derive(PartialEq, Eq, Serialize, Deserialize, Clone, Hash)]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone, Hash)]
 pub enum Capability {
     #[serde(rename = "debug")]
     Debugger,
@@ -54,3 +78,10 @@ pub enum Search {
     // File - find all the tests in a file
     File,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-#[derive(PartialEq, Error, Debug)]
+#[derive(PartialEq, Error, Debug, Clone)]
 pub enum FrameworkError {
     #[error("parsing error. details = `{0}`")]
     ParsingError(String),
@@ -10,4 +10,25 @@ pub enum FrameworkError {
     UnknownError(String),
     #[error("precondition error. details = `{0}`")]
     PreconditionError(String),
+    #[error("query error. details = `{0}`")]
+    QueryError(String),
+    #[error("config error. details = `{0}`")]
+    ConfigError(String),
+    #[error("untrusted file. details = `{0}`")]
+    UntrustedError(String),
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(PartialEq, Error, Debug, Clone)]
+pub enum ExecutionError {
+    #[error("spawn error. details = `{0}`")]
+    SpawnError(String),
+    #[error("io error. details = `{0}`")]
+    IoError(String),
+    #[error("timeout error. details = `{0}`")]
+    TimeoutError(String),
+    #[error("cancelled. details = `{0}`")]
+    CancelledError(String),
+    #[error("rejected. details = `{0}`")]
+    RejectedError(String),
 }
@@ -0,0 +1,254 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::core::errors::FrameworkError;
+
+// git
+//
+// Runs git in `directory`, returning its standard output.
+fn git(directory: &Path, args: &[&str]) -> Result<String, FrameworkError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|e| FrameworkError::PreconditionError(format!("failed to run `git`: {}", e)))?;
+    if !output.status.success() {
+        return Err(FrameworkError::PreconditionError(format!(
+            "`git {}` failed in {}: {}",
+            args.join(" "),
+            directory.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+// toplevel
+//
+// The root of the working tree holding `directory`.
+pub(crate) fn toplevel(directory: &Path) -> Result<PathBuf, FrameworkError> {
+    Ok(PathBuf::from(
+        git(directory, &["rev-parse", "--show-toplevel"])?.trim(),
+    ))
+}
+
+// merge_base
+//
+// The commit the working tree is compared to: HEAD, or where the branch
+// forked from `base`, so changes landed on `base` since are left out.
+pub(crate) fn merge_base(directory: &Path, base: Option<&str>) -> Result<String, FrameworkError> {
+    let res = match base {
+        None => git(directory, &["rev-parse", "--verify", "HEAD"])?,
+        Some(base) => git(directory, &["merge-base", base, "HEAD"])?,
+    };
+    Ok(res.trim().to_string())
+}
+
+// changed_files
+//
+// The files of the working tree differing from `merge_base`, staged or not,
+// along with the untracked ones git does not ignore. Both sides of a rename
+// are listed, deleted files included.
+pub(crate) fn changed_files(
+    directory: &Path,
+    base: Option<&str>,
+) -> Result<Vec<PathBuf>, FrameworkError> {
+    let top = toplevel(directory)?;
+    let commit = merge_base(&top, base)?;
+    let diff = git(
+        &top,
+        &["diff", "--name-only", "--no-renames", "-z", &commit, "--"],
+    )?;
+    let untracked = git(&top, &["ls-files", "--others", "--exclude-standard", "-z"])?;
+    let mut res: Vec<PathBuf> = diff
+        .split('\0')
+        .chain(untracked.split('\0'))
+        .filter(|path| !path.is_empty())
+        .map(|path| top.join(path))
+        .collect();
+    res.sort();
+    res.dedup();
+    Ok(res)
+}
+
+// committed_files
+//
+// The files committed between `from` and HEAD, relative to the root of the
+// working tree. Both sides of a rename are listed, deleted files included.
+pub(crate) fn committed_files(directory: &Path, from: &str) -> Result<Vec<String>, FrameworkError> {
+    let diff = git(
+        directory,
+        &[
+            "diff",
+            "--name-only",
+            "--no-renames",
+            "-z",
+            from,
+            "HEAD",
+            "--",
+        ],
+    )?;
+    Ok(diff
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(|path| path.to_string())
+        .collect())
+}
+
+// show
+//
+// The contents of `path`, relative to the root of the working tree, at
+// `commit`, none when the file did not exist.
+pub(crate) fn show(
+    directory: &Path,
+    commit: &str,
+    path: &str,
+) -> Result<Option<String>, FrameworkError> {
+    if git(directory, &["ls-tree", "--name-only", commit, "--", path])?.is_empty() {
+        return Ok(None);
+    }
+    git(directory, &["show", &format!("{}:{}", commit, path)]).map(Some)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    use googletest::prelude::*;
+
+    use super::{changed_files, committed_files, merge_base, show};
+    use crate::core::errors::FrameworkError;
+
+    // repository
+    //
+    // A fresh repository in a temporary directory, `files` committed on
+    // `main`.
+    pub(crate) fn repository(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let res = std::env::temp_dir().join(format!("examen-git-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&res);
+        fs::create_dir_all(&res).unwrap();
+        run(&res, &["init", "-q", "-b", "main"]);
+        for (path, contents) in files {
+            write(&res, path, contents);
+        }
+        commit(&res, "initial");
+        res
+    }
+
+    pub(crate) fn write(repository: &Path, path: &str, contents: &str) {
+        let path = repository.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    pub(crate) fn commit(repository: &Path, message: &str) {
+        run(repository, &["add", "-A"]);
+        run(
+            repository,
+            &[
+                "-c",
+                "user.name=examen",
+                "-c",
+                "user.email=examen@localhost",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+        );
+    }
+
+    pub(crate) fn run(repository: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(repository)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[gtest]
+    fn lists_changed_and_untracked_files() {
+        // arrange
+        let repository = repository(
+            "changed",
+            &[
+                ("a.go", "package a\n"),
+                ("b/b.go", "package b\n"),
+                (".gitignore", "*.log\n"),
+            ],
+        );
+        write(&repository, "a.go", "package a\n// changed\n");
+        fs::remove_file(repository.join("b/b.go")).unwrap();
+        write(&repository, "c/c.go", "package c\n");
+        write(&repository, "debug.log", "ignored\n");
+
+        // act
+        let res = changed_files(&repository.join("c"), None);
+
+        // assert
+        let res: Vec<String> = res
+            .unwrap()
+            .iter()
+            .map(|p| p.strip_prefix(&repository).unwrap().display().to_string())
+            .collect();
+        assert_that!(res, elements_are![eq("a.go"), eq("b/b.go"), eq("c/c.go")]);
+        let _ = fs::remove_dir_all(&repository);
+    }
+
+    #[gtest]
+    fn compares_to_fork_point_of_base() {
+        // arrange
+        let repository = repository("base", &[("a.go", "package a\n")]);
+        run(&repository, &["checkout", "-q", "-b", "feature"]);
+        write(&repository, "feature.go", "package a\n");
+        commit(&repository, "feature");
+        run(&repository, &["checkout", "-q", "main"]);
+        write(&repository, "main.go", "package a\n");
+        commit(&repository, "main");
+        run(&repository, &["checkout", "-q", "feature"]);
+
+        // act
+        let res = changed_files(&repository, Some("main"));
+
+        // assert
+        assert_that!(res, ok(elements_are![eq(&repository.join("feature.go"))]));
+        assert_that!(
+            merge_base(&repository, Some("missing")),
+            err(matches_pattern!(FrameworkError::PreconditionError(
+                contains_substring("`git merge-base missing HEAD` failed")
+            )))
+        );
+        let _ = fs::remove_dir_all(&repository);
+    }
+
+    #[gtest]
+    fn reads_committed_blobs() {
+        // arrange
+        let repository = repository("show", &[("a/a.go", "package a\n")]);
+        let base = merge_base(&repository, None).unwrap();
+        write(&repository, "a/a.go", "package a\n// changed\n");
+        write(&repository, "b.go", "package b\n");
+        commit(&repository, "change");
+        write(&repository, "c.go", "package c\n");
+
+        // act
+        let files = committed_files(&repository, &base);
+
+        // assert
+        assert_that!(files, ok(elements_are![eq("a/a.go"), eq("b.go")]));
+        assert_that!(
+            show(&repository, &base, "a/a.go"),
+            ok(some(eq("package a\n")))
+        );
+        assert_that!(
+            show(&repository, "HEAD", "a/a.go"),
+            ok(some(eq("package a\n// changed\n")))
+        );
+        assert_that!(show(&repository, &base, "b.go"), ok(none()));
+        let _ = fs::remove_dir_all(&repository);
+    }
+}
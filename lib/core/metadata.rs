@@ -4,13 +4,147 @@ pub(crate) enum RunnableMeta {
         package: String,
         build_tags: Vec<String>,
     },
+    Rust {
+        package: String,
+        target: CargoTarget,
+        exact: bool,
+    },
+    Python {
+        root: String,
+    },
+    JavaScript {
+        root: String,
+        runner: JsRunner,
+        exact: bool,
+    },
+    Lua {
+        root: String,
+        runner: LuaRunner,
+        exact: bool,
+    },
+    Cpp {
+        executable: String,
+        filter: String,
+    },
+    Java {
+        root: String,
+        module: String,
+        build: JavaBuild,
+        display_name: Option<String>,
+    },
+    Ruby {
+        root: String,
+        bundler: bool,
+        line: Option<usize>,
+    },
+    Declarative {
+        root: String,
+        line: Option<usize>,
+        exact: bool,
+    },
+    Scripted {
+        data: serde_json::Value,
+    },
+}
+
+// CargoTarget
+//
+// The cargo compilation target a rust test belongs to, which maps to the
+// `--lib`, `--bin <name>` and `--test <name>` target selection flags.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CargoTarget {
+    Lib,
+    Bin(String),
+    Test(String),
+}
+
+// JsRunner
+//
+// The test runner a javascript project depends on. Both share the jest
+// `describe`/`it` api, only the command line differs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum JsRunner {
+    Jest,
+    Vitest,
+}
+
+// LuaRunner
+//
+// How a lua spec runs: through the busted cli, or inside neovim with
+// plenary.nvim's busted implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum LuaRunner {
+    Busted,
+    Plenary,
+}
+
+// JavaBuild
+//
+// The build tool of a java project, which runs its tests through surefire
+// or the gradle `test` task.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum JavaBuild {
+    Maven,
+    Gradle,
 }
 
 impl RunnableMeta {
+    pub(crate) fn default_cpp() -> Self {
+        RunnableMeta::Cpp {
+            executable: String::new(),
+            filter: String::new(),
+        }
+    }
+
+    pub(crate) fn default_declarative() -> Self {
+        RunnableMeta::Declarative {
+            root: String::new(),
+            line: None,
+            exact: true,
+        }
+    }
+
     pub(crate) fn default_golang() -> Self {
         RunnableMeta::Golang {
             package: String::new(),
             build_tags: Vec::new(),
         }
     }
+
+    pub(crate) fn default_java() -> Self {
+        RunnableMeta::Java {
+            root: String::new(),
+            module: String::new(),
+            build: JavaBuild::Maven,
+            display_name: None,
+        }
+    }
+
+    pub(crate) fn default_python() -> Self {
+        RunnableMeta::Python {
+            root: String::new(),
+        }
+    }
+
+    pub(crate) fn default_ruby() -> Self {
+        RunnableMeta::Ruby {
+            root: String::new(),
+            bundler: false,
+            line: None,
+        }
+    }
+
+    pub(crate) fn default_rust() -> Self {
+        RunnableMeta::Rust {
+            package: String::new(),
+            target: CargoTarget::Lib,
+            exact: true,
+        }
+    }
+
+    pub(crate) fn default_scripted() -> Self {
+        RunnableMeta::Scripted {
+            data: serde_json::Value::Null,
+        }
+    }
 }
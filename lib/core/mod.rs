@@ -1,6 +1,10 @@
+pub(crate) mod config;
 pub(crate) mod enums;
 pub mod errors;
+pub(crate) mod git;
 pub(crate) mod metadata;
+pub(crate) mod paths;
 pub(crate) mod registry;
 pub(crate) mod traits;
+pub(crate) mod trust;
 pub(crate) mod types;
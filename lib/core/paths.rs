@@ -0,0 +1,65 @@
+use std::path::{Component, Path, PathBuf};
+
+// absolute
+//
+// Resolves a path against the current working directory and removes `.`
+// and `..` components without touching the filesystem, so files that only
+// exist in an editor buffer can still be located.
+pub(crate) fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return normalize(path);
+    }
+    let cwd = std::env::current_dir().unwrap_or_default();
+    normalize(&cwd.join(path))
+}
+
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut res = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                res.pop();
+            }
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+// find_ancestor
+//
+// Returns the closest directory, starting from `start` itself, for which
+// `predicate` holds.
+pub(crate) fn find_ancestor(start: &Path, predicate: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| predicate(dir))
+        .map(|dir| dir.to_path_buf())
+}
+
+// config_dir
+//
+// The user configuration directory of examen, `$XDG_CONFIG_HOME/examen`
+// falling back to `~/.config/examen`.
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("examen"))
+}
+
+// state_dir
+//
+// The directory examen keeps its state in, `$XDG_STATE_HOME/examen`
+// falling back to `~/.local/state/examen`.
+pub(crate) fn state_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state"))
+        })?;
+    Some(base.join("examen"))
+}
@@ -24,6 +24,10 @@ impl FrameworkRegistry {
         self.providers.get(name).map(|p| p.create())
     }
 
+    pub fn get_providers(&self) -> Vec<&dyn FrameworkProvider> {
+        self.providers.values().map(|p| p.as_ref()).collect()
+    }
+
     pub fn get_provider_names(&self) -> Vec<String> {
         self.providers.keys().cloned().collect()
     }
@@ -35,7 +39,7 @@ impl FrameworkRegistry {
     ) -> Vec<Box<dyn Framework>> {
         self.providers
             .values()
-            .filter(|p| p.capability() == category && p.language() == lang)
+            .filter(|p| p.capability() == category && p.languages().contains(&lang))
             .map(|p| p.create())
             .collect()
     }
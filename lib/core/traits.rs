@@ -1,23 +1,109 @@
 use std::collections::HashSet;
 
-use super::types::Command;
+use super::types::{Buffer, Command, CursorPosition, Detection, TestResult};
 use super::{
-    enums::{Capability, Language},
+    enums::{Capability, Language, Search},
     errors::FrameworkError,
     types::{CapabilityDetails, Runnable, Target},
 };
 
 pub trait Framework {
     fn detect(&self, target: &Target) -> bool;
+    // detection
+    //
+    // `detect` with a confidence and a reason, ranking the frameworks that
+    // apply to the same file.
+    fn detection(&self, target: &Target) -> Detection {
+        if self.detect(target) {
+            Detection::matched(50, "detect matched the file")
+        } else {
+            Detection::rejected("detect rejected the file")
+        }
+    }
     fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError>;
     fn generate_command(&self, runnable: Runnable) -> Command;
+    // try_generate_command
+    //
+    // `generate_command` for frameworks able to fail building the command,
+    // the error reaching the caller instead of an empty command.
+    fn try_generate_command(&self, runnable: Runnable) -> Result<Command, FrameworkError> {
+        Ok(self.generate_command(runnable))
+    }
+    // try_generate_combined_command
+    //
+    // The command running several runnables of a search at once, e.g. the
+    // tests a file search found. Frameworks whose searches find several
+    // runnables override it.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        Err(FrameworkError::PreconditionError(format!(
+            "cannot run {} runnables at once",
+            runnables.len()
+        )))
+    }
+    // results
+    //
+    // Per-test results of the last run of the runnable, for runners writing
+    // a report once done. None by default.
+    fn results(&self, _runnable: &Runnable) -> Result<Vec<TestResult>, FrameworkError> {
+        Ok(vec![])
+    }
     fn capabilities(&self) -> HashSet<CapabilityDetails>;
     fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails>;
+    // all_runnables
+    //
+    // Every runnable of the file, the tests one after the other along with
+    // their subtests, since a file search covers the test at the cursor only.
+    // Searching line by line, frameworks able to list the tests of a file at
+    // once override it.
+    fn all_runnables(&self, filepath: &str, content: &str) -> Vec<Runnable> {
+        let search = |row: usize, search: Search| {
+            let mut target = Target::new(
+                Capability::TestRunner,
+                Buffer::new(content, filepath.to_string(), CursorPosition::new(row, 0)),
+            );
+            target.override_search_strategy(search);
+            self.runnables(&target).unwrap_or_default()
+        };
+
+        let mut res: Vec<Runnable> = vec![];
+        let mut row = 0;
+        while row < content.lines().count() {
+            let tests = search(row, Search::Method);
+            let Some(end) = tests.iter().map(|r| r.range.end.row).max() else {
+                row += 1;
+                continue;
+            };
+            for runnable in tests.into_iter().chain(search(row, Search::File)) {
+                if !res.iter().any(|r| r.name == runnable.name) {
+                    res.push(runnable);
+                }
+            }
+            row = row.max(end) + 1;
+        }
+        res
+    }
 }
 
 pub trait FrameworkProvider {
     fn create(&self) -> Box<dyn Framework>;
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
     fn language(&self) -> Language;
+    // languages
+    //
+    // Every language the framework handles, for frameworks such as jest that
+    // serve more than one.
+    fn languages(&self) -> Vec<Language> {
+        vec![self.language()]
+    }
     fn capability(&self) -> Capability;
+    // priority
+    //
+    // Rank of the framework among the ones detected for a file, higher
+    // first, before confidence is considered.
+    fn priority(&self) -> u8 {
+        0
+    }
 }
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::core::errors::FrameworkError;
+use crate::core::paths::{absolute, state_dir};
+
+// TRUST_FILE
+//
+// The trust database, relative to the examen state directory.
+pub(crate) const TRUST_FILE: &str = "trust";
+
+// TrustDatabase
+//
+// Project files able to run programs, `.examen.toml`, the dotenv files it
+// reads and the definitions of `.examen/frameworks`, are ignored until the
+// user trusts them, much like neovim's `:trust`. An entry records the
+// sha256 of the contents approved, so any later edit has to be approved
+// again. The file holds one `<sha256> <path>` line per trusted file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TrustDatabase {
+    // where entries are saved, nothing is saved when unset
+    path: Option<PathBuf>,
+    entries: BTreeMap<PathBuf, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustEntry {
+    pub path: PathBuf,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrustStatus {
+    Trusted,
+    // the file was never trusted
+    Untrusted,
+    // the file was trusted with different contents
+    Changed,
+}
+
+impl TrustDatabase {
+    // open
+    //
+    // The database of the user, empty when missing or unreadable.
+    pub fn open() -> Self {
+        match state_dir().map(|dir| dir.join(TRUST_FILE)) {
+            Some(path) => Self::load(&path).unwrap_or(Self {
+                path: Some(path),
+                entries: BTreeMap::new(),
+            }),
+            None => Self::default(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, FrameworkError> {
+        let mut entries = BTreeMap::new();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(error(path, e.to_string())),
+        };
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Some((hash, file)) = line.split_once(' ') else {
+                return Err(error(
+                    path,
+                    format!("line {} is not `<sha256> <path>`", i + 1),
+                ));
+            };
+            entries.insert(PathBuf::from(file), hash.to_string());
+        }
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            entries,
+        })
+    }
+
+    pub fn list(&self) -> Vec<TrustEntry> {
+        self.entries
+            .iter()
+            .map(|(path, hash)| TrustEntry {
+                path: path.clone(),
+                hash: hash.clone(),
+            })
+            .collect()
+    }
+
+    pub fn status(&self, file: &Path, contents: &str) -> TrustStatus {
+        match self.entries.get(&key(file)) {
+            Some(hash) if *hash == digest(contents) => TrustStatus::Trusted,
+            Some(_) => TrustStatus::Changed,
+            None => TrustStatus::Untrusted,
+        }
+    }
+
+    // check
+    //
+    // Fails with an `UntrustedError` naming the file unless these exact
+    // contents were trusted.
+    pub fn check(&self, file: &Path, contents: &str) -> Result<(), FrameworkError> {
+        let details = match self.status(file, contents) {
+            TrustStatus::Trusted => return Ok(()),
+            TrustStatus::Untrusted => "is not trusted, review it then allow it",
+            TrustStatus::Changed => "changed since it was trusted, review it then allow it again",
+        };
+        Err(FrameworkError::UntrustedError(format!(
+            "{} {}",
+            key(file).display(),
+            details
+        )))
+    }
+
+    // allow
+    //
+    // Trusts the current contents of the file.
+    pub fn allow(&mut self, file: &Path) -> Result<TrustEntry, FrameworkError> {
+        let contents = fs::read_to_string(file).map_err(|e| error(file, e.to_string()))?;
+        let entry = TrustEntry {
+            path: key(file),
+            hash: digest(&contents),
+        };
+        self.entries.insert(entry.path.clone(), entry.hash.clone());
+        self.save()?;
+        Ok(entry)
+    }
+
+    // revoke
+    //
+    // Forgets the file, returning whether it was trusted.
+    pub fn revoke(&mut self, file: &Path) -> Result<bool, FrameworkError> {
+        let res = self.entries.remove(&key(file)).is_some();
+        if res {
+            self.save()?;
+        }
+        Ok(res)
+    }
+
+    fn save(&self) -> Result<(), FrameworkError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| error(path, e.to_string()))?;
+        }
+        let contents: String = self
+            .entries
+            .iter()
+            .map(|(file, hash)| format!("{} {}\n", hash, file.display()))
+            .collect();
+        fs::write(path, contents).map_err(|e| error(path, e.to_string()))
+    }
+}
+
+// key
+//
+// Files are trusted by their resolved path, a symlink does not carry the
+// trust of its target.
+fn key(file: &Path) -> PathBuf {
+    fs::canonicalize(file).unwrap_or_else(|_| absolute(file))
+}
+
+fn digest(contents: &str) -> String {
+    format!("{:x}", Sha256::digest(contents.as_bytes()))
+}
+
+fn error(path: &Path, details: String) -> FrameworkError {
+    FrameworkError::UnknownError(format!("trust database {}: {}", path.display(), details))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use googletest::prelude::*;
+
+    use super::{TrustDatabase, TrustStatus};
+    use crate::core::errors::FrameworkError;
+
+    fn scratch(name: &str) -> PathBuf {
+        let res =
+            std::env::temp_dir().join(format!("examen-trust-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&res);
+        fs::create_dir_all(&res).unwrap();
+        res
+    }
+
+    #[gtest]
+    fn allows_exact_contents() {
+        // arrange
+        let dir = scratch("allow");
+        let config = dir.join(".examen.toml");
+        fs::write(&config, "[go]\nflags = [\"-race\"]\n").unwrap();
+        let mut database = TrustDatabase::load(&dir.join("trust")).unwrap();
+        let before = database.status(&config, "[go]\nflags = [\"-race\"]\n");
+
+        // act
+        database.allow(&config).unwrap();
+
+        // assert
+        let reloaded = TrustDatabase::load(&dir.join("trust")).unwrap();
+        assert_that!(before, eq(&TrustStatus::Untrusted));
+        assert_that!(
+            reloaded.status(&config, "[go]\nflags = [\"-race\"]\n"),
+            eq(&TrustStatus::Trusted)
+        );
+        assert_that!(
+            reloaded.status(&config, "[go]\nflags = [\"-exec=rm\"]\n"),
+            eq(&TrustStatus::Changed)
+        );
+        assert_that!(reloaded.list().len(), eq(1));
+    }
+
+    #[gtest]
+    fn revokes_trust() {
+        // arrange
+        let dir = scratch("revoke");
+        let config = dir.join(".examen.toml");
+        fs::write(&config, "cwd = \".\"\n").unwrap();
+        let mut database = TrustDatabase::load(&dir.join("trust")).unwrap();
+        database.allow(&config).unwrap();
+
+        // act
+        let res = database.revoke(&config);
+
+        // assert
+        assert_that!(res, ok(eq(&true)));
+        assert_that!(database.revoke(&config), ok(eq(&false)));
+        assert_that!(
+            TrustDatabase::load(&dir.join("trust")).unwrap().list(),
+            is_empty()
+        );
+        assert_that!(
+            database.check(&config, "cwd = \".\"\n"),
+            err(matches_pattern!(FrameworkError::UntrustedError(ends_with(
+                "is not trusted, review it then allow it"
+            ))))
+        );
+    }
+
+    #[gtest]
+    fn rejects_malformed_database() {
+        // arrange
+        let dir = scratch("malformed");
+        fs::write(dir.join("trust"), "deadbeef\n").unwrap();
+
+        // act
+        let res = TrustDatabase::load(&dir.join("trust"));
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::UnknownError(
+                contains_substring("line 1 is not `<sha256> <path>`")
+            )))
+        );
+    }
+}
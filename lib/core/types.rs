@@ -1,10 +1,12 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Range;
+use std::path::PathBuf;
 
 use tree_sitter::Point;
 
-use self::enums::{Capability, Search};
+use self::enums::{Capability, Search, TestStatus};
 
-use super::{enums, metadata::RunnableMeta};
+use super::{config::Config, enums, metadata::RunnableMeta};
 
 #[derive(Clone, Debug)]
 pub struct Runnable {
@@ -14,9 +16,107 @@ pub struct Runnable {
     pub meta: RunnableMeta,
 }
 
+impl Runnable {
+    // id
+    //
+    // Identifies the runnable across searches, as long as the file and the
+    // test keep their names.
+    pub fn id(&self) -> String {
+        format!("{}::{}", self.filepath, self.name)
+    }
+
+    // combined
+    //
+    // The runnable several runnables of a file run as: unnamed, standing
+    // for the file, spanning them all with the meta of the first one.
+    pub fn combined(runnables: &[Runnable]) -> Option<Runnable> {
+        let first = runnables.first()?;
+        let start = runnables
+            .iter()
+            .map(|r| r.range.start)
+            .min_by_key(|p| (p.row, p.col))?;
+        let end = runnables
+            .iter()
+            .map(|r| r.range.end)
+            .max_by_key(|p| (p.row, p.col))?;
+        Some(Runnable {
+            name: String::new(),
+            filepath: first.filepath.to_string(),
+            range: start..end,
+            meta: first.meta.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Command {
     pub command: String,
     pub args: Vec<String>,
+    // working directory, the editor's one when unset
+    pub cwd: Option<PathBuf>,
+    pub env: Environment,
+}
+
+impl Command {
+    // with_config
+    //
+    // Runs the command from the configured `cwd` with the configured
+    // environment, see `Config`.
+    pub fn with_config(mut self, config: &Config) -> Self {
+        if let Some(cwd) = config.cwd.clone() {
+            self.cwd = Some(cwd);
+        }
+        self.env.extend(config.environment());
+        self
+    }
+}
+
+// EnvPolicy
+//
+// Whether a command starts from the editor's environment or an empty one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum EnvPolicy {
+    #[default]
+    Inherit,
+    Clear,
+}
+
+// Environment
+//
+// Variables of a command: `unset` are removed from the inherited
+// environment, then `set` are assigned.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Environment {
+    pub policy: EnvPolicy,
+    pub set: BTreeMap<String, String>,
+    pub unset: BTreeSet<String>,
+}
+
+impl Environment {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.unset.remove(name);
+        self.set.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn unset(&mut self, name: &str) {
+        self.set.remove(name);
+        self.unset.insert(name.to_string());
+    }
+
+    // extend
+    //
+    // Applies `other` over the environment, `other` winning on conflicts.
+    pub fn extend(&mut self, other: Environment) {
+        if other.policy == EnvPolicy::Clear {
+            self.policy = EnvPolicy::Clear;
+        }
+        for name in other.unset.iter() {
+            self.unset(name);
+        }
+        for (name, value) in other.set.iter() {
+            self.set(name, value);
+        }
+    }
 }
 pub struct Buffer<'a> {
     pub content: &'a str,
@@ -88,3 +188,64 @@ pub struct CapabilityDetails {
     pub(crate) search: Search,
     pub(crate) description: String,
 }
+
+// Detection
+//
+// Whether a framework applies to a file, how confident it is, from 0 to
+// 100, and the reason reported by `Engine::explain`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Detection {
+    pub matched: bool,
+    pub confidence: u8,
+    pub reason: String,
+}
+
+impl Detection {
+    pub(crate) fn matched(confidence: u8, reason: impl Into<String>) -> Self {
+        Detection {
+            matched: true,
+            confidence,
+            reason: reason.into(),
+        }
+    }
+
+    pub(crate) fn rejected(reason: impl Into<String>) -> Self {
+        Detection {
+            matched: false,
+            confidence: 0,
+            reason: reason.into(),
+        }
+    }
+}
+
+// FrameworkMatch
+//
+// Detection of one registered framework for a file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameworkMatch {
+    pub framework: String,
+    pub priority: u8,
+    pub detection: Detection,
+}
+
+// FrameworkCapability
+//
+// A capability together with the framework providing it, as several
+// frameworks may offer a `Test Nearest` for the same file.
+#[derive(Clone)]
+pub struct FrameworkCapability {
+    pub framework: String,
+    pub details: CapabilityDetails,
+}
+
+// TestResult
+//
+// Outcome of a single test as reported by a test runner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestResult {
+    pub name: String,
+    pub suite: String,
+    pub status: TestStatus,
+    pub duration: Option<f64>,
+    pub message: Option<String>,
+}
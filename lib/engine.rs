@@ -3,58 +3,858 @@
 * The purpose of engine is to orchestrate calls and actions
 */
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
 
+use mlua::{Lua, Table};
+
+use crate::core::config::Config;
 use crate::core::enums::Language;
+use crate::core::errors::FrameworkError;
+use crate::core::paths::config_dir;
 use crate::core::registry::FrameworkRegistry;
-use crate::core::types::{Buffer, CapabilityDetails, CursorPosition, Target};
+use crate::core::traits::{Framework, FrameworkProvider};
+use crate::core::trust::{TrustDatabase, TrustEntry};
+use crate::core::types::{
+    Buffer, CapabilityDetails, Command, CursorPosition, Detection, FrameworkCapability,
+    FrameworkMatch, Runnable, Target, TestResult,
+};
+use crate::framework::cpp::gtest::GtestProvider;
+use crate::framework::declarative::provider::DeclarativeProvider;
+use crate::framework::golang::affected::{AffectedTests, affected_tests};
+use crate::framework::golang::delve::{DelveProvider, LaunchConfiguration};
 use crate::framework::golang::gotest::GotestProvider;
-struct Engine {
+use crate::framework::golang::touched::{TouchedTests, touched_tests};
+use crate::framework::java::junit::JunitProvider;
+use crate::framework::javascript::jest::JestProvider;
+use crate::framework::lua::busted::BustedProvider;
+use crate::framework::python::pytest::PytestProvider;
+use crate::framework::python::unittest::UnittestProvider;
+use crate::framework::ruby::rspec::RspecProvider;
+use crate::framework::rust::cargotest::CargotestProvider;
+use crate::framework::rust::nextest::NextestProvider;
+use crate::framework::scripted::provider::ScriptedProvider;
+use crate::overseer::task::TaskDefinition;
+use crate::overseer::template::TemplateProvider;
+use crate::terminal::backend::{ProcessExecutor, executor};
+use crate::terminal::core::TerminalExecution;
+use crate::terminal::jobs::{ExecutionRegistry, JobRequest};
+use crate::treesitter::query_file;
+
+// builtin_providers
+//
+// The frameworks implemented by examen itself, which unlike lua and
+// declarative ones can be rebuilt away from the editor's thread.
+pub(crate) fn builtin_providers() -> Vec<Box<dyn FrameworkProvider>> {
+    vec![
+        Box::new(GotestProvider::new()),
+        Box::new(DelveProvider::new()),
+        Box::new(CargotestProvider::new()),
+        Box::new(NextestProvider::new()),
+        Box::new(PytestProvider::new()),
+        Box::new(UnittestProvider::new()),
+        Box::new(JestProvider::new()),
+        Box::new(BustedProvider::new()),
+        Box::new(GtestProvider::new()),
+        Box::new(JunitProvider::new()),
+        Box::new(RspecProvider::new()),
+    ]
+}
+
+// ProjectFrameworks
+//
+// The declarative frameworks shipped by a project, loaded the first time
+// one of its files is queried.
+#[derive(Default)]
+struct ProjectFrameworks {
+    providers: Vec<DeclarativeProvider>,
+    // framework definitions that failed to load or are not trusted
+    diagnostics: Vec<FrameworkError>,
+}
+
+pub(crate) struct Engine {
     registry: FrameworkRegistry,
+    // framework definitions of the user that failed to load
+    diagnostics: Vec<FrameworkError>,
+    trust: TrustDatabase,
+    // declarative frameworks by definitions directory, see `project`
+    projects: RefCell<HashMap<PathBuf, Rc<ProjectFrameworks>>>,
+    // runs started from the editor, see `job`
+    jobs: ExecutionRegistry,
 }
 
 impl Engine {
-    pub fn initialize() -> Self {
+    // initialize
+    //
+    // Builds the engine of an editor whose runtime path is `runtime_paths`,
+    // searched for user queries after the user configuration directory, see
+    // `QueryFile`.
+    pub fn initialize(runtime_paths: Vec<PathBuf>) -> Self {
+        query_file::set_runtime_paths(config_dir().into_iter().chain(runtime_paths).collect());
+
         let mut registry = FrameworkRegistry::new();
-        let gotest_provider = Box::new(GotestProvider::new());
-        registry.register(gotest_provider);
-        Self { registry }
+        for provider in builtin_providers() {
+            registry.register(provider);
+        }
+
+        // declarative definitions of the user register last, replacing a same
+        // named provider, those of projects are loaded per file
+        let (declarative_providers, diagnostics) =
+            DeclarativeProvider::load(&DeclarativeProvider::user_directories(), None);
+        for provider in declarative_providers {
+            registry.register(Box::new(provider));
+        }
+        // the concurrency policy applies across projects, a broken
+        // configuration is reported by `config`
+        let config = Config::user().unwrap_or_default();
+        Self {
+            jobs: ExecutionRegistry::from_config(&config.executor),
+            ..Self::new(registry, diagnostics, TrustDatabase::open())
+        }
     }
 
-    pub fn get_capabilities(&self, filepath: &str) -> Vec<CapabilityDetails> {
-        // get file extension from filepath
-        let extension = filepath.split('.').last().unwrap_or("");
-        let lang = Language::from_str(extension).unwrap_or(Language::Unsupported);
-        if lang == Language::Unsupported {
-            return vec![];
+    pub(crate) fn new(
+        registry: FrameworkRegistry,
+        diagnostics: Vec<FrameworkError>,
+        trust: TrustDatabase,
+    ) -> Self {
+        Self {
+            registry,
+            diagnostics,
+            trust,
+            projects: RefCell::new(HashMap::new()),
+            jobs: ExecutionRegistry::new(Box::new(ProcessExecutor), &Default::default()),
         }
+    }
 
-        let fm = self.registry.get_frameworks_by_category_and_language(
-            crate::core::enums::Capability::TestRunner,
-            lang,
+    // diagnostics
+    //
+    // Framework definitions of the user, and of the project holding the
+    // file, that failed to load.
+    pub fn diagnostics(&self, filepath: &str) -> Vec<FrameworkError> {
+        let mut res = self.diagnostics.clone();
+        res.extend(self.project(filepath).diagnostics.iter().cloned());
+        res
+    }
+
+    // project
+    //
+    // The declarative frameworks of the closest project holding
+    // `.examen/frameworks` from the file, those of the project once trusted.
+    fn project(&self, filepath: &str) -> Rc<ProjectFrameworks> {
+        let directories = DeclarativeProvider::project_directories(Path::new(filepath));
+        let Some(directory) = directories.first() else {
+            return Rc::new(ProjectFrameworks::default());
+        };
+        self.projects
+            .borrow_mut()
+            .entry(directory.clone())
+            .or_insert_with(|| {
+                let (providers, diagnostics) =
+                    DeclarativeProvider::load(&directories, Some(&self.trust));
+                Rc::new(ProjectFrameworks {
+                    providers,
+                    diagnostics,
+                })
+            })
+            .clone()
+    }
+
+    // providers
+    //
+    // The registered providers, a framework of the project replacing a same
+    // named one.
+    fn providers<'a>(&'a self, project: &'a ProjectFrameworks) -> Vec<&'a dyn FrameworkProvider> {
+        let mut res: Vec<&dyn FrameworkProvider> = self
+            .registry
+            .get_providers()
+            .into_iter()
+            .filter(|p| !project.providers.iter().any(|d| d.name() == p.name()))
+            .collect();
+        res.extend(
+            project
+                .providers
+                .iter()
+                .map(|p| p as &dyn FrameworkProvider),
         );
+        res
+    }
 
-        fm.into_iter()
-            .flat_map(|f| f.capabilities().clone())
+    fn provider<'a>(
+        &'a self,
+        project: &'a ProjectFrameworks,
+        name: &str,
+    ) -> Option<&'a dyn FrameworkProvider> {
+        self.providers(project)
+            .into_iter()
+            .find(|p| p.name() == name)
+    }
+
+    fn framework(&self, filepath: &str, name: &str) -> Option<Box<dyn Framework>> {
+        let project = self.project(filepath);
+        self.provider(&project, name).map(|p| p.create())
+    }
+
+    // register_lua_framework
+    //
+    // Registers a framework implemented by a lua table, see `ScriptedProvider`.
+    pub fn register_lua_framework(&mut self, lua: &Lua, spec: Table) -> Result<(), FrameworkError> {
+        let provider = ScriptedProvider::new(lua, spec)?;
+        self.registry.register(Box::new(provider));
+        Ok(())
+    }
+
+    // config
+    //
+    // The configuration applying to the file, failing when a file is invalid
+    // or disables a framework that is not registered. Untrusted project files
+    // are left out and listed in `untrusted`.
+    pub fn config(&self, filepath: &str) -> Result<Config, FrameworkError> {
+        let res = Config::discover(Path::new(filepath), &self.trust)?;
+        let project = self.project(filepath);
+        let registered: Vec<&str> = self
+            .providers(&project)
+            .into_iter()
+            .map(|p| p.name())
+            .collect();
+        if let Some(unknown) = res
+            .disabled_frameworks
+            .iter()
+            .find(|f| !registered.contains(&f.as_str()))
+        {
+            return Err(FrameworkError::ConfigError(format!(
+                "{}: unknown framework `{}` in `disabled_frameworks`, expected one of {}",
+                res.sources
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                unknown,
+                registered
+                    .iter()
+                    .map(|n| format!("`{}`", n))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )));
+        }
+        Ok(res)
+    }
+
+    // trusted
+    //
+    // Project files trusted so far, see `TrustDatabase`.
+    pub fn trusted(&self) -> Vec<TrustEntry> {
+        self.trust.list()
+    }
+
+    // allow
+    //
+    // Trusts the current contents of a project file, framework definitions
+    // of projects load again on the next query.
+    pub fn allow(&mut self, filepath: &str) -> Result<TrustEntry, FrameworkError> {
+        let res = self.trust.allow(Path::new(filepath))?;
+        self.projects.get_mut().clear();
+        Ok(res)
+    }
+
+    pub fn revoke(&mut self, filepath: &str) -> Result<bool, FrameworkError> {
+        let res = self.trust.revoke(Path::new(filepath))?;
+        self.projects.get_mut().clear();
+        Ok(res)
+    }
+
+    // get_capabilities
+    //
+    // Capabilities of every framework detected for the file, ranked as
+    // `explain` ranks them, each with the name of its framework.
+    pub fn get_capabilities(&self, filepath: &str) -> Vec<FrameworkCapability> {
+        self.explain(filepath)
+            .into_iter()
+            .filter(|m| m.detection.matched)
+            .filter_map(|m| {
+                let framework = self.framework(filepath, &m.framework)?;
+                let mut capabilities: Vec<CapabilityDetails> =
+                    framework.capabilities().into_iter().collect();
+                capabilities.sort_by(|a, b| a.description.cmp(&b.description));
+                Some(
+                    capabilities
+                        .into_iter()
+                        .map(|details| FrameworkCapability {
+                            framework: m.framework.to_string(),
+                            details,
+                        })
+                        .collect::<Vec<FrameworkCapability>>(),
+                )
+            })
+            .flatten()
             .collect()
     }
 
+    // explain
+    //
+    // Runs the detection of every registered framework against the file and
+    // reports why each matched or was rejected. Matches come first, ranked by
+    // provider priority then confidence.
+    pub fn explain(&self, filepath: &str) -> Vec<FrameworkMatch> {
+        let extension = filepath.rsplit('.').next().unwrap_or_default();
+        let lang = Language::from_str(extension).unwrap_or(Language::Unsupported);
+        let contents = fs::read_to_string(filepath).unwrap_or_default();
+        // an invalid configuration is reported by `config`
+        let config = Config::discover(Path::new(filepath), &self.trust).unwrap_or_default();
+        let project = self.project(filepath);
+
+        let mut res: Vec<FrameworkMatch> = self
+            .providers(&project)
+            .into_iter()
+            .map(|provider| {
+                let languages = provider.languages();
+                let detection = if config.is_disabled(provider.name()) {
+                    Detection::rejected("disabled by `disabled_frameworks`".to_string())
+                } else if lang == Language::Unsupported {
+                    Detection::rejected(format!("`.{}` files are not supported", extension))
+                } else if !languages.contains(&lang) {
+                    let handled: Vec<&str> = languages
+                        .iter()
+                        .filter_map(|l| l.aliases().first().copied())
+                        .collect();
+                    Detection::rejected(format!("handles {} files only", handled.join(", ")))
+                } else {
+                    let target = Target::new(
+                        provider.capability(),
+                        Buffer::new(&contents, filepath.to_string(), CursorPosition::default()),
+                    );
+                    provider.create().detection(&target)
+                };
+                FrameworkMatch {
+                    framework: provider.name().to_string(),
+                    priority: provider.priority(),
+                    detection,
+                }
+            })
+            .collect();
+
+        // rejected frameworks have no rank, they are listed by name
+        let rank = |m: &FrameworkMatch| match m.detection.matched {
+            true => (1, m.priority, m.detection.confidence),
+            false => (0, 0, 0),
+        };
+        res.sort_by(|a, b| rank(b).cmp(&rank(a)).then(a.framework.cmp(&b.framework)));
+        res
+    }
+
+    // overseer_task
+    //
+    // The overseer task definition running the runnables a search of the
+    // framework found, see `subject`.
+    pub fn overseer_task(
+        &self,
+        framework_name: &str,
+        runnables: Vec<Runnable>,
+    ) -> Result<TaskDefinition, FrameworkError> {
+        let runnable = subject(&runnables)?;
+        let project = self.project(&runnable.filepath);
+        let provider = self.provider(&project, framework_name).ok_or_else(|| {
+            FrameworkError::NotFoundError(format!("unknown framework `{}`", framework_name))
+        })?;
+        let command = self.command(framework_name, runnables)?;
+        Ok(TaskDefinition::new(
+            provider.name(),
+            &provider.language(),
+            &runnable,
+            command,
+        ))
+    }
+
+    // command
+    //
+    // The command the framework generates for the runnables of a search,
+    // all of them at once, run from the working directory and with the
+    // environment configured for their file.
+    fn command(
+        &self,
+        framework_name: &str,
+        mut runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let runnable = subject(&runnables)?;
+        let config = self.config(&runnable.filepath)?;
+        let framework = self
+            .framework(&runnable.filepath, framework_name)
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!("unknown framework `{}`", framework_name))
+            })?;
+        let command = match runnables.len() {
+            1 => framework.try_generate_command(runnables.remove(0))?,
+            _ => framework.try_generate_combined_command(runnables)?,
+        };
+        Ok(command.with_config(&config))
+    }
+
+    // debug_configuration
+    //
+    // The nvim-dap launch configuration debugging the runnables a search of
+    // the framework found, configured for their file as commands are.
+    pub fn debug_configuration(
+        &self,
+        framework_name: &str,
+        runnables: Vec<Runnable>,
+    ) -> Result<LaunchConfiguration, FrameworkError> {
+        let runnable = subject(&runnables)?;
+        let config = self.config(&runnable.filepath)?;
+        let provider = DelveProvider::new();
+        if framework_name != provider.name() {
+            return Err(FrameworkError::NotFoundError(format!(
+                "`{}` has no debug configuration",
+                framework_name
+            )));
+        }
+        provider.launch_configuration(&runnables, &config)
+    }
+
+    // job
+    //
+    // The job running the runnables a search of the framework found with the
+    // executor the project of their file configured, to submit to `jobs`.
+    pub fn job(
+        &self,
+        framework_name: &str,
+        runnables: Vec<Runnable>,
+    ) -> Result<JobRequest, FrameworkError> {
+        let runnable = subject(&runnables)?;
+        let config = self.config(&runnable.filepath)?;
+        let execution = TerminalExecution::from(self.command(framework_name, runnables)?);
+        Ok(JobRequest::new(&runnable, execution).with_executor(executor(&config.executor)))
+    }
+
+    // results
+    //
+    // Per-test results of the last run of a runnable of the framework, see
+    // `Framework::results`.
+    pub fn results(
+        &self,
+        framework_name: &str,
+        runnable: &Runnable,
+    ) -> Result<Vec<TestResult>, FrameworkError> {
+        let framework = self
+            .framework(&runnable.filepath, framework_name)
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!("unknown framework `{}`", framework_name))
+            })?;
+        framework.results(runnable)
+    }
+
+    // jobs
+    //
+    // The runs started from the editor, see `ExecutionRegistry`.
+    pub fn jobs(&self) -> &ExecutionRegistry {
+        &self.jobs
+    }
+
+    // overseer_templates
+    //
+    // The overseer template provider listing the capabilities of the file.
+    pub fn overseer_templates(&self, filepath: &str) -> TemplateProvider {
+        TemplateProvider::new(filepath, &self.get_capabilities(filepath))
+    }
+
+    // affected_tests
+    //
+    // The go tests of the module holding the file that the changes of the
+    // working tree may break, see `affected_tests`.
+    pub fn affected_tests(
+        &self,
+        filepath: &str,
+        base: Option<&str>,
+    ) -> Result<AffectedTests, FrameworkError> {
+        let config = self.config(filepath)?;
+        affected_tests(Path::new(filepath), base, &config)
+    }
+
+    // touched_tests
+    //
+    // The go tests the branch holding the file added or changed since it
+    // forked from `base`, see `touched_tests`.
+    pub fn touched_tests(
+        &self,
+        filepath: &str,
+        base: &str,
+    ) -> Result<TouchedTests, FrameworkError> {
+        let config = self.config(filepath)?;
+        let framework = self.framework(filepath, "GoTest").ok_or_else(|| {
+            FrameworkError::NotFoundError("unknown framework `GoTest`".to_string())
+        })?;
+        touched_tests(Path::new(filepath), base, framework.as_ref(), &config)
+    }
+
+    // find_runnables
+    //
+    // The runnables the capability of the framework, e.g. "Test Nearest",
+    // finds in the file at the cursor.
     pub fn find_runnables(
         &self,
         filepath: &str,
         description: &str,
         framework_name: &str,
         cursor: CursorPosition,
-    ) {
-        let framework = self.registry.get_framework(framework_name).unwrap();
-        let contents = fs::read_to_string(filepath).unwrap_or("".to_string());
-        let cap = framework.search_for_capability(description).unwrap();
-        let target = Target::new(
+    ) -> Result<Vec<Runnable>, FrameworkError> {
+        let framework = self.framework(filepath, framework_name).ok_or_else(|| {
+            FrameworkError::NotFoundError(format!("unknown framework `{}`", framework_name))
+        })?;
+        let cap = framework
+            .search_for_capability(description)
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!(
+                    "{} has no capability `{}`",
+                    framework_name, description
+                ))
+            })?;
+        let contents = fs::read_to_string(filepath).unwrap_or_default();
+        let mut target = Target::new(
             cap.capability.clone(),
             Buffer::new(contents.as_str(), filepath.to_string(), cursor),
         );
+        target.override_search_strategy(cap.search);
+
+        framework.runnables(&target)
+    }
+}
+
+// subject
+//
+// The runnable runs of the runnables of a search are about: the runnable
+// itself, or the file several of them stand for, see `Runnable::combined`.
+pub(crate) fn subject(runnables: &[Runnable]) -> Result<Runnable, FrameworkError> {
+    match runnables {
+        [runnable] => Ok(runnable.clone()),
+        _ => Runnable::combined(runnables)
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::Engine;
+    use crate::core::config::ConfigSource;
+    use crate::core::enums::Capability;
+    use crate::core::errors::FrameworkError;
+    use crate::core::registry::FrameworkRegistry;
+    use crate::core::trust::TrustDatabase;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::declarative::provider::DeclarativeProvider;
+    use crate::framework::golang::delve::DelveProvider;
+    use crate::framework::golang::gotest::GotestProvider;
+    use crate::framework::python::pytest::PytestProvider;
+    use crate::framework::rust::cargotest::CargotestProvider;
+    use crate::framework::rust::nextest::NextestProvider;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/declarative/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    fn engine() -> Engine {
+        let mut registry = FrameworkRegistry::new();
+        registry.register(Box::new(GotestProvider::with_config_source(
+            ConfigSource::default(),
+        )));
+        registry.register(Box::new(DelveProvider::with_config_source(
+            ConfigSource::default(),
+        )));
+        registry.register(Box::new(PytestProvider::new()));
+        let (providers, diagnostics) =
+            DeclarativeProvider::load(&[PathBuf::from(fixture("user/frameworks"))], None);
+        for provider in providers {
+            registry.register(Box::new(provider));
+        }
+        Engine::new(registry, diagnostics, TrustDatabase::default())
+    }
+
+    #[gtest]
+    fn explain_ranks_detected_frameworks() {
+        // arrange
+        let engine = engine();
+
+        // act
+        let res = engine.explain(&fixture("suite/pkg/calculator/suite_test.go"));
+
+        // assert
+        let ranking: Vec<(String, bool)> = res
+            .iter()
+            .map(|m| (m.framework.to_string(), m.detection.matched))
+            .collect();
+        assert_that!(
+            ranking,
+            container_eq(vec![
+                ("Ginkgo".to_string(), true),
+                ("GoTest".to_string(), true),
+                ("Delve".to_string(), true),
+                ("Pytest".to_string(), false),
+                ("Scenario".to_string(), false),
+            ])
+        );
+        assert_that!(res[3].detection.reason, eq("handles python files only"));
+    }
+
+    #[gtest]
+    fn explain_reports_rejections() {
+        // arrange
+        let engine = engine();
+
+        // act
+        let res = engine.explain(&fixture("suite/pkg/calculator/calculator_test.go"));
+
+        // assert
+        let gotest = res.iter().find(|m| m.framework == "GoTest").unwrap();
+        assert_that!(gotest.detection.matched, eq(false));
+        assert_that!(
+            gotest.detection.reason,
+            eq("file does not import `testing`")
+        );
+        assert_that!(res[0].framework, eq("Ginkgo"));
+        assert_that!(res[0].detection.matched, eq(true));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "workspace/crates/alpha/tests/integration.rs",
+        &[("Nextest", true), ("CargoTest", true)],
+        "file with `#[test]` functions"
+    )]
+    #[case(
+        "standalone/src/lib.rs",
+        &[("CargoTest", true), ("Nextest", false)],
+        "workspace has no `.config/nextest.toml`"
+    )]
+    fn explain_prefers_nextest_where_configured(
+        #[case] path: &str,
+        #[case] expected: &[(&str, bool)],
+        #[case] reason: &str,
+    ) {
+        // arrange
+        let mut registry = FrameworkRegistry::new();
+        registry.register(Box::new(CargotestProvider::new()));
+        registry.register(Box::new(NextestProvider::with_config_source(
+            ConfigSource::default(),
+        )));
+        let engine = Engine::new(registry, vec![], TrustDatabase::default());
+
+        // act
+        let res = engine.explain(&format!(
+            "{}/lib/fixtures/rust/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        ));
+
+        // assert
+        let ranking: Vec<(&str, bool)> = res
+            .iter()
+            .map(|m| (m.framework.as_str(), m.detection.matched))
+            .collect();
+        assert_that!(ranking, container_eq(expected.to_vec()));
+        assert_that!(res[1].detection.reason, contains_substring(reason));
+    }
+
+    #[gtest]
+    fn explain_rejects_disabled_frameworks() {
+        // arrange
+        let mut engine = engine();
+        let filepath = format!(
+            "{}/lib/fixtures/config/project/pkg/app/app_test.go",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let before = engine.explain(&filepath);
+        for file in [".examen.toml", ".env"] {
+            engine
+                .allow(&format!(
+                    "{}/lib/fixtures/config/project/{}",
+                    env!("CARGO_MANIFEST_DIR"),
+                    file
+                ))
+                .unwrap();
+        }
 
-        framework.runnables(&target);
+        // act
+        let res = engine.explain(&filepath);
+
+        // assert
+        let delve = res.iter().find(|m| m.framework == "Delve").unwrap();
+        assert_that!(delve.detection.matched, eq(false));
+        assert_that!(
+            delve.detection.reason,
+            eq("disabled by `disabled_frameworks`")
+        );
+        assert_that!(engine.config(&filepath), ok(anything()));
+        let untrusted = before.iter().find(|m| m.framework == "Delve").unwrap();
+        assert_that!(untrusted.detection.matched, eq(true));
+    }
+
+    #[gtest]
+    fn commands_follow_the_project_config() {
+        // arrange
+        let mut engine = engine();
+        let root = format!("{}/lib/fixtures/config/project", env!("CARGO_MANIFEST_DIR"));
+        let filepath = format!("{}/pkg/app/test_app.py", root);
+        for file in [".examen.toml", ".env"] {
+            engine.allow(&format!("{}/{}", root, file)).unwrap();
+        }
+        let runnable = engine
+            .find_runnables(&filepath, "Test File", "Pytest", CursorPosition::default())
+            .unwrap()
+            .remove(0);
+
+        // act
+        let command = engine.command("Pytest", vec![runnable.clone()]).unwrap();
+        let task = engine.overseer_task("Pytest", vec![runnable]).unwrap();
+
+        // assert
+        assert_that!(
+            command.cwd,
+            some(eq(&PathBuf::from(format!("{}/pkg", root))))
+        );
+        assert_that!(
+            command.env.set.get("GOFLAGS").map(String::as_str),
+            some(eq("-count=1"))
+        );
+        assert_that!(command.env.unset.contains("GOPATH"), eq(true));
+        assert_that!(task.cwd, some(eq(&format!("{}/pkg", root))));
+        assert_that!(
+            task.env.get("DATABASE_URL").map(String::as_str),
+            some(eq("postgres://localhost:5432/app?sslmode=disable"))
+        );
+    }
+
+    #[gtest]
+    fn project_frameworks_follow_the_queried_file() {
+        // arrange
+        let mut engine = engine();
+        let filepath = fixture("suite/pkg/calculator/suite_test.go");
+        let other = format!(
+            "{}/lib/fixtures/config/project/pkg/app/app_test.go",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let content = fs::read_to_string(&filepath).unwrap();
+        let target = Target::new(
+            Capability::TestRunner,
+            Buffer::new(&content, filepath.clone(), CursorPosition::new(15, 3)),
+        );
+        let runnable = engine
+            .framework(&filepath, "Ginkgo")
+            .unwrap()
+            .runnables(&target)
+            .unwrap()
+            .remove(0);
+        let untrusted = engine
+            .overseer_task("Ginkgo", vec![runnable.clone()])
+            .unwrap();
+        let diagnostics = engine.diagnostics(&filepath);
+
+        // act
+        engine
+            .allow(&fixture("suite/.examen/frameworks/ginkgo.toml"))
+            .unwrap();
+        let res = engine.overseer_task("Ginkgo", vec![runnable]);
+
+        // assert
+        assert_that!(untrusted.cmd, eq("ginkgo"));
+        assert_that!(
+            diagnostics,
+            elements_are![matches_pattern!(FrameworkError::UntrustedError(_))]
+        );
+        assert_that!(res.map(|t| t.cmd), ok(eq("./bin/ginkgo")));
+        assert_that!(engine.diagnostics(&filepath), is_empty());
+        assert_that!(engine.diagnostics(&other), is_empty());
+    }
+
+    #[gtest]
+    fn overseer_templates_list_file_capabilities() {
+        // arrange
+        let engine = engine();
+
+        // act
+        let res = engine.overseer_templates(&fixture("suite/pkg/calculator/suite_test.go"));
+
+        // assert
+        let names: Vec<&str> = res.templates.iter().map(|t| t.name.as_str()).collect();
+        assert_that!(names.len(), eq(9));
+        assert_that!(names[0], eq("Ginkgo: Test File"));
+    }
+
+    #[gtest]
+    fn overseer_task_runs_generated_command() {
+        // arrange
+        let engine = engine();
+        let filepath = fixture("suite/pkg/calculator/suite_test.go");
+        let content = fs::read_to_string(&filepath).unwrap();
+        let target = Target::new(
+            Capability::TestRunner,
+            Buffer::new(&content, filepath.clone(), CursorPosition::new(10, 1)),
+        );
+        let runnable = engine
+            .registry
+            .get_framework("GoTest")
+            .unwrap()
+            .runnables(&target)
+            .unwrap()
+            .remove(0);
+
+        // act
+        let res = engine.overseer_task("GoTest", vec![runnable.clone()]);
+
+        // assert
+        let task = res.unwrap();
+        assert_that!(task.cmd, eq("go"));
+        assert_that!(task.name, eq("GoTest: TestCalculator"));
+        assert_that!(
+            task.metadata.runnable_id,
+            ends_with("suite_test.go::TestCalculator")
+        );
+        assert_that!(
+            engine.overseer_task("Unknown", vec![runnable]),
+            err(eq(&FrameworkError::NotFoundError(
+                "unknown framework `Unknown`".to_string()
+            )))
+        );
+    }
+
+    #[gtest]
+    fn get_capabilities_attaches_framework_names() {
+        // arrange
+        let engine = engine();
+
+        // act
+        let res = engine.get_capabilities(&fixture("suite/pkg/calculator/suite_test.go"));
+
+        // assert
+        let capabilities: Vec<String> = res
+            .iter()
+            .map(|c| format!("{}: {}", c.framework, c.details.description))
+            .collect();
+        assert_that!(
+            capabilities,
+            container_eq(vec![
+                "Ginkgo: Test File".to_string(),
+                "Ginkgo: Test Function".to_string(),
+                "Ginkgo: Test Nearest".to_string(),
+                "GoTest: Test File".to_string(),
+                "GoTest: Test Function".to_string(),
+                "GoTest: Test Nearest".to_string(),
+                "Delve: Debug File".to_string(),
+                "Delve: Debug Function".to_string(),
+                "Delve: Debug Nearest".to_string(),
+            ])
+        );
     }
 }
@@ -0,0 +1,11 @@
+package api
+
+import (
+	"fmt"
+
+	"example.com/shop/cart"
+)
+
+func Describe(c cart.Cart) string {
+	return fmt.Sprint(c.Total)
+}
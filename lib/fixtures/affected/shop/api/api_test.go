@@ -0,0 +1,5 @@
+package api
+
+import "testing"
+
+func TestDescribe(t *testing.T) {}
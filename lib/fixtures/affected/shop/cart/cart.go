@@ -0,0 +1,7 @@
+package cart
+
+import "example.com/shop/money"
+
+type Cart struct {
+	Total money.Cents
+}
@@ -0,0 +1,11 @@
+package cart_test
+
+import (
+	"testing"
+
+	"example.com/shop/cart"
+)
+
+func TestCart(t *testing.T) {
+	_ = cart.Cart{}
+}
@@ -0,0 +1,3 @@
+package money
+
+type Cents int64
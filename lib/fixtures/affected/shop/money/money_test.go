@@ -0,0 +1,5 @@
+package money
+
+import "testing"
+
+func TestCents(t *testing.T) {}
@@ -0,0 +1,5 @@
+package report
+
+import m "example.com/shop/money"
+
+var Zero m.Cents
@@ -0,0 +1,9 @@
+package tools
+
+import (
+	"testing"
+
+	_ "example.com/shop/money"
+)
+
+func TestTools(t *testing.T) {}
@@ -0,0 +1,7 @@
+package app
+
+import "testing"
+
+func TestApp(t *testing.T) {
+	t.Log("app")
+}
@@ -0,0 +1,4 @@
+# CMake generated Testfile for 
+# Source directory: ctest_project
+# Build directory: ctest_project/build
+subdirs("tests")
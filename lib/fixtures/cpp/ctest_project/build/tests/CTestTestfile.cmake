@@ -0,0 +1,4 @@
+# CMake generated Testfile for 
+# Source directory: ctest_project/tests
+# Build directory: ctest_project/build/tests
+include("parser_test[1]_include.cmake")
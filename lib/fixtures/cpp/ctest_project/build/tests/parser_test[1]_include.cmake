@@ -0,0 +1,12 @@
+if(EXISTS "parser_test")
+  if(NOT EXISTS "parser_test[1]_tests.cmake" OR
+     NOT "parser_test[1]_tests.cmake" IS_NEWER_THAN "parser_test")
+    include("/usr/share/cmake/Modules/GoogleTestAddTests.cmake")
+    gtest_discover_tests_impl(
+      TEST_EXECUTABLE [==[parser_test]==]
+    )
+  endif()
+  include("parser_test[1]_tests.cmake")
+else()
+  add_test(parser_test_NOT_BUILT parser_test_NOT_BUILT)
+endif()
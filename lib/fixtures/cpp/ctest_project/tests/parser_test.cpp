@@ -0,0 +1,3 @@
+#include <gtest/gtest.h>
+
+TEST(ParserTest, Parses) { EXPECT_TRUE(true); }
@@ -0,0 +1,18 @@
+package calculator_test
+
+import (
+	. "github.com/onsi/ginkgo/v2"
+	. "github.com/onsi/gomega"
+)
+
+var _ = Describe("Calculator", func() {
+	It("adds", func() {
+		Expect(1 + 1).To(Equal(2))
+	})
+
+	Context("with negatives", func() {
+		It("keeps the sign", func() {
+			Expect(-1 + -1).To(Equal(-2))
+		})
+	})
+})
@@ -0,0 +1,19 @@
+package calculator_test
+
+import (
+	"testing"
+
+	. "github.com/onsi/ginkgo/v2"
+	. "github.com/onsi/gomega"
+)
+
+func TestCalculator(t *testing.T) {
+	RegisterFailHandler(Fail)
+	RunSpecs(t, "Calculator Suite")
+}
+
+var _ = Describe("Suite", func() {
+	It("boots", func() {
+		Expect(true).To(BeTrue())
+	})
+})
@@ -0,0 +1,12 @@
+package com.acme.app;
+
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import org.junit.jupiter.api.Test;
+
+class AppTest {
+  @Test
+  void starts() {
+    assertTrue(true);
+  }
+}
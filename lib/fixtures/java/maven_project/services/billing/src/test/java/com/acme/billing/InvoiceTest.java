@@ -0,0 +1,12 @@
+package com.acme.billing;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+import org.junit.jupiter.api.Test;
+
+class InvoiceTest {
+  @Test
+  void totals() {
+    assertEquals(2, 1 + 1);
+  }
+}
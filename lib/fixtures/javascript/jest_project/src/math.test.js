@@ -0,0 +1,7 @@
+const { add } = require("./math");
+
+describe("math", () => {
+  it("adds", () => {
+    expect(add(1, 1)).toBe(2);
+  });
+});
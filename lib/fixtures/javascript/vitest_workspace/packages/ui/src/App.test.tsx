@@ -0,0 +1,5 @@
+import { expect, it } from "vitest";
+
+it("renders", () => {
+  expect(<div />).toBeTruthy();
+});
@@ -0,0 +1,7 @@
+import { describe, expect, it } from "vitest";
+
+describe("math", () => {
+  it("adds", () => {
+    expect(1 + 1).toBe(2);
+  });
+});
@@ -0,0 +1,5 @@
+describe("config", function()
+  it("sets the runner", function()
+    assert.are.equal("busted", "busted")
+  end)
+end)
@@ -0,0 +1,3 @@
+return {
+  runner = "plenary",
+}
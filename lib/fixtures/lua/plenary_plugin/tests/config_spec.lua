@@ -0,0 +1,7 @@
+local config = require("plugin.config")
+
+describe("config", function()
+  it("sets the runner", function()
+    assert.are.equal("plenary", config.runner)
+  end)
+end)
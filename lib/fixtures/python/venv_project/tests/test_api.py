@@ -0,0 +1,11 @@
+import pytest
+
+
+def create_user(role):
+    return {"role": role}
+
+
+class TestUsers:
+    @pytest.mark.parametrize("role", ["admin", "user"])
+    def test_create(self, role):
+        assert create_user(role)["role"] == role
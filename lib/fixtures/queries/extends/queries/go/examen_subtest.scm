@@ -0,0 +1,11 @@
+; extends
+
+;; table tests keyed by name: for name, tt := range map[string]struct{...}{"case": {...}}
+(for_statement
+  (range_clause
+    right: (composite_literal
+      type: (map_type)
+      body: (literal_value
+        (keyed_element
+          (literal_element
+            (interpreted_string_literal) @test.case.field.value)) @test.case))))
@@ -0,0 +1,3 @@
+(call_expression
+  arguments: (argument_list
+    (interpreted_string_literal) @test.case.name)) @test.case
@@ -0,0 +1,6 @@
+;; only subtests named by a constant
+(call_expression
+  function: (selector_expression
+    field: (field_identifier) @method (#eq? @method "Run"))
+  arguments: (argument_list
+    (identifier) @test.case.field.value)) @test.case
@@ -0,0 +1,5 @@
+RSpec.describe Calculator do
+  it "adds" do
+    expect(Calculator.new.add(1, 1)).to eq(2)
+  end
+end
@@ -0,0 +1,5 @@
+describe "Calculator" do
+  it "adds" do
+    expect(1 + 1).to eq(2)
+  end
+end
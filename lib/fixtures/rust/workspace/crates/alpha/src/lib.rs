@@ -0,0 +1,15 @@
+pub mod parser;
+
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn adds() {
+        assert_eq!(3, add(1, 2));
+    }
+}
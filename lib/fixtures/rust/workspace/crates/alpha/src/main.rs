@@ -0,0 +1,3 @@
+fn main() {
+    println!("{}", alpha::add(1, 2));
+}
@@ -0,0 +1,13 @@
+pub fn tokens(input: &str) -> Vec<&str> {
+    input.split_whitespace().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn splits_on_whitespace() {
+        assert_eq!(vec!["a", "b"], tokens("a b"));
+    }
+}
@@ -0,0 +1,18 @@
+use rstest::rstest;
+
+#[test]
+fn adds() {
+    assert_eq!(3, alpha::add(1, 2));
+}
+
+#[test]
+fn adds_negatives() {
+    assert_eq!(-3, alpha::add(-1, -2));
+}
+
+#[rstest]
+#[case(0, 0)]
+#[case(1, 1)]
+fn adds_zero(#[case] a: i32, #[case] expected: i32) {
+    assert_eq!(expected, alpha::add(a, 0));
+}
@@ -0,0 +1,4 @@
+#[test]
+fn adds_from_outside() {
+    assert_eq!(3, alpha::add(1, 2));
+}
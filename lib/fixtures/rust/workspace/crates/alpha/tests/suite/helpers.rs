@@ -0,0 +1,4 @@
+#[test]
+fn helper_adds() {
+    assert_eq!(3, alpha::add(1, 2));
+}
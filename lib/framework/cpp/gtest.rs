@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::cpp::operations::get_gtest_executable;
+use crate::framework::cpp::operations::gtest_get_file_tests;
+use crate::framework::cpp::operations::parse_tree;
+
+// GtestProvider
+//
+// Runs GoogleTest tests through the executable built from the file, as
+// found in the cmake build directory, selecting them with `--gtest_filter`.
+pub struct GtestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static EXTENSIONS: [&str; 3] = ["cpp", "cc", "cxx"];
+
+impl GtestProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest or method, return the test under the cursor
+         *   - Search set to file, return all tests in the file
+         * */
+        let tests =
+            gtest_get_file_tests::op::execute(tree.root_node(), target).ok_or_else(|| {
+                FrameworkError::NotFoundError(
+                    "GoogleTest test not found no tests in this file".to_string(),
+                )
+            })?;
+        let position = target.buffer.position;
+
+        match target.search_strategy {
+            Search::File => Ok(tests),
+            Search::Nearest | Search::Method => tests
+                .into_iter()
+                .find(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+                .map(|t| vec![t])
+                .ok_or_else(|| {
+                    FrameworkError::NotFoundError(
+                        "GoogleTest test not found at position".to_string(),
+                    )
+                }),
+        }
+    }
+
+    // command
+    //
+    // The test executable filtered to the runnables.
+    fn command(&self, runnables: &[Runnable]) -> Command {
+        let mut executable = None;
+        let mut filters = vec![];
+        for runnable in runnables {
+            let (path, filter) = runnable
+                .meta
+                .get_gtest_meta()
+                .map(|m| (m.executable, m.filter))
+                .unwrap_or_else(|| (String::new(), runnable.name.to_string()));
+            executable.get_or_insert(path);
+            filters.push(filter);
+        }
+
+        Command {
+            command: executable.unwrap_or_default(),
+            args: vec![format!("--gtest_filter={}", filters.join(":"))],
+            ..Default::default()
+        }
+    }
+}
+
+impl FrameworkProvider for GtestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(GtestProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "GoogleTest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Cpp
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for GtestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        let extension = target
+            .buffer
+            .filepath
+            .rsplit('.')
+            .next()
+            .unwrap_or_default();
+        if !EXTENSIONS.contains(&extension) {
+            return Detection::rejected(format!(
+                "file is not a `.{}` file",
+                EXTENSIONS.join("`, `.")
+            ));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if gtest_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `TEST` macros");
+        }
+
+        Detection::matched(80, "file with `TEST` macros")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let executable =
+            get_gtest_executable::op::execute(&target.buffer.filepath).ok_or_else(|| {
+                FrameworkError::PreconditionError(format!(
+                    "GoogleTest executable not found for {}, configure the build with CMAKE_EXPORT_COMPILE_COMMANDS or enable CTest",
+                    target.buffer.filepath
+                ))
+            })?;
+        for runnable in res.iter_mut() {
+            runnable
+                .meta
+                .set_executable(executable.to_string_lossy().to_string());
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.command(&[runnable])
+    }
+
+    // try_generate_combined_command
+    //
+    // Googletest filters join patterns with `:`, the executable being that
+    // of the first runnable.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        Ok(self.command(&runnables))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
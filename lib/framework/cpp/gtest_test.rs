@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod test {
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::cpp::gtest,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+#include <gtest/gtest.h>
+
+int Add(int a, int b) { return a + b; }
+
+TEST(MathTest, Adds) {
+  EXPECT_EQ(2, Add(1, 1));
+}
+
+class MathParamTest : public ::testing::TestWithParam<int> {};
+
+TEST_P(MathParamTest, AddsZero) {
+  EXPECT_EQ(GetParam(), Add(GetParam(), 0));
+}
+
+INSTANTIATE_TEST_SUITE_P(Small, MathParamTest, ::testing::Values(1, 2, 3));
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/cpp/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["MathTest.Adds", "MathParamTest.AddsZero"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(6, 4), vec!["MathTest.Adds"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(12, 4), vec!["MathParamTest.AddsZero"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("cmake_project/tests/math_test.cpp"),
+            position,
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = gtest::GtestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(types::CursorPosition::new(3, 4), "cmake_project/tests/math_test.cpp")]
+    #[case(
+        types::CursorPosition::new(6, 4),
+        "cmake_project/tests/unbuilt_test.cpp"
+    )]
+    fn runnables_errors(#[case] position: types::CursorPosition, #[case] path: &str) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = gtest::GtestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            any![
+                matches_pattern!(&FrameworkError::NotFoundError(_)),
+                matches_pattern!(&FrameworkError::PreconditionError(_))
+            ]
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "cmake_project/tests/math_test.cpp",
+        types::CursorPosition::new(6, 4),
+        "cmake_project/build/tests/math_tests",
+        "--gtest_filter=MathTest.Adds"
+    )]
+    #[case(
+        "ctest_project/tests/parser_test.cpp",
+        types::CursorPosition::new(12, 4),
+        "ctest_project/build/tests/parser_test",
+        "--gtest_filter=Small/MathParamTest.AddsZero/*"
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] position: types::CursorPosition,
+        #[case] expected_executable: &str,
+        #[case] expected_filter: &str,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = gtest::GtestProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq(&fixture(expected_executable)));
+        assert_that!(cmd.args, elements_are![eq(expected_filter)]);
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "tests/math_test.cpp", CONTENT, true)]
+    #[case(enums::Capability::TestRunner, "tests/math_test.cc", CONTENT, true)]
+    #[case(enums::Capability::TestRunner, "tests/math_test.py", CONTENT, false)]
+    #[case(enums::Capability::Debugger, "tests/math_test.cpp", CONTENT, false)]
+    #[case(
+        enums::Capability::TestRunner,
+        "src/math.cpp",
+        "int Add(int a, int b) { return a + b; }\n",
+        false
+    )]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = gtest::GtestProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
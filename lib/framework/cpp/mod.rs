@@ -0,0 +1,6 @@
+pub mod gtest;
+#[cfg(test)]
+mod gtest_test;
+mod operations;
+pub(crate) mod runnable_meta;
+mod treesitter;
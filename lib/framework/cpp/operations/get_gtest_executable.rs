@@ -0,0 +1,191 @@
+pub(crate) mod op {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::LazyLock,
+    };
+
+    use regex::Regex;
+    use serde::Deserialize;
+
+    use crate::core::paths::{absolute, normalize};
+
+    static TARGET_DIRECTORY: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"CMakeFiles/([^/]+)\.dir/").unwrap());
+
+    static CTEST_COMMAND: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r#"(?:add_test\(\s*(?:\[=*\[[^\]]*\]=*\]|"[^"]*"|\S+)\s+|if\(EXISTS\s+)"([^"]+)""#,
+        )
+        .unwrap()
+    });
+
+    // CTEST_DEPTH
+    //
+    // How deep below a build directory CTest files are looked for, enough
+    // for `build/tests/CTestTestfile.cmake` in nested projects.
+    const CTEST_DEPTH: usize = 4;
+
+    #[derive(Deserialize)]
+    struct CompileCommand {
+        directory: String,
+        file: String,
+        #[serde(default)]
+        output: Option<String>,
+        #[serde(default)]
+        command: Option<String>,
+        #[serde(default)]
+        arguments: Option<Vec<String>>,
+    }
+
+    impl CompileCommand {
+        fn object(&self) -> Option<String> {
+            if let Some(output) = &self.output {
+                return Some(output.to_string());
+            }
+            let arguments = match (&self.arguments, &self.command) {
+                (Some(arguments), _) => arguments.clone(),
+                (None, Some(command)) => command.split_whitespace().map(String::from).collect(),
+                _ => return None,
+            };
+            arguments
+                .iter()
+                .position(|a| a == "-o")
+                .and_then(|i| arguments.get(i + 1))
+                .cloned()
+        }
+    }
+
+    // execute
+    //
+    // Locates the test executable built from a source file. Build
+    // directories are the project directories themselves, `build` and
+    // `cmake-build-*`, looked up from the file upwards:
+    //   - `compile_commands.json` maps the source to its object, which cmake
+    //     writes below `CMakeFiles/<target>.dir` of the binary directory
+    //     holding the `<target>` executable
+    //   - otherwise the executables registered with CTest, through
+    //     `add_test` or `gtest_discover_tests`, are matched by file stem
+    pub(crate) fn execute(filepath: &str) -> Option<PathBuf> {
+        let file = absolute(Path::new(filepath));
+        let stem = file.file_stem()?.to_string_lossy().to_string();
+        let directories: Vec<PathBuf> = file
+            .ancestors()
+            .skip(1)
+            .flat_map(build_directories)
+            .collect();
+
+        directories
+            .iter()
+            .find_map(|dir| from_compile_commands(dir, &file))
+            .or_else(|| directories.iter().find_map(|dir| from_ctest(dir, &stem)))
+    }
+
+    fn build_directories(dir: &Path) -> Vec<PathBuf> {
+        let mut res = vec![dir.to_path_buf()];
+        let Ok(entries) = fs::read_dir(dir) else {
+            return res;
+        };
+        let mut children: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter(|p| {
+                p.file_name()
+                    .map(|n| n.to_string_lossy())
+                    .is_some_and(|n| n == "build" || n.starts_with("cmake-build-"))
+            })
+            .collect();
+        children.sort();
+        res.extend(children);
+        res
+    }
+
+    fn from_compile_commands(dir: &Path, file: &Path) -> Option<PathBuf> {
+        let content = fs::read_to_string(dir.join("compile_commands.json")).ok()?;
+        let commands: Vec<CompileCommand> = serde_json::from_str(&content).ok()?;
+        commands.iter().find_map(|command| {
+            let directory = dir.join(&command.directory);
+            if normalize(&directory.join(&command.file)) != *file {
+                return None;
+            }
+            let object = command.object()?;
+            let target = TARGET_DIRECTORY.captures(&object)?.get(1)?.as_str();
+            Some(normalize(&directory.join(target)))
+        })
+    }
+
+    fn from_ctest(dir: &Path, stem: &str) -> Option<PathBuf> {
+        if !dir.join("CTestTestfile.cmake").is_file() {
+            return None;
+        }
+        let mut pending = vec![(dir.to_path_buf(), 0)];
+        while let Some((current, depth)) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&current) else {
+                continue;
+            };
+            for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+                if path.is_dir() {
+                    if depth < CTEST_DEPTH {
+                        pending.push((path, depth + 1));
+                    }
+                    continue;
+                }
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                if name != "CTestTestfile.cmake" && !name.ends_with("_include.cmake") {
+                    continue;
+                }
+                let Ok(content) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                let executable = CTEST_COMMAND
+                    .captures_iter(&content)
+                    .filter_map(|c| c.get(1))
+                    .map(|c| normalize(&current.join(c.as_str())))
+                    .find(|e| e.file_stem().is_some_and(|s| s.to_string_lossy() == stem));
+                if executable.is_some() {
+                    return executable;
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/cpp/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "cmake_project/tests/math_test.cpp",
+        "cmake_project/build/tests/math_tests"
+    )]
+    #[case(
+        "ctest_project/tests/parser_test.cpp",
+        "ctest_project/build/tests/parser_test"
+    )]
+    fn locates_executable(#[case] path: &str, #[case] expected: &str) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res, some(eq(&std::path::PathBuf::from(fixture(expected)))));
+    }
+
+    #[gtest]
+    fn none_without_build_directory() {
+        let res = op::execute(&fixture("cmake_project/tests/unbuilt_test.cpp"));
+
+        assert_that!(res, none());
+    }
+}
@@ -0,0 +1,146 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::cpp::{operations::gtest_get_instantiations, treesitter::gtest_test_macro},
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns every GoogleTest test of the file named `Suite.Name`, with the
+    // `--gtest_filter` selecting it stored in the meta:
+    //   - `TEST`, `TEST_F`: `Suite.Name`
+    //   - `TEST_P`: `Prefix/Suite.Name/*`, `*/Suite.Name/*` unless the file
+    //     holds its only instantiation
+    //   - `TYPED_TEST`: `Suite/*.Name`
+    //   - `TYPED_TEST_P`: `Prefix/Suite/*.Name`, likewise
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = gtest_test_macro::query();
+        let query = Query::new(&Language::new(tree_sitter_cpp::LANGUAGE), &query_pattern).ok()?;
+        let macro_index = query.capture_index_for_name("gtest.macro")?;
+        let suite_index = query.capture_index_for_name("gtest.suite")?;
+        let name_index = query.capture_index_for_name("gtest.name")?;
+        let test_index = query.capture_index_for_name("gtest.test")?;
+        let instantiations = gtest_get_instantiations::op::execute(root, content);
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(test), Some(test_macro), Some(suite), Some(name)) = (
+                capture(test_index),
+                capture(macro_index),
+                capture(suite_index),
+                capture(name_index),
+            ) else {
+                continue;
+            };
+
+            let suite = node::node_text(suite, content);
+            let name = node::node_text(name, content);
+            let prefix = match instantiations.get(&suite).map(|p| p.as_slice()) {
+                Some([prefix]) => prefix.to_string(),
+                _ => "*".to_string(),
+            };
+            let filter = match node::node_text(test_macro, content).as_str() {
+                "TEST_P" => format!("{}/{}.{}/*", prefix, suite, name),
+                "TYPED_TEST" => format!("{}/*.{}", suite, name),
+                "TYPED_TEST_P" => format!("{}/{}/*.{}", prefix, suite, name),
+                _ => format!("{}.{}", suite, name),
+            };
+
+            let mut meta = RunnableMeta::default_cpp();
+            meta.set_filter(filter);
+            runnables.push(Runnable {
+                name: format!("{}.{}", suite, name),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(test.start_position()),
+                    end: CursorPosition::from_point(test.end_position()),
+                },
+                meta,
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::cpp::operations::parse_tree;
+
+    #[gtest]
+    fn finds_tests_and_filters() {
+        let content = r#"
+#include <gtest/gtest.h>
+
+TEST(MathTest, Adds) { EXPECT_EQ(2, 1 + 1); }
+
+TEST_F(CalculatorTest, Clears) { calc.Clear(); }
+
+TEST_P(ParserTest, Parses) { EXPECT_TRUE(Parse(GetParam())); }
+
+INSTANTIATE_TEST_SUITE_P(Inputs, ParserTest, ::testing::Values("a", "b"));
+
+TEST_P(LexerTest, Lexes) { EXPECT_TRUE(Lex(GetParam())); }
+
+TYPED_TEST(ListTest, Empty) { TypeParam list; }
+
+TYPED_TEST_P(SetTest, Empty) { TypeParam set; }
+
+void helper(int a, int b) {}
+"#;
+        let buffer = Buffer::new(
+            content,
+            "tests/math_test.cpp".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let filters: Vec<String> = res
+            .iter()
+            .map(|r| r.meta.get_gtest_meta().unwrap().filter)
+            .collect();
+        assert_that!(
+            filters,
+            container_eq(vec![
+                "MathTest.Adds".to_string(),
+                "CalculatorTest.Clears".to_string(),
+                "Inputs/ParserTest.Parses/*".to_string(),
+                "*/LexerTest.Lexes/*".to_string(),
+                "ListTest/*.Empty".to_string(),
+                "*/SetTest/*.Empty".to_string(),
+            ])
+        );
+        assert_that!(res[1].name, eq("CalculatorTest.Clears"));
+        assert_that!(res[1].range.start.row, eq(5));
+    }
+}
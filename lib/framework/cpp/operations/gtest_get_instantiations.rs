@@ -0,0 +1,71 @@
+pub(crate) mod op {
+    use std::collections::HashMap;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{framework::cpp::treesitter::gtest_instantiation, treesitter::node};
+
+    // execute
+    //
+    // Returns the instantiation prefixes of each parameterized suite of the
+    // file, e.g. `ParserTest => [Inputs]`.
+    pub(crate) fn execute(root: Node, content: &str) -> HashMap<String, Vec<String>> {
+        let mut res: HashMap<String, Vec<String>> = HashMap::new();
+        let query_pattern = gtest_instantiation::query();
+        let Ok(query) = Query::new(&Language::new(tree_sitter_cpp::LANGUAGE), &query_pattern)
+        else {
+            return res;
+        };
+        let (Some(prefix_index), Some(suite_index)) = (
+            query.capture_index_for_name("gtest.prefix"),
+            query.capture_index_for_name("gtest.suite"),
+        ) else {
+            return res;
+        };
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| node::node_text(c.node, content))
+            };
+            if let (Some(prefix), Some(suite)) = (capture(prefix_index), capture(suite_index)) {
+                res.entry(suite).or_default().push(prefix);
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::framework::cpp::operations::parse_tree;
+
+    #[gtest]
+    fn finds_instantiations() {
+        let content = r#"
+INSTANTIATE_TEST_SUITE_P(Inputs, ParserTest, ::testing::Values("a", "b"));
+INSTANTIATE_TEST_SUITE_P(Edges, ParserTest, ::testing::Values(""));
+INSTANTIATE_TYPED_TEST_SUITE_P(Ints, ListTest, IntTypes);
+"#;
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), content);
+
+        assert_that!(
+            res.get("ParserTest").cloned().unwrap_or_default(),
+            container_eq(vec!["Inputs".to_string(), "Edges".to_string()])
+        );
+        assert_that!(
+            res.get("ListTest").cloned().unwrap_or_default(),
+            container_eq(vec!["Ints".to_string()])
+        );
+    }
+}
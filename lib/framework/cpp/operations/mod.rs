@@ -0,0 +1,4 @@
+pub(crate) mod get_gtest_executable;
+pub(crate) mod gtest_get_file_tests;
+pub(crate) mod gtest_get_instantiations;
+pub(crate) mod parse_tree;
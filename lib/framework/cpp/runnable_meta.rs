@@ -0,0 +1,30 @@
+use crate::core::metadata::RunnableMeta;
+
+impl RunnableMeta {
+    pub(in crate::framework::cpp) fn set_executable(&mut self, value: String) {
+        if let RunnableMeta::Cpp { executable, .. } = self {
+            *executable = value;
+        }
+    }
+
+    pub(in crate::framework::cpp) fn set_filter(&mut self, value: String) {
+        if let RunnableMeta::Cpp { filter, .. } = self {
+            *filter = value;
+        }
+    }
+
+    pub(in crate::framework::cpp) fn get_gtest_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::Cpp { executable, filter } = self {
+            return Some(Meta {
+                executable: executable.clone(),
+                filter: filter.clone(),
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::cpp) struct Meta {
+    pub(in crate::framework::cpp) executable: String,
+    pub(in crate::framework::cpp) filter: String,
+}
@@ -0,0 +1,24 @@
+// query
+//
+// Finds the instantiations of value and type parameterized suites, which
+// prefix the names of their tests.
+//
+// Example:
+// INSTANTIATE_TEST_SUITE_P(Inputs, ParserTest, ::testing::Values("a", "b"));
+//
+// This will find the following instantiations:
+// - Inputs ParserTest
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((call_expression
+                    function: (identifier) @gtest.instantiate
+                    arguments: (argument_list
+                        .
+                        (identifier) @gtest.prefix
+                        .
+                        (identifier) @gtest.suite))
+                  (#match? @gtest.instantiate "^INSTANTIATE_(TYPED_)?TEST_(SUITE|CASE)_P$"))]]
+            "#;
+
+    res.to_string()
+}
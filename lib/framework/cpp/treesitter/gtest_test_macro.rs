@@ -0,0 +1,30 @@
+// query
+//
+// Finds GoogleTest test definitions. tree-sitter-cpp reads the macros as
+// function definitions whose parameters are the suite and test names.
+//
+// Example:
+// TEST_F(CalculatorTest, Clears) {
+//   calc.Clear();
+//   EXPECT_EQ(0, calc.Value());
+// }
+//
+// This will find the following tests:
+// - TEST_F CalculatorTest Clears
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((function_definition
+                    declarator: (function_declarator
+                        declarator: (identifier) @gtest.macro
+                        parameters: (parameter_list
+                            .
+                            (parameter_declaration type: (type_identifier) @gtest.suite)
+                            .
+                            (parameter_declaration type: (type_identifier) @gtest.name)
+                            .))
+                    body: (compound_statement)) @gtest.test
+                  (#match? @gtest.macro "^(TEST|TEST_F|TEST_P|TYPED_TEST|TYPED_TEST_P)$"))]]
+            "#;
+
+    res.to_string()
+}
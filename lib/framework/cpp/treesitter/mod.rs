@@ -0,0 +1,2 @@
+pub(crate) mod gtest_instantiation;
+pub(crate) mod gtest_test_macro;
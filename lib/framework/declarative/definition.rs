@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+use crate::core::enums::Capability;
+
+// Definition
+//
+// A test framework described in a `.toml` or `.json` file rather than in
+// rust, so in-house test dsls are supported without forking.
+//
+// Example:
+// name = "Ginkgo"
+// language = "go"
+// files = ["*_test.go"]
+// root_markers = ["go.mod"]
+// query = '''
+// (call_expression
+//   function: (identifier) @_describe (#match? @_describe "^(Describe|Context)$")
+//   arguments: (argument_list . (interpreted_string_literal) @parent.name)) @parent.body
+// (call_expression
+//   function: (identifier) @_it (#eq? @_it "It")
+//   arguments: (argument_list . (interpreted_string_literal) @test.name)) @test.body
+// '''
+//
+// [names]
+// separator = " "
+//
+// [command]
+// program = "ginkgo"
+// args = ["--focus", "{pattern}", "{dir}"]
+// file_args = ["{dir}"]
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Definition {
+    pub name: String,
+    // language name or alias, as accepted by `Language::from_str`
+    pub language: String,
+    #[serde(default = "default_capability")]
+    pub capability: Capability,
+    // globs matched against the file name and the path from the root
+    pub files: Vec<String>,
+    // files or directories marking the directory commands run from,
+    // the directory of the test file when none is found
+    #[serde(default)]
+    pub root_markers: Vec<String>,
+    // tree-sitter query capturing `@test.name` and `@test.body`, and
+    // optionally `@parent.name` and `@parent.body` for enclosing groups
+    pub query: String,
+    #[serde(default)]
+    pub names: NameRule,
+    pub command: CommandTemplate,
+}
+
+// NameRule
+//
+// How the name of a test is joined to the names of its parents.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct NameRule {
+    pub separator: String,
+    // drops the quotes surrounding a captured string literal
+    pub strip_quotes: bool,
+}
+
+impl Default for NameRule {
+    fn default() -> Self {
+        Self {
+            separator: " ".to_string(),
+            strip_quotes: true,
+        }
+    }
+}
+
+// CommandTemplate
+//
+// The command running a test. Arguments may hold the placeholders
+// `{root}`, `{file}`, `{dir}`, `{name}`, `{pattern}` and `{line}`, where
+// `{pattern}` is the anchored, regex escaped full name. Running a whole
+// file uses `file_args`, which only accepts `{root}`, `{file}` and `{dir}`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CommandTemplate {
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub file_args: Option<Vec<String>>,
+}
+
+fn default_capability() -> Capability {
+    Capability::TestRunner
+}
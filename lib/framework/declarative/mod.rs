@@ -0,0 +1,6 @@
+pub mod definition;
+mod operations;
+pub mod provider;
+#[cfg(test)]
+mod provider_test;
+pub(crate) mod runnable_meta;
@@ -0,0 +1,239 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::declarative::definition::{Definition, NameRule},
+        treesitter::node,
+    };
+
+    // Block
+    //
+    // A test or a parent group captured by the query of a definition, with
+    // its full name: the names of the enclosing parents and its own joined
+    // by the separator of the definition.
+    pub(crate) struct Block<'a> {
+        pub(crate) name: String,
+        pub(crate) parent: bool,
+        pub(crate) node: Node<'a>,
+    }
+
+    // execute
+    //
+    // Returns every test captured in the file.
+    pub(crate) fn execute(
+        root: Node,
+        target: &Target,
+        definition: &Definition,
+        grammar: &Language,
+    ) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> = blocks(root, target.buffer.content, definition, grammar)?
+            .iter()
+            .filter(|b| !b.parent)
+            .map(|b| runnable(b, target))
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // runnable
+    //
+    // Converts a block to a runnable, parents match by prefix rather than
+    // exactly.
+    pub(crate) fn runnable(block: &Block, target: &Target) -> Runnable {
+        let mut meta = RunnableMeta::default_declarative();
+        meta.set_declarative_line(Some(block.node.start_position().row + 1), !block.parent);
+        Runnable {
+            name: block.name.to_string(),
+            filepath: target.buffer.filepath.to_string(),
+            range: Range {
+                start: CursorPosition::from_point(block.node.start_position()),
+                end: CursorPosition::from_point(block.node.end_position()),
+            },
+            meta,
+        }
+    }
+
+    // blocks
+    //
+    // Returns the tests and parents captured in the file in source order. A
+    // body captured by several patterns counts once.
+    pub(crate) fn blocks<'a>(
+        root: Node<'a>,
+        content: &str,
+        definition: &Definition,
+        grammar: &Language,
+    ) -> Option<Vec<Block<'a>>> {
+        let query = Query::new(grammar, &definition.query).ok()?;
+        let test_name_index = query.capture_index_for_name("test.name")?;
+        let test_body_index = query.capture_index_for_name("test.body")?;
+        let parent_indexes = query
+            .capture_index_for_name("parent.name")
+            .zip(query.capture_index_for_name("parent.body"));
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut calls: Vec<(Node, String, bool)> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let captured = match (capture(test_name_index), capture(test_body_index)) {
+                (Some(name), Some(body)) => Some((name, body, false)),
+                _ => parent_indexes.and_then(|(name_index, body_index)| {
+                    capture(name_index)
+                        .zip(capture(body_index))
+                        .map(|(name, body)| (name, body, true))
+                }),
+            };
+            let Some((name, body, parent)) = captured else {
+                continue;
+            };
+            if calls.iter().any(|(call, _, _)| call.id() == body.id()) {
+                continue;
+            }
+            calls.push((body, name_text(name, content, &definition.names), parent));
+        }
+        calls.sort_by_key(|(call, _, _)| call.start_byte());
+
+        let blocks: Vec<Block> = calls
+            .iter()
+            .map(|(call, name, parent)| {
+                let mut path: Vec<&str> = calls
+                    .iter()
+                    .filter(|(enclosing, _, enclosing_parent)| {
+                        *enclosing_parent
+                            && enclosing.id() != call.id()
+                            && enclosing.start_byte() <= call.start_byte()
+                            && call.end_byte() <= enclosing.end_byte()
+                    })
+                    .map(|(_, enclosing_name, _)| enclosing_name.as_str())
+                    .collect();
+                path.push(name);
+                Block {
+                    name: path.join(&definition.names.separator),
+                    parent: *parent,
+                    node: *call,
+                }
+            })
+            .collect();
+
+        if blocks.is_empty() {
+            None
+        } else {
+            Some(blocks)
+        }
+    }
+
+    // name_text
+    //
+    // Text of a captured name, without the quotes of a string literal when
+    // the definition strips them.
+    fn name_text(name: Node, content: &str, rule: &NameRule) -> String {
+        let text = node::node_text(name, content);
+        if !rule.strip_quotes {
+            return text;
+        }
+        for quote in ['"', '\'', '`'] {
+            if let Some(inner) = text
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+            {
+                return inner.to_string();
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::{Capability, Language};
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::declarative::definition::Definition;
+    use crate::framework::declarative::operations::parse_tree;
+    use crate::treesitter::grammar::grammar;
+
+    const DEFINITION: &str = r#"
+name = "Ginkgo"
+language = "go"
+files = ["*_test.go"]
+query = '''
+(call_expression
+  function: (identifier) @_describe (#match? @_describe "^(Describe|Context)$")
+  arguments: (argument_list . (interpreted_string_literal) @parent.name)) @parent.body
+(call_expression
+  function: (identifier) @_it (#eq? @_it "It")
+  arguments: (argument_list . (interpreted_string_literal) @test.name)) @test.body
+'''
+
+[names]
+separator = " / "
+
+[command]
+program = "ginkgo"
+"#;
+
+    const CONTENT: &str = r#"package calculator_test
+
+var _ = Describe("Calculator", func() {
+	It("adds", func() {
+		Expect(Add(1, 1)).To(Equal(2))
+	})
+
+	Context("with negatives", func() {
+		It("keeps the sign", func() {
+			Expect(Add(-1, -1)).To(Equal(-2))
+		})
+	})
+})
+"#;
+
+    #[gtest]
+    fn finds_tests_with_parent_names() {
+        // arrange
+        let definition: Definition = toml::from_str(DEFINITION).unwrap();
+        let grammar = grammar(&Language::Golang, "").unwrap();
+        let buffer = Buffer::new(
+            CONTENT,
+            "calculator_test.go".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(CONTENT, &Language::Golang, "").unwrap();
+
+        // act
+        let res = op::execute(tree.root_node(), &target, &definition, &grammar).unwrap();
+
+        // assert
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "Calculator / adds".to_string(),
+                "Calculator / with negatives / keeps the sign".to_string(),
+            ])
+        );
+        let lines: Vec<Option<usize>> = res
+            .iter()
+            .map(|r| r.meta.get_declarative_meta().unwrap().line)
+            .collect();
+        assert_that!(lines, container_eq(vec![Some(4), Some(9)]));
+    }
+}
@@ -0,0 +1,82 @@
+pub(crate) mod op {
+    use std::path::{Path, PathBuf};
+
+    use crate::core::paths::{absolute, find_ancestor};
+
+    // Project
+    //
+    // The directory the commands of a declarative framework run from.
+    pub(crate) struct Project {
+        pub(crate) root: PathBuf,
+    }
+
+    impl Project {
+        // relative_path
+        //
+        // Path of a file relative to the project root.
+        pub(crate) fn relative_path(&self, filepath: &str) -> String {
+            let file = absolute(Path::new(filepath));
+            let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+
+        // relative_directory
+        //
+        // Directory of a file relative to the project root, `./` prefixed as
+        // go and most runners expect for package paths.
+        pub(crate) fn relative_directory(&self, filepath: &str) -> String {
+            let relative = self.relative_path(filepath);
+            match relative.rsplit_once('/') {
+                Some((directory, _)) => format!("./{}", directory),
+                None => ".".to_string(),
+            }
+        }
+    }
+
+    // execute
+    //
+    // Resolves the closest directory holding one of the markers, or the
+    // directory of the file without any.
+    pub(crate) fn execute(filepath: &str, markers: &[String]) -> Project {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        let root = find_ancestor(&directory, |dir| {
+            markers.iter().any(|marker| dir.join(marker).exists())
+        })
+        .unwrap_or(directory);
+        Project { root }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case(vec!["go.mod".to_string()], "suite", "./pkg/calculator", "pkg/calculator/calculator_test.go")]
+    #[case(vec![], "calculator", ".", "calculator_test.go")]
+    fn resolves_root(
+        #[case] markers: Vec<String>,
+        #[case] root: &str,
+        #[case] directory: &str,
+        #[case] relative: &str,
+    ) {
+        let filepath = format!(
+            "{}/lib/fixtures/declarative/suite/pkg/calculator/calculator_test.go",
+            env!("CARGO_MANIFEST_DIR")
+        );
+
+        let project = op::execute(&filepath, &markers);
+
+        assert_that!(project.root.ends_with(root), eq(true));
+        assert_that!(project.relative_directory(&filepath), eq(directory));
+        assert_that!(project.relative_path(&filepath), eq(relative));
+    }
+}
@@ -0,0 +1,127 @@
+pub(crate) mod op {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::core::errors::FrameworkError;
+    use crate::core::trust::TrustDatabase;
+    use crate::framework::declarative::definition::Definition;
+
+    // execute
+    //
+    // Reads every `.toml` and `.json` framework definition of a directory in
+    // file name order. A missing directory holds no definitions; a file that
+    // cannot be read or parsed yields an error naming it, without stopping
+    // the others from loading. Given a trust database, only files whose
+    // contents were trusted are parsed.
+    pub(crate) fn execute(
+        directory: &Path,
+        trust: Option<&TrustDatabase>,
+    ) -> Vec<Result<Definition, FrameworkError>> {
+        let Ok(entries) = fs::read_dir(directory) else {
+            return vec![];
+        };
+        let mut files: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|extension| extension == "toml" || extension == "json")
+            })
+            .collect();
+        files.sort();
+
+        files.iter().map(|file| read(file, trust)).collect()
+    }
+
+    fn read(file: &Path, trust: Option<&TrustDatabase>) -> Result<Definition, FrameworkError> {
+        let error = |details: String| {
+            FrameworkError::ParsingError(format!(
+                "framework definition {}: {}",
+                file.display(),
+                details
+            ))
+        };
+        let content = fs::read_to_string(file).map_err(|e| error(e.to_string()))?;
+        if let Some(trust) = trust {
+            trust.check(file, &content)?;
+        }
+        if file
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            serde_json::from_str(&content).map_err(|e| error(e.to_string()))
+        } else {
+            toml::from_str(&content).map_err(|e| error(e.message().to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::op;
+    use crate::core::errors::FrameworkError;
+    use crate::core::trust::TrustDatabase;
+    use googletest::prelude::*;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(format!(
+            "{}/lib/fixtures/declarative/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        ))
+    }
+
+    #[gtest]
+    fn loads_toml_and_json_definitions() {
+        let res = op::execute(&fixture("user/frameworks"), None);
+
+        let names: Vec<String> = res
+            .into_iter()
+            .map(|d| d.map(|d| d.name).unwrap_or_default())
+            .collect();
+        assert_that!(
+            names,
+            container_eq(vec!["Ginkgo".to_string(), "Scenario".to_string()])
+        );
+    }
+
+    #[gtest]
+    fn reports_unparsable_definition() {
+        let res = op::execute(&fixture("project/.examen/frameworks"), None);
+
+        let errors: Vec<String> = res
+            .into_iter()
+            .filter_map(|d| d.err())
+            .map(|e| e.to_string())
+            .collect();
+        assert_that!(errors.len(), eq(1));
+        assert_that!(errors[0], contains_substring("broken.toml"));
+        assert_that!(errors[0], contains_substring("missing field `command`"));
+    }
+
+    #[gtest]
+    fn skips_untrusted_definitions() {
+        let res = op::execute(
+            &fixture("project/.examen/frameworks"),
+            Some(&TrustDatabase::default()),
+        );
+
+        let errors: Vec<FrameworkError> = res.into_iter().filter_map(|d| d.err()).collect();
+        assert_that!(
+            errors,
+            elements_are![matches_pattern!(FrameworkError::UntrustedError(
+                contains_substring("broken.toml is not trusted")
+            ))]
+        );
+    }
+
+    #[gtest]
+    fn missing_directory_holds_no_definitions() {
+        let res = op::execute(&fixture("missing"), None);
+
+        assert_that!(res.is_empty(), eq(true));
+    }
+}
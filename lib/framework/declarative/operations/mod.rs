@@ -0,0 +1,6 @@
+pub(crate) mod declarative_get_file_tests;
+pub(crate) mod get_project_root;
+pub(crate) mod load_definitions;
+pub(crate) mod parse_tree;
+pub(crate) mod render_command;
+pub(crate) mod validate_definition;
@@ -0,0 +1,33 @@
+pub(crate) mod op {
+    use tree_sitter::{Parser, Tree};
+
+    use crate::core::enums::Language;
+    use crate::core::errors::FrameworkError;
+    use crate::treesitter::grammar::grammar;
+
+    pub(crate) fn execute(
+        content: &str,
+        language: &Language,
+        filepath: &str,
+    ) -> Result<Tree, FrameworkError> {
+        let Some(grammar) = grammar(language, filepath) else {
+            return Err(FrameworkError::ParsingError(format!(
+                "no grammar for language {:?}",
+                language
+            )));
+        };
+        let mut parser = Parser::new();
+        parser
+            .set_language(&grammar)
+            .map_err(|e| FrameworkError::ParsingError(e.to_string()))?;
+
+        let tree = parser.parse(content, None);
+        if tree.is_none() {
+            return Err(FrameworkError::ParsingError(
+                "failed to parse content to tree".to_string(),
+            ));
+        }
+
+        Ok(tree.unwrap())
+    }
+}
@@ -0,0 +1,85 @@
+pub(crate) mod op {
+    use std::collections::HashMap;
+    use std::sync::LazyLock;
+
+    use regex::{Captures, Regex};
+
+    static PLACEHOLDER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\{([A-Za-z_]+)\}").unwrap());
+
+    // PLACEHOLDERS
+    //
+    // Values available to every argument of a command template.
+    pub(crate) const PLACEHOLDERS: [&str; 6] = ["root", "file", "dir", "name", "pattern", "line"];
+
+    // FILE_PLACEHOLDERS
+    //
+    // Values available when running a whole file, which has no test name.
+    pub(crate) const FILE_PLACEHOLDERS: [&str; 3] = ["root", "file", "dir"];
+
+    // placeholders
+    //
+    // Names of the placeholders an argument refers to, e.g. `-run={pattern}`
+    // refers to `pattern`.
+    pub(crate) fn placeholders(arg: &str) -> Vec<String> {
+        PLACEHOLDER
+            .captures_iter(arg)
+            .map(|c| c[1].to_string())
+            .collect()
+    }
+
+    // execute
+    //
+    // Substitutes the placeholders of every argument. Unknown placeholders
+    // are rejected when the definition loads and are left as is.
+    pub(crate) fn execute(args: &[String], values: &HashMap<&str, String>) -> Vec<String> {
+        args.iter()
+            .map(|arg| {
+                PLACEHOLDER
+                    .replace_all(arg, |c: &Captures| {
+                        values
+                            .get(&c[1])
+                            .cloned()
+                            .unwrap_or_else(|| c[0].to_string())
+                    })
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::op;
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn substitutes_placeholders() {
+        let values = HashMap::from([
+            ("dir", "./pkg".to_string()),
+            ("pattern", "^Calculator adds$".to_string()),
+        ]);
+        let args = vec![
+            "--focus={pattern}".to_string(),
+            "{dir}".to_string(),
+            "{unknown}".to_string(),
+        ];
+
+        let res = op::execute(&args, &values);
+
+        assert_that!(
+            res,
+            container_eq(vec![
+                "--focus=^Calculator adds$".to_string(),
+                "./pkg".to_string(),
+                "{unknown}".to_string(),
+            ])
+        );
+        assert_that!(
+            op::placeholders("{file}:{line}"),
+            container_eq(vec!["file".to_string(), "line".to_string()])
+        );
+    }
+}
@@ -0,0 +1,155 @@
+pub(crate) mod op {
+    use std::str::FromStr;
+
+    use globset::Glob;
+    use tree_sitter::Query;
+
+    use crate::core::enums::Language;
+    use crate::framework::declarative::definition::Definition;
+    use crate::framework::declarative::operations::render_command;
+    use crate::treesitter::grammar::grammar;
+
+    const TEST_CAPTURES: [&str; 2] = ["test.name", "test.body"];
+    const PARENT_CAPTURES: [&str; 2] = ["parent.name", "parent.body"];
+
+    // execute
+    //
+    // Checks a definition can be used before it is registered, returning the
+    // language it targets or a message naming the offending field.
+    pub(crate) fn execute(definition: &Definition) -> Result<Language, String> {
+        if definition.name.trim().is_empty() {
+            return Err("`name` must not be empty".to_string());
+        }
+        let language = Language::from_str(&definition.language)
+            .map_err(|_| format!("unknown `language` `{}`", definition.language))?;
+        let Some(grammar) = grammar(&language, "") else {
+            return Err(format!("unknown `language` `{}`", definition.language));
+        };
+
+        if definition.files.is_empty() {
+            return Err("`files` must list at least one glob".to_string());
+        }
+        for pattern in definition.files.iter() {
+            Glob::new(pattern).map_err(|e| format!("invalid glob in `files`: {}", e))?;
+        }
+
+        let query = Query::new(&grammar, &definition.query)
+            .map_err(|e| format!("invalid `query`: {}", e))?;
+        let captures = query.capture_names();
+        for capture in TEST_CAPTURES {
+            if !captures.contains(&capture) {
+                return Err(format!("`query` must capture `@{}`", capture));
+            }
+        }
+        let parents = PARENT_CAPTURES
+            .iter()
+            .filter(|c| captures.contains(c))
+            .count();
+        if parents == 1 {
+            return Err("`query` must capture both `@parent.name` and `@parent.body`".to_string());
+        }
+
+        if definition.command.program.trim().is_empty() {
+            return Err("`command.program` must not be empty".to_string());
+        }
+        for arg in definition.command.args.iter() {
+            check_placeholders(arg, &render_command::op::PLACEHOLDERS, "command.args")?;
+        }
+        for arg in definition.command.file_args.iter().flatten() {
+            check_placeholders(
+                arg,
+                &render_command::op::FILE_PLACEHOLDERS,
+                "command.file_args",
+            )?;
+        }
+
+        Ok(language)
+    }
+
+    fn check_placeholders(arg: &str, allowed: &[&str], field: &str) -> Result<(), String> {
+        match render_command::op::placeholders(arg)
+            .into_iter()
+            .find(|p| !allowed.contains(&p.as_str()))
+        {
+            Some(placeholder) => Err(format!(
+                "unknown placeholder `{{{}}}` in `{}`, expected one of {}",
+                placeholder,
+                field,
+                allowed
+                    .iter()
+                    .map(|p| format!("`{{{}}}`", p))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use crate::core::enums::Language;
+    use crate::framework::declarative::definition::Definition;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    const DEFINITION: &str = r#"
+name = "Ginkgo"
+language = "golang"
+files = ["*_test.go"]
+query = '''
+(call_expression
+  function: (identifier) @_it (#eq? @_it "It")
+  arguments: (argument_list . (interpreted_string_literal) @test.name)) @test.body
+'''
+
+[command]
+program = "ginkgo"
+args = ["--focus", "{pattern}", "{dir}"]
+"#;
+
+    fn definition() -> Definition {
+        toml::from_str(DEFINITION).unwrap()
+    }
+
+    #[gtest]
+    fn accepts_valid_definition() {
+        let res = op::execute(&definition());
+
+        assert_that!(res, ok(eq(&Language::Golang)));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(|d: &mut Definition| d.language = "cobol".to_string(), "unknown `language` `cobol`")]
+    #[case(|d: &mut Definition| d.files = vec!["[".to_string()], "invalid glob in `files`")]
+    #[case(|d: &mut Definition| d.query = "(call_expression".to_string(), "invalid `query`")]
+    #[case(
+        |d: &mut Definition| d.query = "(call_expression) @test.body".to_string(),
+        "`query` must capture `@test.name`"
+    )]
+    #[case(
+        |d: &mut Definition| d.query.push_str("(function_declaration) @parent.body"),
+        "`query` must capture both `@parent.name` and `@parent.body`"
+    )]
+    #[case(
+        |d: &mut Definition| d.command.args.push("{test}".to_string()),
+        "unknown placeholder `{test}` in `command.args`"
+    )]
+    #[case(
+        |d: &mut Definition| d.command.file_args = Some(vec!["{pattern}".to_string()]),
+        "unknown placeholder `{pattern}` in `command.file_args`"
+    )]
+    fn rejects_invalid_definition(#[case] change: fn(&mut Definition), #[case] expected: &str) {
+        // arrange
+        let mut definition = definition();
+        change(&mut definition);
+
+        // act
+        let res = op::execute(&definition);
+
+        // assert
+        assert_that!(res, err(starts_with(expected)));
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::RunnableMeta;
+use crate::core::paths::{absolute, config_dir, find_ancestor};
+use crate::core::trust::TrustDatabase;
+use crate::core::types::Command;
+use crate::core::types::CursorPosition;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::declarative::definition::Definition;
+use crate::framework::declarative::operations::declarative_get_file_tests;
+use crate::framework::declarative::operations::get_project_root;
+use crate::framework::declarative::operations::load_definitions;
+use crate::framework::declarative::operations::parse_tree;
+use crate::framework::declarative::operations::render_command;
+use crate::framework::declarative::operations::validate_definition;
+use crate::treesitter::grammar::grammar;
+
+// DEFINITIONS_DIRECTORY
+//
+// Directory holding framework definitions, under the user configuration
+// directory and under `.examen` in a project.
+static DEFINITIONS_DIRECTORY: &str = "frameworks";
+
+// DeclarativeProvider
+//
+// Runs tests of a framework described by a `Definition` file: the query
+// of the definition finds the tests, its command template runs them.
+pub struct DeclarativeProvider {
+    definition: Definition,
+    language: crate_language,
+    files: GlobSet,
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+impl DeclarativeProvider {
+    pub fn new(definition: Definition) -> Result<Self, FrameworkError> {
+        let language = validate_definition::op::execute(&definition).map_err(|details| {
+            FrameworkError::ParsingError(format!(
+                "framework definition `{}`: {}",
+                definition.name, details
+            ))
+        })?;
+        let mut files = GlobSetBuilder::new();
+        for pattern in definition.files.iter() {
+            if let Ok(glob) = Glob::new(pattern) {
+                files.add(glob);
+            }
+        }
+        let files = files
+            .build()
+            .map_err(|e| FrameworkError::ParsingError(e.to_string()))?;
+
+        let prefix = match definition.capability {
+            Capability::TestRunner => "Test",
+            Capability::Debugger => "Debug",
+        };
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: definition.capability.clone(),
+            search: Search::Nearest,
+            description: format!("{} Nearest", prefix),
+        });
+        res.insert(CapabilityDetails {
+            capability: definition.capability.clone(),
+            search: Search::Method,
+            description: format!("{} Function", prefix),
+        });
+        if definition.command.file_args.is_some() {
+            res.insert(CapabilityDetails {
+                capability: definition.capability.clone(),
+                search: Search::File,
+                description: format!("{} File", prefix),
+            });
+        }
+
+        Ok(Self {
+            definition,
+            language,
+            files,
+            search_capabilities: res,
+        })
+    }
+
+    // load
+    //
+    // Providers for the definitions found in the directories, in order, so
+    // a definition of a later directory replaces a same named one once
+    // registered. Definitions failing to load are reported, not registered,
+    // as are definitions the trust database, when given, does not trust.
+    pub fn load(
+        directories: &[PathBuf],
+        trust: Option<&TrustDatabase>,
+    ) -> (Vec<DeclarativeProvider>, Vec<FrameworkError>) {
+        let mut providers = vec![];
+        let mut errors = vec![];
+        for definition in directories
+            .iter()
+            .flat_map(|directory| load_definitions::op::execute(directory, trust))
+        {
+            match definition.and_then(DeclarativeProvider::new) {
+                Ok(provider) => providers.push(provider),
+                Err(e) => errors.push(e),
+            }
+        }
+        (providers, errors)
+    }
+
+    // user_directories
+    //
+    // Directories of the definitions written by the user, trusted as is.
+    pub fn user_directories() -> Vec<PathBuf> {
+        config_dir()
+            .map(|config| config.join(DEFINITIONS_DIRECTORY))
+            .into_iter()
+            .collect()
+    }
+
+    // project_directories
+    //
+    // Directories of the definitions shipped by the closest project holding
+    // `.examen/frameworks` from `start`, loaded once trusted.
+    pub fn project_directories(start: &Path) -> Vec<PathBuf> {
+        find_ancestor(&absolute(start), |dir| {
+            dir.join(".examen").join(DEFINITIONS_DIRECTORY).is_dir()
+        })
+        .map(|project| project.join(".examen").join(DEFINITIONS_DIRECTORY))
+        .into_iter()
+        .collect()
+    }
+
+    fn matches_file(&self, filepath: &str) -> bool {
+        let project = get_project_root::op::execute(filepath, &self.definition.root_markers);
+        let file_name = Path::new(filepath)
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.files.is_match(file_name) || self.files.is_match(project.relative_path(filepath))
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the innermost test under the cursor.
+         *       Outside of a test, fall back to the innermost parent holding the cursor
+         *   - Search set to method, return the test under the cursor
+         *   - Search set to file, return the whole file when the definition runs files
+         * */
+        let root = tree.root_node();
+        let grammar = grammar(&self.language, &target.buffer.filepath).ok_or_else(|| {
+            FrameworkError::PreconditionError(format!("{} has no grammar", self.definition.name))
+        })?;
+        let position = target.buffer.position;
+
+        if let Search::File = target.search_strategy {
+            if self.definition.command.file_args.is_none() {
+                return Err(FrameworkError::PreconditionError(format!(
+                    "{} does not define `command.file_args`",
+                    self.definition.name
+                )));
+            }
+            return Ok(vec![Runnable {
+                name: String::new(),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(root.start_position()),
+                    end: CursorPosition::from_point(root.end_position()),
+                },
+                meta: RunnableMeta::default_declarative(),
+            }]);
+        }
+
+        let blocks = declarative_get_file_tests::op::blocks(
+            root,
+            target.buffer.content,
+            &self.definition,
+            &grammar,
+        )
+        .unwrap_or_default();
+        let innermost = |parent: bool| {
+            blocks
+                .iter()
+                .filter(|b| b.parent == parent)
+                .filter(|b| position.in_range(b.node.start_position()..b.node.end_position()))
+                .max_by_key(|b| b.node.start_byte())
+        };
+
+        let block = match target.search_strategy {
+            Search::Method => innermost(false),
+            _ => innermost(false).or_else(|| innermost(true)),
+        };
+        block
+            .map(|b| vec![declarative_get_file_tests::op::runnable(b, target)])
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!(
+                    "{} test not found at position",
+                    self.definition.name
+                ))
+            })
+    }
+}
+
+impl FrameworkProvider for DeclarativeProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(DeclarativeProvider {
+            definition: self.definition.clone(),
+            language: self.language.clone(),
+            files: self.files.clone(),
+            search_capabilities: self.search_capabilities.clone(),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn language(&self) -> crate_language {
+        self.language.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.definition.capability.clone()
+    }
+
+    // priority
+    //
+    // A definition is written for the project at hand, so it ranks above
+    // the built-in frameworks detecting the same file.
+    fn priority(&self) -> u8 {
+        10
+    }
+}
+
+impl Framework for DeclarativeProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.definition.capability {
+            return Detection::rejected("capability not requested");
+        }
+        if !self.matches_file(&target.buffer.filepath) {
+            return Detection::rejected(format!(
+                "file matches none of `files` {}",
+                self.definition.files.join(", ")
+            ));
+        }
+        let Some(grammar) = grammar(&self.language, &target.buffer.filepath) else {
+            return Detection::rejected("no grammar for the language");
+        };
+        let Ok(tree) = parse_tree::op::execute(
+            target.buffer.content,
+            &self.language,
+            &target.buffer.filepath,
+        ) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if declarative_get_file_tests::op::execute(
+            tree.root_node(),
+            target,
+            &self.definition,
+            &grammar,
+        )
+        .is_none()
+        {
+            return Detection::rejected("`query` found no tests");
+        }
+
+        Detection::matched(70, "file matches `files` and `query` found tests")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(
+            target.buffer.content,
+            &self.language,
+            &target.buffer.filepath,
+        )?;
+        let mut res = self.search(&tree, target)?;
+        let project =
+            get_project_root::op::execute(&target.buffer.filepath, &self.definition.root_markers);
+        for runnable in res.iter_mut() {
+            if runnable.name.is_empty() {
+                runnable.name = project.relative_path(&target.buffer.filepath);
+            }
+            runnable
+                .meta
+                .set_declarative_root(project.root.to_string_lossy().to_string());
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        let meta = runnable.meta.get_declarative_meta();
+        let project = match meta.as_ref() {
+            Some(meta) if !meta.root.is_empty() => get_project_root::op::Project {
+                root: PathBuf::from(&meta.root),
+            },
+            _ => get_project_root::op::execute(&runnable.filepath, &self.definition.root_markers),
+        };
+        let line = meta.as_ref().and_then(|m| m.line);
+        let exact = meta.as_ref().is_none_or(|m| m.exact);
+
+        let mut values = HashMap::from([
+            ("root", project.root.to_string_lossy().to_string()),
+            ("file", project.relative_path(&runnable.filepath)),
+            ("dir", project.relative_directory(&runnable.filepath)),
+        ]);
+        let template = match (line, self.definition.command.file_args.as_ref()) {
+            (None, Some(file_args)) => file_args,
+            _ => {
+                let escaped = regex::escape(&runnable.name);
+                let pattern = if exact {
+                    format!("^{}$", escaped)
+                } else {
+                    format!(
+                        "^{}{}",
+                        escaped,
+                        regex::escape(&self.definition.names.separator)
+                    )
+                };
+                values.insert("name", runnable.name.to_string());
+                values.insert("pattern", pattern);
+                values.insert("line", line.map(|l| l.to_string()).unwrap_or_default());
+                &self.definition.command.args
+            }
+        };
+
+        Command {
+            command: self.definition.command.program.to_string(),
+            args: render_command::op::execute(template, &values),
+            ..Default::default()
+        }
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::{Framework, FrameworkProvider};
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::declarative::provider,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const FILE: &str = "suite/pkg/calculator/calculator_test.go";
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/declarative/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    fn provider(name: &str) -> provider::DeclarativeProvider {
+        let (providers, _) =
+            provider::DeclarativeProvider::load(&[PathBuf::from(fixture("user/frameworks"))], None);
+        providers.into_iter().find(|p| p.name() == name).unwrap()
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(9, 3),
+        "Calculator adds"
+    )]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(11, 0),
+        "Calculator"
+    )]
+    #[case(
+        enums::Search::Method,
+        types::CursorPosition::new(14, 3),
+        "Calculator with negatives keeps the sign"
+    )]
+    #[case(
+        enums::Search::File,
+        types::CursorPosition::new(0, 0),
+        "pkg/calculator/calculator_test.go"
+    )]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_name: &str,
+    ) {
+        // arrange
+        let content = fs::read_to_string(fixture(FILE)).unwrap();
+        let buffer = Buffer::new(&content, fixture(FILE), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = provider("Ginkgo");
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        assert_that!(names, container_eq(vec![expected_test_name.to_string()]));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let content = fs::read_to_string(fixture(FILE)).unwrap();
+        let buffer = Buffer::new(&content, fixture(FILE), types::CursorPosition::new(0, 0));
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Method);
+        let provider = provider("Ginkgo");
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(9, 3),
+        vec!["--focus", "^Calculator adds$", "./pkg/calculator"]
+    )]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(11, 0),
+        vec!["--focus", "^Calculator ", "./pkg/calculator"]
+    )]
+    #[case(enums::Search::File, types::CursorPosition::new(0, 0), vec!["./pkg/calculator"])]
+    fn generate_command(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_args: Vec<&str>,
+    ) {
+        // arrange
+        let content = fs::read_to_string(fixture(FILE)).unwrap();
+        let buffer = Buffer::new(&content, fixture(FILE), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = provider("Ginkgo");
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq("ginkgo"));
+        let expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        assert_that!(cmd.args, container_eq(expected));
+    }
+
+    #[gtest]
+    fn generate_command_with_name() {
+        // arrange
+        let content = "feature(\"checkout\", () => {\n  scenario(\"pays by card\", () => {})\n})\n";
+        let filepath = "web/scenarios/checkout.js".to_string();
+        let buffer = Buffer::new(content, filepath, types::CursorPosition::new(1, 4));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider("Scenario");
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq("node"));
+        assert_that!(
+            cmd.args,
+            elements_are![
+                eq("scripts/scenarios.js"),
+                eq("checkout.js"),
+                eq("--only"),
+                eq("checkout > pays by card")
+            ]
+        );
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "pkg/calculator_test.go", true)]
+    #[case(enums::Capability::Debugger, "pkg/calculator_test.go", false)]
+    #[case(enums::Capability::TestRunner, "pkg/calculator.go", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] expected: bool,
+    ) {
+        let content = fs::read_to_string(fixture(FILE)).unwrap();
+        let buffer = Buffer::new(
+            &content,
+            filepath.to_string(),
+            types::CursorPosition::new(9, 3),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = provider("Ginkgo");
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+
+    #[gtest]
+    fn load_reports_invalid_definitions() {
+        // arrange
+        let directories = vec![
+            PathBuf::from(fixture("user/frameworks")),
+            PathBuf::from(fixture("project/.examen/frameworks")),
+        ];
+
+        // act
+        let (providers, errors) = provider::DeclarativeProvider::load(&directories, None);
+
+        // assert
+        let mut names: Vec<String> = providers.iter().map(|p| p.name().to_string()).collect();
+        names.sort();
+        assert_that!(
+            names,
+            container_eq(vec!["Ginkgo".to_string(), "Scenario".to_string()])
+        );
+        assert_that!(errors.len(), eq(1));
+    }
+
+    #[gtest]
+    fn user_directories_live_in_config_directory() {
+        // act
+        let res = provider::DeclarativeProvider::user_directories();
+
+        // assert
+        assert_that!(
+            res,
+            each(predicate(|p: &PathBuf| p.ends_with("examen/frameworks")))
+        );
+    }
+
+    #[gtest]
+    fn project_directories_include_closest_project() {
+        // act
+        let res = provider::DeclarativeProvider::project_directories(&PathBuf::from(fixture(
+            "project/.examen",
+        )));
+
+        // assert
+        assert_that!(
+            res,
+            elements_are![eq(&PathBuf::from(fixture("project/.examen/frameworks")))]
+        );
+    }
+}
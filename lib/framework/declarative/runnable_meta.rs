@@ -0,0 +1,37 @@
+use crate::core::metadata::RunnableMeta;
+
+impl RunnableMeta {
+    pub(in crate::framework::declarative) fn set_declarative_line(
+        &mut self,
+        value: Option<usize>,
+        value_exact: bool,
+    ) {
+        if let RunnableMeta::Declarative { line, exact, .. } = self {
+            *line = value;
+            *exact = value_exact;
+        }
+    }
+
+    pub(in crate::framework::declarative) fn set_declarative_root(&mut self, value: String) {
+        if let RunnableMeta::Declarative { root, .. } = self {
+            *root = value;
+        }
+    }
+
+    pub(in crate::framework::declarative) fn get_declarative_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::Declarative { root, line, exact } = self {
+            return Some(Meta {
+                root: root.clone(),
+                line: *line,
+                exact: *exact,
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::declarative) struct Meta {
+    pub(in crate::framework::declarative) root: String,
+    pub(in crate::framework::declarative) line: Option<usize>,
+    pub(in crate::framework::declarative) exact: bool,
+}
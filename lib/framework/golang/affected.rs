@@ -0,0 +1,427 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use mlua::{Lua, Table};
+
+use crate::core::config::Config;
+use crate::core::errors::FrameworkError;
+use crate::core::git;
+use crate::core::paths::{absolute, find_ancestor};
+use crate::core::types::Command;
+use crate::framework::golang::gotest::with_go_config;
+use crate::framework::golang::operations::{get_imports, get_module_path, parse_tree};
+
+const GO_MOD: &str = "go.mod";
+const GO_SUM: &str = "go.sum";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct GoPackage {
+    pub(crate) directory: PathBuf,
+    // import paths of its files, test files included
+    pub(crate) imports: BTreeSet<String>,
+    pub(crate) has_tests: bool,
+}
+
+// ImportGraph
+//
+// The packages of a go module by import path, along with what they import.
+// Directories go ignores, `vendor`, `testdata` and those starting with `.`
+// or `_`, are left out, as are nested modules.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ImportGraph {
+    pub(crate) root: PathBuf,
+    // e.g. `example.com/shop`
+    pub(crate) module: String,
+    pub(crate) packages: BTreeMap<String, GoPackage>,
+}
+
+impl ImportGraph {
+    pub(crate) fn load(root: &Path) -> Result<Self, FrameworkError> {
+        let go_mod = root.join(GO_MOD);
+        let contents = fs::read_to_string(&go_mod).map_err(|e| {
+            FrameworkError::PreconditionError(format!("{}: {}", go_mod.display(), e))
+        })?;
+        let module = get_module_path::op::execute(&contents).ok_or_else(|| {
+            FrameworkError::ParsingError(format!("{}: no `module` directive", go_mod.display()))
+        })?;
+        let mut res = Self {
+            root: root.to_path_buf(),
+            module,
+            packages: BTreeMap::new(),
+        };
+        res.walk(root);
+        Ok(res)
+    }
+
+    fn walk(&mut self, directory: &Path) {
+        let Ok(entries) = fs::read_dir(directory) else {
+            return;
+        };
+        let mut entries: Vec<fs::DirEntry> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        let mut package: Option<GoPackage> = None;
+        for entry in entries {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || name.starts_with('_') {
+                continue;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if name != "vendor" && name != "testdata" && !path.join(GO_MOD).is_file() {
+                    self.walk(&path);
+                }
+                continue;
+            }
+            if !name.ends_with(".go") {
+                continue;
+            }
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let package = package.get_or_insert_with(|| GoPackage {
+                directory: directory.to_path_buf(),
+                imports: BTreeSet::new(),
+                has_tests: false,
+            });
+            if let Ok(tree) = parse_tree::op::execute(&content) {
+                package
+                    .imports
+                    .extend(get_imports::op::execute(tree.root_node(), &content));
+            }
+            package.has_tests |= name.ends_with("_test.go");
+        }
+        if let Some(package) = package {
+            self.packages.insert(self.import_path(directory), package);
+        }
+    }
+
+    // import_path
+    //
+    // The import path of a directory of the module.
+    pub(crate) fn import_path(&self, directory: &Path) -> String {
+        let relative: Vec<String> = directory
+            .strip_prefix(&self.root)
+            .map(|relative| {
+                relative
+                    .components()
+                    .filter_map(|c| match c {
+                        Component::Normal(c) => Some(c.to_string_lossy().to_string()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        match relative.is_empty() {
+            true => self.module.to_string(),
+            false => format!("{}/{}", self.module, relative.join("/")),
+        }
+    }
+
+    // changed_packages
+    //
+    // The packages the changed files belong to, every package when the
+    // module requirements changed. Go files count even once deleted, other
+    // files only when in a package directory or its `testdata`.
+    pub(crate) fn changed_packages(&self, files: &[PathBuf]) -> BTreeSet<String> {
+        let mut res = BTreeSet::new();
+        for file in files {
+            let Ok(relative) = file.strip_prefix(&self.root) else {
+                continue;
+            };
+            if relative == Path::new(GO_MOD) || relative == Path::new(GO_SUM) {
+                return self.packages.keys().cloned().collect();
+            }
+            let Some(directory) = file.parent() else {
+                continue;
+            };
+            // files of nested modules and vendored packages are not ours
+            let module = find_ancestor(directory, |dir| dir.join(GO_MOD).is_file());
+            if module.as_deref() != Some(self.root.as_path())
+                || relative.components().any(|c| c.as_os_str() == "vendor")
+            {
+                continue;
+            }
+            if file.extension().is_some_and(|e| e == "go") {
+                res.insert(self.import_path(directory));
+                continue;
+            }
+            let owner = directory
+                .ancestors()
+                .take_while(|d| d.starts_with(&self.root))
+                .find(|d| d.file_name().is_some_and(|name| name == "testdata"))
+                .and_then(|testdata| testdata.parent())
+                .unwrap_or(directory);
+            let path = self.import_path(owner);
+            if self.packages.contains_key(&path) {
+                res.insert(path);
+            }
+        }
+        res
+    }
+
+    // dependents
+    //
+    // The packages given along with every package importing them, directly
+    // or not.
+    pub(crate) fn dependents(&self, packages: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut importers: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (path, package) in self.packages.iter() {
+            for import in package.imports.iter() {
+                importers.entry(import.as_str()).or_default().push(path);
+            }
+        }
+        let mut res = BTreeSet::new();
+        let mut queue: VecDeque<&str> = packages.iter().map(|p| p.as_str()).collect();
+        while let Some(path) = queue.pop_front() {
+            if !res.insert(path.to_string()) {
+                continue;
+            }
+            queue.extend(importers.get(path).into_iter().flatten());
+        }
+        res
+    }
+}
+
+// AffectedTests
+//
+// The tested packages a change may break, and the `go test` running them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AffectedTests {
+    // packages holding changed files
+    pub changed: Vec<String>,
+    // packages with tests among the changed ones and their dependents
+    pub packages: Vec<String>,
+    pub command: Command,
+}
+
+impl AffectedTests {
+    // to_lua
+    //
+    // The packages, and the single `go test` running them as
+    // `{ cmd = "go", args = { ... }, cwd = ..., env = { ... } }`.
+    pub fn to_lua(&self, lua: &Lua) -> mlua::Result<Table> {
+        let res = lua.create_table()?;
+        res.set("changed", self.changed.clone())?;
+        res.set("packages", self.packages.clone())?;
+        let command = lua.create_table()?;
+        command.set("cmd", self.command.command.as_str())?;
+        command.set("args", self.command.args.clone())?;
+        command.set(
+            "cwd",
+            self.command
+                .cwd
+                .as_ref()
+                .map(|cwd| cwd.display().to_string()),
+        )?;
+        command.set("env", self.command.env.set.clone())?;
+        res.set("command", command)?;
+        Ok(res)
+    }
+}
+
+// affected_tests
+//
+// Selects the tests of the module holding `start` that depend on the files
+// changed in the working tree since HEAD, or since it forked from `base`.
+pub(crate) fn affected_tests(
+    start: &Path,
+    base: Option<&str>,
+    config: &Config,
+) -> Result<AffectedTests, FrameworkError> {
+    let start = absolute(start);
+    let root = find_ancestor(&start, |dir| dir.join(GO_MOD).is_file()).ok_or_else(|| {
+        FrameworkError::PreconditionError(format!("no `{}` above {}", GO_MOD, start.display()))
+    })?;
+    // git reports resolved paths
+    let root = root.canonicalize().unwrap_or(root);
+    let files = git::changed_files(&root, base)?;
+    let graph = ImportGraph::load(&root)?;
+    let changed = graph.changed_packages(&files);
+    let packages: Vec<String> = graph
+        .dependents(&changed)
+        .into_iter()
+        .filter(|path| graph.packages.get(path).is_some_and(|p| p.has_tests))
+        .collect();
+    if packages.is_empty() {
+        return Err(FrameworkError::NotFoundError(
+            "no tested package depends on the changes".to_string(),
+        ));
+    }
+
+    let cmd = Command {
+        command: "go".to_string(),
+        args: vec!["test".to_string()],
+        cwd: Some(root),
+        ..Default::default()
+    }
+    .with_config(config);
+    let mut command = with_go_config(cmd, config, vec![]);
+    command.args.extend(packages.iter().cloned());
+    Ok(AffectedTests {
+        changed: changed.into_iter().collect(),
+        packages,
+        command,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::{ImportGraph, affected_tests};
+    use crate::core::config::Config;
+    use crate::core::errors::FrameworkError;
+    use crate::core::git::test::{repository, write};
+
+    fn fixture() -> PathBuf {
+        PathBuf::from(format!(
+            "{}/lib/fixtures/affected/shop",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+    }
+
+    fn set(paths: &[&str]) -> BTreeSet<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[gtest]
+    fn loads_module_packages() {
+        // act
+        let res = ImportGraph::load(&fixture()).unwrap();
+
+        // assert
+        assert_that!(res.module, eq("example.com/shop"));
+        assert_that!(
+            res.packages.keys().cloned().collect::<BTreeSet<String>>(),
+            eq(&set(&[
+                "example.com/shop/api",
+                "example.com/shop/cart",
+                "example.com/shop/cmd/shop",
+                "example.com/shop/money",
+                "example.com/shop/report",
+            ]))
+        );
+        let cart = &res.packages["example.com/shop/cart"];
+        assert_that!(
+            cart.imports,
+            eq(&set(&[
+                "example.com/shop/cart",
+                "example.com/shop/money",
+                "testing"
+            ]))
+        );
+        assert_that!(cart.has_tests, eq(true));
+        assert_that!(res.packages["example.com/shop/report"].has_tests, eq(false));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "money/money.go",
+        &["example.com/shop/api", "example.com/shop/cart", "example.com/shop/cmd/shop", "example.com/shop/money", "example.com/shop/report"]
+    )]
+    #[case("cart/testdata/empty.json", &["example.com/shop/api", "example.com/shop/cart", "example.com/shop/cmd/shop"])]
+    #[case("api/removed.go", &["example.com/shop/api", "example.com/shop/cmd/shop"])]
+    #[case("README.md", &[])]
+    #[case("tools/tools_test.go", &[])]
+    #[case("vendor/example.com/ext/ext.go", &[])]
+    fn finds_dependents_of_changes(#[case] file: &str, #[case] expected: &[&str]) {
+        // arrange
+        let graph = ImportGraph::load(&fixture()).unwrap();
+
+        // act
+        let res = graph.dependents(&graph.changed_packages(&[fixture().join(file)]));
+
+        // assert
+        assert_that!(res, eq(&set(expected)));
+    }
+
+    #[gtest]
+    fn module_requirements_change_every_package() {
+        // arrange
+        let graph = ImportGraph::load(&fixture()).unwrap();
+
+        // act
+        let res = graph.changed_packages(&[fixture().join("go.sum")]);
+
+        // assert
+        assert_that!(res.len(), eq(5));
+    }
+
+    #[gtest]
+    fn tests_packages_depending_on_working_tree_changes() {
+        // arrange
+        let repository = repository(
+            "affected",
+            &[
+                ("go.mod", "module example.com/app\n"),
+                ("lib/lib.go", "package lib\n"),
+                ("lib/lib_test.go", "package lib\n"),
+                (
+                    "web/web_test.go",
+                    "package web\n\nimport \"example.com/app/lib\"\n",
+                ),
+                ("cli/cli_test.go", "package cli\n"),
+            ],
+        );
+        write(
+            &repository,
+            "lib/lib.go",
+            "package lib\n\nvar Changed = true\n",
+        );
+        let config = Config::default();
+
+        // act
+        let res = affected_tests(&repository.join("cli"), None, &config).unwrap();
+
+        // assert
+        assert_that!(res.changed, elements_are![eq("example.com/app/lib")]);
+        assert_that!(
+            res.command.args,
+            elements_are![
+                eq("test"),
+                eq("example.com/app/lib"),
+                eq("example.com/app/web")
+            ]
+        );
+        assert_that!(
+            res.command.cwd.as_deref(),
+            some(eq(repository.canonicalize().unwrap().as_path()))
+        );
+        let _ = fs::remove_dir_all(&repository);
+    }
+
+    #[gtest]
+    fn reports_changes_without_tests() {
+        // arrange
+        let repository = repository(
+            "untested",
+            &[
+                ("go.mod", "module example.com/app\n"),
+                ("main.go", "package main\n"),
+            ],
+        );
+        write(&repository, "main.go", "package main\n\nfunc main() {}\n");
+
+        // act
+        let res = affected_tests(Path::new(&repository), None, &Config::default());
+
+        // assert
+        assert_that!(
+            res,
+            err(eq(&FrameworkError::NotFoundError(
+                "no tested package depends on the changes".to_string()
+            )))
+        );
+        let _ = fs::remove_dir_all(&repository);
+    }
+}
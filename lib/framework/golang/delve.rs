@@ -0,0 +1,286 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use mlua::{Lua, LuaSerdeExt};
+use serde::Serialize;
+
+use crate::core::config::{Config, ConfigSource};
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::paths::absolute;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::golang::gotest::GotestProvider;
+use crate::framework::golang::operations::detect_gotest_file;
+use crate::framework::golang::operations::get_package_directory;
+use crate::framework::golang::operations::gotest_run_pattern;
+use crate::framework::golang::operations::parse_tree;
+
+// DelveProvider
+//
+// Debugs go tests through delve. Runnables are discovered exactly like
+// `GotestProvider` does, so the subtest under the cursor is the one that
+// ends up in the debugger.
+pub struct DelveProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+    gotest: GotestProvider,
+    config: ConfigSource,
+}
+
+static FILE_SUFFIX: &str = "_test.go";
+
+// LaunchConfiguration
+//
+// nvim-dap launch configuration for the `go` adapter (delve in dap mode).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchConfiguration {
+    #[serde(rename = "type")]
+    pub adapter: String,
+    pub name: String,
+    pub request: String,
+    pub mode: String,
+    pub program: String,
+    pub args: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub build_flags: String,
+    // working directory the configuration sets, see `Config`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    // variables the configuration sets
+    pub env: BTreeMap<String, String>,
+}
+
+impl LaunchConfiguration {
+    pub fn to_lua_table(&self, lua: &Lua) -> mlua::Result<mlua::Value> {
+        lua.to_value(self)
+    }
+}
+
+impl DelveProvider {
+    pub fn new() -> Self {
+        Self::with_config_source(ConfigSource::user())
+    }
+
+    // with_config_source
+    //
+    // Reads the configuration of debug sessions from `config` rather than
+    // from the files of the user.
+    pub(crate) fn with_config_source(config: ConfigSource) -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::Debugger,
+            search: Search::Nearest,
+            description: "Debug Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::Debugger,
+            search: Search::Method,
+            description: "Debug Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::Debugger,
+            search: Search::File,
+            description: "Debug File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+            gotest: GotestProvider::with_config_source(config.clone()),
+            config,
+        }
+    }
+
+    // launch_configuration
+    //
+    // The nvim-dap configuration debugging the runnables of a search with
+    // the build tags, working directory and environment of `config`.
+    pub fn launch_configuration(
+        &self,
+        runnables: &[Runnable],
+        config: &Config,
+    ) -> Result<LaunchConfiguration, FrameworkError> {
+        let runnable = runnables
+            .first()
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to debug".to_string()))?;
+        let (program, build_tags) = Self::package_and_tags(runnable, config);
+        let mut build_flags = String::new();
+        if !build_tags.is_empty() {
+            build_flags = format!("-tags={}", build_tags.join(","));
+        }
+        let name = match runnables {
+            [runnable] => runnable.name.to_string(),
+            _ => runnable.filepath.to_string(),
+        };
+
+        Ok(LaunchConfiguration {
+            adapter: "go".to_string(),
+            name: format!("Debug {}", name),
+            request: "launch".to_string(),
+            mode: "test".to_string(),
+            program,
+            args: vec!["-test.run".to_string(), Self::run_pattern(runnables)],
+            build_flags,
+            cwd: config.cwd.as_ref().map(|cwd| cwd.display().to_string()),
+            env: config.environment().set,
+        })
+    }
+
+    // run_pattern
+    //
+    // The `-test.run` expression of a runnable, or of the tests holding
+    // several runnables.
+    fn run_pattern(runnables: &[Runnable]) -> String {
+        match runnables {
+            [runnable] => gotest_run_pattern::op::execute(&runnable.name),
+            _ => gotest_run_pattern::op::any(
+                &runnables
+                    .iter()
+                    .map(|r| r.name.as_str())
+                    .collect::<Vec<&str>>(),
+            ),
+        }
+    }
+
+    // command
+    //
+    // `dlv test` running the runnables of a search.
+    fn command(&self, runnables: &[Runnable]) -> Result<Command, FrameworkError> {
+        let runnable = runnables
+            .first()
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to debug".to_string()))?;
+        let config = self.config.load(Path::new(&runnable.filepath))?;
+        let (package, build_tags) = Self::package_and_tags(runnable, &config);
+        let mut cmd = Command {
+            command: "dlv".to_string(),
+            args: vec!["test".to_string(), package],
+            ..Default::default()
+        };
+
+        if !build_tags.is_empty() {
+            cmd.args
+                .push(format!("--build-flags=-tags={}", build_tags.join(",")));
+        }
+
+        cmd.args.push("--".to_string());
+        cmd.args.push("-test.run".to_string());
+        cmd.args.push(Self::run_pattern(runnables));
+        Ok(cmd)
+    }
+
+    // package_and_tags
+    //
+    // The package of the runnable, absolute once the configuration moves the
+    // working directory away from the editor's, and its build tags joined to
+    // the configured ones.
+    fn package_and_tags(runnable: &Runnable, config: &Config) -> (String, Vec<String>) {
+        let (package, mut build_tags) = match runnable.meta.get_meta() {
+            Some(meta) if !meta.package.is_empty() => (meta.package, meta.build_tags),
+            Some(meta) => (
+                get_package_directory::op::execute(&runnable.filepath),
+                meta.build_tags,
+            ),
+            None => (
+                get_package_directory::op::execute(&runnable.filepath),
+                vec![],
+            ),
+        };
+        for tag in config.go.build_tags.iter() {
+            if !build_tags.contains(tag) {
+                build_tags.push(tag.to_string());
+            }
+        }
+        let package = match config.cwd {
+            Some(_) => absolute(Path::new(&package)).display().to_string(),
+            None => package,
+        };
+        (package, build_tags)
+    }
+}
+
+impl FrameworkProvider for DelveProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(DelveProvider::with_config_source(self.config.clone()))
+    }
+
+    fn name(&self) -> &'static str {
+        "Delve"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Golang
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::Debugger
+    }
+}
+
+impl Framework for DelveProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("starts debug sessions, not test runs");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if !detect_gotest_file::op::execute(tree.root_node(), target.buffer.content) {
+            return Detection::rejected("file does not import `testing`");
+        }
+
+        Detection::matched(50, format!("`{}` file importing `testing`", FILE_SUFFIX))
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        self.gotest.runnables(target)
+    }
+
+    fn all_runnables(&self, filepath: &str, content: &str) -> Vec<Runnable> {
+        self.gotest.all_runnables(filepath, content)
+    }
+
+    // generate_command
+    //
+    // An unreadable configuration yields an empty command, see
+    // `try_generate_command` for its error.
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.try_generate_command(runnable).unwrap_or_default()
+    }
+
+    fn try_generate_command(&self, runnable: Runnable) -> Result<Command, FrameworkError> {
+        self.command(&[runnable])
+    }
+
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        self.command(&runnables)
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,269 @@
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::core::config::{Config, ConfigSource};
+    use crate::core::enums;
+    use crate::core::traits::Framework;
+    use crate::core::trust::TrustDatabase;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::golang::delve,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use rstest::rstest;
+
+    // provider
+    //
+    // A provider reading no configuration file.
+    fn provider() -> delve::DelveProvider {
+        delve::DelveProvider::with_config_source(ConfigSource::default())
+    }
+
+    const CONTENT: &str = r#"//go:build integration
+
+        package golang
+        import (
+          "testing"
+
+          "github.com/stretchr/testify/assert"
+        )
+
+        func sample_add(a, b int) int {
+          return a + b
+        }
+
+        func TestSample(t *testing.T) {
+            t.Run("case a", func(t *testing.T){
+              assert.Equal(t, 1, sample_add(1, 0))
+            })
+            t.Run("case b", func(t *testing.T){
+              assert.Equal(t, 2, sample_add(1, 2))
+            })
+        }
+        "#;
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(15, 3),
+        "^TestSample$/^case_a$"
+    )]
+    #[case(
+        enums::Search::Nearest,
+        types::CursorPosition::new(18, 3),
+        "^TestSample$/^case_b$"
+    )]
+    #[case(
+        enums::Search::Method,
+        types::CursorPosition::new(18, 3),
+        "^TestSample$"
+    )]
+    fn generate_command(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_pattern: &str,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, "pkg/math/run_test.go".to_string(), position);
+        let mut target = Target::new(enums::Capability::Debugger, buffer);
+        target.override_search_strategy(search);
+        let provider = provider();
+        let res = provider.runnables(&target);
+        assert_that!(res.is_ok(), eq(true));
+        let runnable = res.unwrap().first().cloned();
+        assert_that!(runnable, some(anything()));
+
+        // act
+        let cmd = provider.generate_command(runnable.unwrap());
+
+        // assert
+        assert_that!(cmd.command, eq("dlv"));
+        assert_that!(
+            cmd.args,
+            elements_are![
+                eq("test"),
+                eq("./pkg/math"),
+                eq("--build-flags=-tags=integration"),
+                eq("--"),
+                eq("-test.run"),
+                eq(expected_pattern)
+            ]
+        );
+    }
+
+    #[gtest]
+    fn launch_configuration() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            "pkg/math/run_test.go".to_string(),
+            types::CursorPosition::new(15, 3),
+        );
+        let target = Target::new(enums::Capability::Debugger, buffer);
+        let provider = provider();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let config = provider
+            .launch_configuration(std::slice::from_ref(&runnable), &Config::default())
+            .unwrap();
+
+        // assert
+        assert_that!(config.adapter, eq("go"));
+        assert_that!(config.request, eq("launch"));
+        assert_that!(config.mode, eq("test"));
+        assert_that!(config.program, eq("./pkg/math"));
+        assert_that!(config.build_flags, eq("-tags=integration"));
+        assert_that!(
+            config.args,
+            elements_are![eq("-test.run"), eq("^TestSample$/^case_a$")]
+        );
+    }
+
+    #[gtest]
+    fn debugs_every_test_of_the_file() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            "pkg/math/run_test.go".to_string(),
+            types::CursorPosition::new(15, 3),
+        );
+        let mut target = Target::new(enums::Capability::Debugger, buffer);
+        target.override_search_strategy(enums::Search::File);
+        let provider = provider();
+        let runnables = provider.runnables(&target).unwrap();
+
+        // act
+        let config = provider
+            .launch_configuration(&runnables, &Config::default())
+            .unwrap();
+        let cmd = provider.try_generate_combined_command(runnables).unwrap();
+
+        // assert
+        assert_that!(config.name, eq("Debug pkg/math/run_test.go"));
+        assert_that!(
+            config.args,
+            elements_are![eq("-test.run"), eq("^(TestSample)$")]
+        );
+        assert_that!(cmd.args.last(), some(eq("^(TestSample)$")));
+    }
+
+    #[gtest]
+    fn launch_configuration_follows_config() {
+        // arrange
+        let project = format!("{}/lib/fixtures/config/project", env!("CARGO_MANIFEST_DIR"));
+        let filepath = format!("{}/pkg/app/app_test.go", project);
+        let trust = std::env::temp_dir().join(format!("examen-delve-trust-{}", std::process::id()));
+        let _ = std::fs::remove_file(&trust);
+        let mut database = TrustDatabase::load(&trust).unwrap();
+        for file in [".examen.toml", ".env"] {
+            database
+                .allow(Path::new(&format!("{}/{}", project, file)))
+                .unwrap();
+        }
+        let source = ConfigSource {
+            user: None,
+            trust: Some(trust.clone()),
+        };
+        let provider = delve::DelveProvider::with_config_source(source.clone());
+        let content = std::fs::read_to_string(&filepath).unwrap();
+        let buffer = Buffer::new(&content, filepath.clone(), types::CursorPosition::new(5, 2));
+        let target = Target::new(enums::Capability::Debugger, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let config = provider
+            .launch_configuration(
+                std::slice::from_ref(&runnable),
+                &source.load(Path::new(&filepath)).unwrap(),
+            )
+            .unwrap();
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let package = format!("{}/pkg/app", project);
+        assert_that!(config.program, eq(&package));
+        assert_that!(config.build_flags, eq("-tags=integration"));
+        assert_that!(config.cwd, some(eq(&format!("{}/pkg", project))));
+        assert_that!(
+            cmd.args,
+            elements_are![
+                eq("test"),
+                eq(&package),
+                eq("--build-flags=-tags=integration"),
+                eq("--"),
+                eq("-test.run"),
+                eq("^TestApp$")
+            ]
+        );
+        // the engine gives the command its working directory and environment
+        assert_that!(cmd.cwd, none());
+        assert_that!(
+            config.env.get("GOFLAGS").map(String::as_str),
+            some(eq("-count=1"))
+        );
+        assert_that!(
+            config.env.get("DATABASE_URL").map(String::as_str),
+            some(eq("postgres://localhost:5432/app?sslmode=disable"))
+        );
+        let _ = std::fs::remove_file(&trust);
+    }
+
+    #[gtest]
+    fn launch_configuration_to_lua_table() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            "run_test.go".to_string(),
+            types::CursorPosition::new(18, 3),
+        );
+        let target = Target::new(enums::Capability::Debugger, buffer);
+        let provider = provider();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+        let lua = Lua::new();
+
+        // act
+        let value = provider
+            .launch_configuration(std::slice::from_ref(&runnable), &Config::default())
+            .unwrap()
+            .to_lua_table(&lua)
+            .unwrap();
+
+        // assert
+        let table = value.as_table().unwrap();
+        assert_that!(table.get::<String>("type").unwrap(), eq("go"));
+        assert_that!(table.get::<String>("program").unwrap(), eq("."));
+        assert_that!(
+            table.get::<String>("buildFlags").unwrap(),
+            eq("-tags=integration")
+        );
+        let args: Table = table.get("args").unwrap();
+        assert_that!(args.get::<String>(2).unwrap(), eq("^TestSample$/^case_b$"));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::Debugger, "run_test.go", true)]
+    #[case(enums::Capability::TestRunner, "run_test.go", false)]
+    #[case(enums::Capability::Debugger, "run.go", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            CONTENT,
+            filepath.to_string(),
+            types::CursorPosition::new(15, 3),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = provider();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -1,29 +1,52 @@
 use std::collections::HashSet;
+use std::path::Path;
 
+use tree_sitter::Tree;
+
+use crate::core::config::{Config, ConfigSource};
 use crate::core::enums::Language as crate_language;
 use crate::core::errors::FrameworkError;
+use crate::core::paths::absolute;
+use crate::core::types::Buffer;
 use crate::core::types::Command;
+use crate::core::types::CursorPosition;
+use crate::core::types::Detection;
 use crate::core::types::Runnable;
 use crate::core::types::Target;
 use crate::core::{
-    enums::Capability,
+    enums::{Capability, Search},
     traits::{Framework, FrameworkProvider},
     types::CapabilityDetails,
 };
 use crate::framework::golang::operations::detect_gotest_file;
+use crate::framework::golang::operations::get_build_tags;
+use crate::framework::golang::operations::get_package_directory;
+use crate::framework::golang::operations::gotest_get_file_test_methods;
 use crate::framework::golang::operations::gotest_get_file_tests;
 use crate::framework::golang::operations::gotest_get_subtests;
 use crate::framework::golang::operations::gotest_get_test;
+use crate::framework::golang::operations::gotest_run_pattern;
 use crate::framework::golang::operations::parse_tree;
+use crate::framework::golang::treesitter;
+use crate::treesitter::query_file;
 
 pub struct GotestProvider {
     search_capabilities: HashSet<CapabilityDetails>,
+    config: ConfigSource,
 }
 
 static FILE_SUFFIX: &str = "_test.go";
 
 impl GotestProvider {
     pub fn new() -> Self {
+        Self::with_config_source(ConfigSource::user())
+    }
+
+    // with_config_source
+    //
+    // Reads the configuration applied to commands from `config` rather than
+    // from the files of the user.
+    pub(crate) fn with_config_source(config: ConfigSource) -> Self {
         let mut res = HashSet::with_capacity(3);
         res.insert(CapabilityDetails {
             capability: Capability::TestRunner,
@@ -42,62 +65,11 @@ impl GotestProvider {
         });
         Self {
             search_capabilities: res,
+            config,
         }
     }
-}
-
-impl FrameworkProvider for GotestProvider {
-    fn create(&self) -> Box<dyn Framework> {
-        Box::new(GotestProvider::new())
-    }
-
-    fn name(&self) -> &'static str {
-        "GoTest"
-    }
-
-    fn language(&self) -> crate_language {
-        crate_language::Golang
-    }
-
-    fn capability(&self) -> Capability {
-        Capability::TestRunner
-    }
-}
-
-impl Framework for GotestProvider {
-    fn detect(&self, target: &Target) -> bool {
-        let tree = parse_tree::op::execute(target.buffer.content);
-        if tree.is_err() {
-            return false;
-        }
-        let tree = tree.unwrap();
-        if !detect_gotest_file::op::execute(tree.root_node(), target.buffer.content) {
-            return false;
-        }
-        if target.category != self.capability() {
-            return false;
-        }
-
-        target.buffer.filepath.to_string().ends_with(FILE_SUFFIX)
-    }
-
-    fn generate_command(&self, runnable: Runnable) -> Command {
-        let mut cmd = Command {
-            command: "go".to_string(),
-            args: vec!["test".to_string(), "-v".to_string()],
-        };
-
-        cmd.args.push(runnable.filepath);
-        if let Some(meta) = runnable.meta.get_meta() {
-            if !meta.build_tags.is_empty() {
-                cmd.args
-                    .push(format!("-tags={}", meta.build_tags.join(",")));
-            }
-        }
-        cmd
-    }
 
-    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
         /*
          * Goals
          *   - Search set to nearest, return singular test.
@@ -106,7 +78,6 @@ impl Framework for GotestProvider {
          *   - Search set to file, return all test names in a file
          *   -
          * */
-        let tree = parse_tree::op::execute(target.buffer.content)?;
         let mut walker = tree.walk();
         walker.goto_first_child_for_point(target.buffer.position.to_point());
         let walker_node = walker.node();
@@ -162,6 +133,183 @@ impl Framework for GotestProvider {
             }
         }
     }
+}
+
+// with_meta
+//
+// Records the package and the build tags of the file on its runnables.
+fn with_meta(tree: &Tree, target: &Target, runnables: &mut [Runnable]) {
+    let build_tags =
+        get_build_tags::op::execute(tree.root_node(), target.buffer.content).unwrap_or_default();
+    let package = get_package_directory::op::execute(&target.buffer.filepath);
+    for runnable in runnables.iter_mut() {
+        runnable.meta.set_package(package.clone());
+        runnable.meta.extend_build_tags(build_tags.clone());
+    }
+}
+
+// with_go_config
+//
+// Applies the go settings of the configuration to a `go test` command: the
+// extra flags, and the build tags joined to those the tests require. Its
+// working directory and environment are left to `Command::with_config`.
+pub(crate) fn with_go_config(
+    mut cmd: Command,
+    config: &Config,
+    mut build_tags: Vec<String>,
+) -> Command {
+    for flag in config.go.flags.iter() {
+        if !cmd.args.contains(flag) {
+            cmd.args.push(flag.to_string());
+        }
+    }
+    for tag in config.go.build_tags.iter() {
+        if !build_tags.contains(tag) {
+            build_tags.push(tag.to_string());
+        }
+    }
+    if !build_tags.is_empty() {
+        cmd.args.push(format!("-tags={}", build_tags.join(",")));
+    }
+    cmd
+}
+
+impl FrameworkProvider for GotestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(GotestProvider::with_config_source(self.config.clone()))
+    }
+
+    fn name(&self) -> &'static str {
+        "GoTest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Golang
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for GotestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if !detect_gotest_file::op::execute(tree.root_node(), target.buffer.content) {
+            return Detection::rejected("file does not import `testing`");
+        }
+
+        Detection::matched(50, format!("`{}` file importing `testing`", FILE_SUFFIX))
+    }
+
+    // generate_command
+    //
+    // An unreadable configuration yields an empty command, see
+    // `try_generate_command` for its error.
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.try_generate_command(runnable).unwrap_or_default()
+    }
+
+    fn try_generate_command(&self, runnable: Runnable) -> Result<Command, FrameworkError> {
+        let mut cmd = Command {
+            command: "go".to_string(),
+            args: vec!["test".to_string(), "-v".to_string()],
+            ..Default::default()
+        };
+
+        let config = self.config.load(Path::new(&runnable.filepath))?;
+        let build_tags = runnable
+            .meta
+            .get_meta()
+            .map(|meta| meta.build_tags)
+            .unwrap_or_default();
+        cmd = with_go_config(cmd, &config, build_tags);
+        // a runnable without a name stands for every test of its file
+        if !runnable.name.is_empty() {
+            cmd.args.push("-run".to_string());
+            cmd.args
+                .push(gotest_run_pattern::op::execute(&runnable.name));
+        }
+        // the file is relative to the editor, not to a configured `cwd`
+        match config.cwd {
+            Some(_) => cmd.args.push(
+                absolute(Path::new(&runnable.filepath))
+                    .display()
+                    .to_string(),
+            ),
+            None => cmd.args.push(runnable.filepath),
+        }
+        Ok(cmd)
+    }
+
+    // try_generate_combined_command
+    //
+    // Runnables of a file run as the file.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let runnable = Runnable::combined(&runnables)
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        self.try_generate_command(runnable)
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        query_file::validate(&treesitter::queries())?;
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        with_meta(&tree, target, &mut res);
+        Ok(res)
+    }
+
+    // all_runnables
+    //
+    // Every test of the file followed by its subtests, the file parsed and
+    // its tests queried once.
+    fn all_runnables(&self, filepath: &str, content: &str) -> Vec<Runnable> {
+        if query_file::validate(&treesitter::queries()).is_err() {
+            return vec![];
+        }
+        let Ok(tree) = parse_tree::op::execute(content) else {
+            return vec![];
+        };
+        let mut target = Target::new(
+            Capability::TestRunner,
+            Buffer::new(content, filepath.to_string(), CursorPosition::default()),
+        );
+        target.override_search_strategy(Search::File);
+
+        let mut res: Vec<Runnable> = vec![];
+        for (parent, node) in gotest_get_file_test_methods::op::execute(tree.root_node(), &target) {
+            let subtests = gotest_get_subtests::op::execute(node, parent.to_owned(), &target);
+            for runnable in std::iter::once(parent).chain(subtests.unwrap_or_default()) {
+                if !res.iter().any(|r| r.name == runnable.name) {
+                    res.push(runnable);
+                }
+            }
+        }
+        with_meta(&tree, &target, &mut res);
+        res
+    }
 
     fn capabilities(&self) -> HashSet<CapabilityDetails> {
         self.search_capabilities.clone()
@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod test {
+    use std::path::Path;
+
+    use crate::core::config::ConfigSource;
     use crate::core::enums;
     use crate::core::errors::FrameworkError;
     use crate::core::traits::Framework;
+    use crate::core::trust::TrustDatabase;
     use crate::{
         core::types::{self, Buffer, Target},
         framework::golang::gotest,
@@ -12,6 +16,13 @@ mod test {
 
     use rstest::rstest;
 
+    // provider
+    //
+    // A provider reading no configuration file.
+    fn provider() -> gotest::GotestProvider {
+        gotest::GotestProvider::with_config_source(ConfigSource::default())
+    }
+
     #[gtest]
     fn test_not_found() {
         // arrange
@@ -30,7 +41,7 @@ mod test {
 
         let mut target = Target::new(crate::core::enums::Capability::TestRunner, buffer);
         target.override_search_strategy(enums::Search::Method);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -60,7 +71,7 @@ mod test {
 
         let mut target = Target::new(crate::core::enums::Capability::TestRunner, buffer);
         target.override_search_strategy(enums::Search::Method);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -109,7 +120,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -147,7 +158,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(enums::Search::Nearest);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
         // act
         let res = provider.runnables(&target);
 
@@ -218,7 +229,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -292,7 +303,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -366,7 +377,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -442,7 +453,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -517,7 +528,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -591,7 +602,7 @@ mod test {
         let buffer = Buffer::new(content, "run_test.go".to_string(), position);
         let mut target = Target::new(enums::Capability::TestRunner, buffer);
         target.override_search_strategy(search);
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
 
         // act
         let res = provider.runnables(&target);
@@ -614,7 +625,7 @@ mod test {
     #[case("Test Directory", None)]
     fn capabilities(#[case] description: &str, #[case] expected: Option<enums::Search>) {
         // arrange
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
         // act
         let actual = provider.search_for_capability(description);
         // assert
@@ -628,10 +639,130 @@ mod test {
 
     #[gtest]
     fn ssearch_for_capability() {
-        let provider = gotest::GotestProvider::new();
+        let provider = provider();
         // act
         let actual = provider.capabilities();
         // assert
         assert_that!(actual.len(), eq(3))
     }
+
+    #[gtest]
+    #[rstest]
+    #[case(false, vec!["test", "-v", "-run", "^TestApp$"])]
+    #[case(true, vec!["test", "-v", "-race", "-tags=integration", "-run", "^TestApp$"])]
+    fn generate_command_applies_trusted_project_config(
+        #[case] trusted: bool,
+        #[case] expected_args: Vec<&str>,
+    ) {
+        // arrange
+        let project = format!("{}/lib/fixtures/config/project", env!("CARGO_MANIFEST_DIR"));
+        let filepath = format!("{}/pkg/app/app_test.go", project);
+        let trust = std::env::temp_dir().join(format!(
+            "examen-gotest-trust-{}-{}",
+            trusted,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&trust);
+        if trusted {
+            let mut database = TrustDatabase::load(&trust).unwrap();
+            for file in [".examen.toml", ".env"] {
+                database
+                    .allow(Path::new(&format!("{}/{}", project, file)))
+                    .unwrap();
+            }
+        }
+        let provider = gotest::GotestProvider::with_config_source(ConfigSource {
+            user: None,
+            trust: Some(trust.clone()),
+        });
+        let content = std::fs::read_to_string(&filepath).unwrap();
+        let buffer = Buffer::new(&content, filepath.clone(), types::CursorPosition::new(5, 2));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let mut expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        expected.push(filepath);
+        assert_that!(cmd.command, eq("go"));
+        assert_that!(cmd.args, container_eq(expected));
+        assert_that!(cmd.cwd, none());
+        let _ = std::fs::remove_file(&trust);
+    }
+
+    #[gtest]
+    fn try_generate_command_reports_invalid_config() {
+        // arrange
+        let filepath = format!(
+            "{}/lib/fixtures/config/project/pkg/app/app_test.go",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let user =
+            std::env::temp_dir().join(format!("examen-gotest-invalid-{}.toml", std::process::id()));
+        std::fs::write(&user, "[go]\nflags = [\"race\"]\n").unwrap();
+        let provider = gotest::GotestProvider::with_config_source(ConfigSource {
+            user: Some(user.clone()),
+            trust: None,
+        });
+        let content = std::fs::read_to_string(&filepath).unwrap();
+        let buffer = Buffer::new(&content, filepath, types::CursorPosition::new(5, 2));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let res = provider.try_generate_command(runnable.clone());
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let _ = std::fs::remove_file(&user);
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::ConfigError(
+                contains_substring("`go.flags[0]` `race` is not a flag")
+            )))
+        );
+        assert_that!(cmd.command, eq(""));
+    }
+
+    #[gtest]
+    fn all_runnables_lists_tests_with_their_subtests() {
+        // arrange
+        let content = r#"
+        package golang
+        import "testing"
+
+        func TestSample(t *testing.T) {
+            t.Run("case_a", func(t *testing.T){})
+            t.Run("case_b", func(t *testing.T){})
+        }
+
+        func helper() {}
+
+        func TestOther(t *testing.T) {}
+        "#;
+        let provider = provider();
+
+        // act
+        let res = provider.all_runnables("pkg/calc/run_test.go", content);
+
+        // assert
+        let names: Vec<&str> = res.iter().map(|r| r.name.as_str()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "TestSample",
+                "TestSample/case_a",
+                "TestSample/case_b",
+                "TestOther",
+            ])
+        );
+        assert_that!(
+            res.iter()
+                .map(|r| r.meta.get_meta().unwrap().package)
+                .collect::<Vec<String>>(),
+            each(eq("./pkg/calc"))
+        );
+    }
 }
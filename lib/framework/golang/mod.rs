@@ -1,6 +1,11 @@
+pub(crate) mod affected;
+pub mod delve;
+#[cfg(test)]
+mod delve_test;
 pub mod gotest;
 #[cfg(test)]
 mod gotest_test;
-mod operations;
+pub(crate) mod operations;
 pub(crate) mod runnable_meta;
+pub(crate) mod touched;
 mod treesitter;
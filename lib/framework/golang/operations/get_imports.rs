@@ -0,0 +1,75 @@
+pub(crate) mod op {
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::framework::golang::treesitter::import_paths;
+    use crate::treesitter::node::node_text;
+
+    // execute
+    //
+    // The import paths of the file, unquoted and in order of appearance.
+    pub(crate) fn execute(root: Node, content: &str) -> Vec<String> {
+        let Ok(query) = Query::new(
+            &Language::new(tree_sitter_go::LANGUAGE),
+            import_paths::query().as_str(),
+        ) else {
+            return vec![];
+        };
+        let Some(capture_index) = query.capture_index_for_name("import.path") else {
+            return vec![];
+        };
+        let mut res = vec![];
+        let mut cursor = QueryCursor::new();
+        for node_matched in cursor.matches(&query, root, content.as_bytes()) {
+            for capture in node_matched.captures.iter() {
+                if capture.index != capture_index {
+                    continue;
+                }
+                let path = node_text(capture.node, content)
+                    .trim_matches('"')
+                    .to_string();
+                if !res.contains(&path) {
+                    res.push(path);
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::framework::golang::operations::parse_tree;
+
+    #[gtest]
+    fn finds_single_and_listed_imports() {
+        // arrange
+        let content: &str = r#"
+        package cart
+        import "fmt"
+        import (
+          "testing"
+
+          m "example.com/shop/money"
+          _ "example.com/shop/internal/db"
+        )
+        "#;
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        // act
+        let res = op::execute(tree.root_node(), content);
+
+        // assert
+        assert_that!(
+            res,
+            elements_are![
+                eq("fmt"),
+                eq("testing"),
+                eq("example.com/shop/money"),
+                eq("example.com/shop/internal/db")
+            ]
+        );
+    }
+}
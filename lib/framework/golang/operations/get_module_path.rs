@@ -0,0 +1,37 @@
+pub(crate) mod op {
+    // execute
+    //
+    // The module path a `go.mod` declares, e.g. `example.com/shop` for
+    // `module example.com/shop`.
+    pub(crate) fn execute(go_mod: &str) -> Option<String> {
+        go_mod.lines().find_map(|line| {
+            let line = line.split("//").next().unwrap_or_default().trim();
+            let path = line.strip_prefix("module")?;
+            if !path.starts_with(char::is_whitespace) && !path.starts_with('"') {
+                return None;
+            }
+            let path = path.trim().trim_matches(|c| c == '"' || c == '`');
+            (!path.is_empty()).then(|| path.to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case("module example.com/shop\n\ngo 1.22\n", Some("example.com/shop"))]
+    #[case(
+        "// shop\nmodule \"example.com/shop\" // quoted\n",
+        Some("example.com/shop")
+    )]
+    #[case("modules example.com/shop\n", None)]
+    #[case("go 1.22\n", None)]
+    fn module_path(#[case] go_mod: &str, #[case] expected: Option<&str>) {
+        assert_that!(op::execute(go_mod).as_deref(), eq(expected))
+    }
+}
@@ -0,0 +1,42 @@
+pub(crate) mod op {
+    use std::path::Path;
+
+    // execute
+    //
+    // Returns the directory holding the go file in a form that `go test`
+    // and `dlv test` accept as a package argument. Relative directories are
+    // prefixed with `./` so they are not mistaken for import paths.
+    pub(crate) fn execute(filepath: &str) -> String {
+        let parent = Path::new(filepath)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if parent.is_empty() {
+            return ".".to_string();
+        }
+
+        if parent.starts_with('/') || parent.starts_with('.') {
+            return parent;
+        }
+
+        format!("./{}", parent)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case("sample_test.go", ".")]
+    #[case("pkg/utils/sample_test.go", "./pkg/utils")]
+    #[case("./pkg/sample_test.go", "./pkg")]
+    #[case("/home/dev/project/sample_test.go", "/home/dev/project")]
+    fn package_directory(#[case] filepath: &str, #[case] expected: &str) {
+        assert_that!(op::execute(filepath), eq(expected))
+    }
+}
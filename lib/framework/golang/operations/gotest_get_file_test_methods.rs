@@ -0,0 +1,63 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::golang::treesitter::gotest_file_test_methods,
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Every test function of the file along with its declaration, in which
+    // its subtests are searched.
+    pub fn execute<'a>(root: Node<'a>, target: &Target) -> Vec<(Runnable, Node<'a>)> {
+        let content = target.buffer.content;
+        let query_pattern = gotest_file_test_methods::query();
+        let Ok(query) = Query::new(&Language::new(tree_sitter_go::LANGUAGE), &query_pattern) else {
+            return vec![];
+        };
+        let (Some(test_name_index), Some(test_function_index)) = (
+            query.capture_index_for_name("test_name"),
+            query.capture_index_for_name("testfunc"),
+        ) else {
+            return vec![];
+        };
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut res = vec![];
+        for node_matched in query_matches {
+            let Some(function_node) = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == test_function_index)
+                .map(|c| c.node)
+            else {
+                continue;
+            };
+            for m in node_matched
+                .captures
+                .iter()
+                .filter(|c| c.index == test_name_index)
+            {
+                let runnable = Runnable {
+                    name: node::node_text(m.node, content),
+                    filepath: target.buffer.filepath.to_string(),
+                    range: Range {
+                        start: CursorPosition::from_point(function_node.start_position()),
+                        end: CursorPosition::from_point(function_node.end_position()),
+                    },
+                    meta: RunnableMeta::default_golang(),
+                };
+                res.push((runnable, function_node));
+            }
+        }
+        res
+    }
+}
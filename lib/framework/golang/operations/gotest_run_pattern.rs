@@ -0,0 +1,70 @@
+pub(crate) mod op {
+    // execute
+    //
+    // Converts a runnable name such as `TestSample/case a` into the anchored
+    // expression accepted by `go test -run` (and `-test.run` for compiled
+    // test binaries), e.g. `^TestSample$/^case_a$`.
+    //
+    // Go rewrites spaces in subtest names to underscores, so the same is
+    // done here before escaping any regular expression metacharacters.
+    pub(crate) fn execute(name: &str) -> String {
+        name.split('/')
+            .map(|segment| format!("^{}$", escape(&segment.replace(' ', "_"))))
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    // any
+    //
+    // The expression matching any of the tests of the runnable names, their
+    // subtests included, e.g. `^(TestSample|TestOther)$`.
+    pub(crate) fn any(names: &[&str]) -> String {
+        let mut tests: Vec<String> = vec![];
+        for name in names {
+            let test = escape(name.split('/').next().unwrap_or(name));
+            if !tests.contains(&test) {
+                tests.push(test);
+            }
+        }
+        format!("^({})$", tests.join("|"))
+    }
+
+    fn escape(segment: &str) -> String {
+        let mut res = String::with_capacity(segment.len());
+        for c in segment.chars() {
+            if "\\.+*?()|[]{}^$".contains(c) {
+                res.push('\\');
+            }
+            res.push(c);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case("TestSample", "^TestSample$")]
+    #[case("TestSample/case_a", "^TestSample$/^case_a$")]
+    #[case("TestSample/base case", "^TestSample$/^base_case$")]
+    #[case("TestSample/a+b (c)", "^TestSample$/^a\\+b_\\(c\\)$")]
+    fn run_pattern(#[case] name: &str, #[case] expected: &str) {
+        assert_that!(op::execute(name), eq(expected))
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(vec!["TestSample"], "^(TestSample)$")]
+    #[case(
+        vec!["TestSample/case_a", "TestSample/case_b", "TestOther"],
+        "^(TestSample|TestOther)$"
+    )]
+    fn any_pattern(#[case] names: Vec<&str>, #[case] expected: &str) {
+        assert_that!(op::any(&names), eq(expected))
+    }
+}
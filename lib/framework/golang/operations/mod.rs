@@ -1,6 +1,11 @@
 pub(crate) mod detect_gotest_file;
 pub(crate) mod get_build_tags;
+pub(crate) mod get_imports;
+pub(crate) mod get_module_path;
+pub(crate) mod get_package_directory;
+pub(crate) mod gotest_get_file_test_methods;
 pub(crate) mod gotest_get_file_tests;
 pub(crate) mod gotest_get_subtests;
 pub(crate) mod gotest_get_test;
+pub(crate) mod gotest_run_pattern;
 pub(crate) mod parse_tree;
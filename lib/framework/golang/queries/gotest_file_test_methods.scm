@@ -0,0 +1,11 @@
+[[((function_declaration
+        name: (identifier) @test_name
+        parameters: (parameter_list
+            (parameter_declaration
+                     name: (identifier)
+                     type: (pointer_type
+                         (qualified_type
+                          package: (package_identifier) @_param_package
+                          name: (type_identifier) @_param_name))))
+         ) @testfunc
+      (#contains? @test_name "Test"))]]
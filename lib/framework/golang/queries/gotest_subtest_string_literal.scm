@@ -0,0 +1,28 @@
+[[
+  ;; string literal sub test
+  (((expression_statement
+      (call_expression
+          function: (selector_expression
+              operand: (identifier) @testing
+                field: (field_identifier) @testing.method (#eq? @testing.method "Run")
+            )
+            arguments: (argument_list
+              (interpreted_string_literal) @test.case.name.value
+                (func_literal
+                  parameters: (parameter_list
+                      (parameter_declaration
+                          name: (identifier)
+                            type: (pointer_type
+                              (qualified_type
+                                package: (package_identifier) @test.case.package
+                                  name: (type_identifier) @test.case.package.param
+            )
+          )
+                        )
+                    )
+                )
+            )
+        )
+    ) @test.case
+  ))
+]]
@@ -0,0 +1,4 @@
+[[
+  (import_spec
+      path: (interpreted_string_literal) @import.path)
+]]
@@ -0,0 +1,8 @@
+[[
+      (import_declaration
+          (import_spec_list
+              (import_spec
+                  path: (interpreted_string_literal) @import.path (#eq? @import.path "\"$PACKAGE\"")
+        ))
+)
+]]
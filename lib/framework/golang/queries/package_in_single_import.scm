@@ -0,0 +1,7 @@
+[[
+  (import_declaration
+          (import_spec
+              path: (interpreted_string_literal) @import.path (#eq? @import.path "\"$PACKAGE\"")
+         )
+    )
+]]
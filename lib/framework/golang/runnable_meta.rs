@@ -2,10 +2,14 @@ use crate::core::metadata::RunnableMeta;
 
 impl RunnableMeta {
     pub(in crate::framework::golang) fn extend_build_tags(&mut self, tags: Vec<String>) {
-        match self {
-            RunnableMeta::Golang { build_tags, .. } => {
-                build_tags.extend(tags);
-            }
+        if let RunnableMeta::Golang { build_tags, .. } = self {
+            build_tags.extend(tags);
+        }
+    }
+
+    pub(in crate::framework::golang) fn set_package(&mut self, value: String) {
+        if let RunnableMeta::Golang { package, .. } = self {
+            *package = value;
         }
     }
 
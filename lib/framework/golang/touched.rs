@@ -0,0 +1,582 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+
+use mlua::{Lua, Table};
+
+use crate::core::config::Config;
+use crate::core::enums::Language;
+use crate::core::errors::FrameworkError;
+use crate::core::git;
+use crate::core::paths::absolute;
+use crate::core::traits::Framework;
+use crate::core::types::{Command, CursorPosition, Runnable};
+use crate::framework::golang::gotest::with_go_config;
+use crate::framework::golang::operations::gotest_run_pattern;
+use crate::overseer::task::TaskDefinition;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TestChange {
+    Added,
+    Modified,
+}
+
+#[derive(Clone, Debug)]
+pub struct TouchedTest {
+    pub runnable: Runnable,
+    pub change: TestChange,
+}
+
+// TestInvocation
+//
+// A `go test` running touched tests of a single package.
+#[derive(Clone, Debug)]
+pub struct TestInvocation {
+    pub runnables: Vec<Runnable>,
+    pub command: Command,
+}
+
+// TouchedTests
+//
+// The tests added or changed since the merge-base, and the fewest `go test`
+// running them.
+#[derive(Clone, Debug)]
+pub struct TouchedTests {
+    pub merge_base: String,
+    pub tests: Vec<TouchedTest>,
+    pub invocations: Vec<TestInvocation>,
+}
+
+impl TouchedTests {
+    // to_lua
+    //
+    // The tests, and each invocation as the overseer task running it, see
+    // `TaskDefinition`.
+    pub fn to_lua(&self, lua: &Lua) -> mlua::Result<Table> {
+        let res = lua.create_table()?;
+        res.set("merge_base", self.merge_base.as_str())?;
+
+        let tests = lua.create_table()?;
+        for test in self.tests.iter() {
+            let table = lua.create_table()?;
+            table.set("runnable_id", test.runnable.id())?;
+            table.set("name", test.runnable.name.as_str())?;
+            table.set("filepath", test.runnable.filepath.as_str())?;
+            table.set("line", test.runnable.range.start.row + 1)?;
+            let change = match test.change {
+                TestChange::Added => "added",
+                TestChange::Modified => "modified",
+            };
+            table.set("change", change)?;
+            tests.push(table)?;
+        }
+        res.set("tests", tests)?;
+
+        let invocations = lua.create_table()?;
+        for invocation in self.invocations.iter() {
+            let Some(first) = invocation.runnables.first() else {
+                continue;
+            };
+            let names: Vec<&str> = invocation
+                .runnables
+                .iter()
+                .map(|r| r.name.as_str())
+                .collect();
+            let task = TaskDefinition::new(
+                "GoTest",
+                &Language::Golang,
+                first,
+                invocation.command.clone(),
+            )
+            .to_lua(lua)?;
+            task.set("name", format!("GoTest: {}", names.join(", ")))?;
+            let table = lua.create_table()?;
+            table.set(
+                "runnable_ids",
+                invocation
+                    .runnables
+                    .iter()
+                    .map(Runnable::id)
+                    .collect::<Vec<String>>(),
+            )?;
+            table.set("task", task)?;
+            invocations.push(table)?;
+        }
+        res.set("invocations", invocations)?;
+        Ok(res)
+    }
+}
+
+// touched_tests
+//
+// Compares the test files committed on the branch holding `start` with
+// their version where it forked from `base`, their tests listed by
+// `framework`.
+pub(crate) fn touched_tests(
+    start: &Path,
+    base: &str,
+    framework: &dyn Framework,
+    config: &Config,
+) -> Result<TouchedTests, FrameworkError> {
+    let start = absolute(start);
+    let directory = match start.is_dir() {
+        true => start.as_path(),
+        false => start.parent().unwrap_or(&start),
+    };
+    let top = git::toplevel(directory)?;
+    let merge_base = git::merge_base(&top, Some(base))?;
+
+    let mut tests = vec![];
+    for path in git::committed_files(&top, &merge_base)? {
+        // go ignores `testdata`, vendored tests are not ours
+        if !path.ends_with("_test.go")
+            || Path::new(&path).components().any(|c| {
+                c == Component::Normal("testdata".as_ref())
+                    || c == Component::Normal("vendor".as_ref())
+            })
+        {
+            continue;
+        }
+        // deleted on the branch
+        let Some(head) = git::show(&top, "HEAD", &path)? else {
+            continue;
+        };
+        let before = git::show(&top, &merge_base, &path)?;
+        let filepath = top.join(&path).display().to_string();
+        tests.extend(compare(framework, &filepath, before.as_deref(), &head));
+    }
+    if tests.is_empty() {
+        return Err(FrameworkError::NotFoundError(format!(
+            "no test added or changed since `{}`",
+            base
+        )));
+    }
+
+    let invocations = invocations(&tests, config);
+    Ok(TouchedTests {
+        merge_base,
+        tests,
+        invocations,
+    })
+}
+
+// compare
+//
+// The tests of `after` missing from `before` or whose body changed,
+// whitespace aside. When the only changes of a test are in its table cases
+// or subtests, those are reported instead of the test.
+pub(crate) fn compare(
+    framework: &dyn Framework,
+    filepath: &str,
+    before: Option<&str>,
+    after: &str,
+) -> Vec<TouchedTest> {
+    let old = before
+        .map(|before| framework.all_runnables(filepath, before))
+        .unwrap_or_default();
+    let new = framework.all_runnables(filepath, after);
+    let before = before.unwrap_or_default();
+    let touched = |runnable: &Runnable, change: TestChange| TouchedTest {
+        runnable: runnable.clone(),
+        change,
+    };
+
+    let mut res = vec![];
+    for test in new.iter().filter(|r| !r.name.contains('/')) {
+        let Some(previous) = old.iter().find(|r| r.name == test.name) else {
+            res.push(touched(test, TestChange::Added));
+            continue;
+        };
+        if normalize(text(after, &test.range)) == normalize(text(before, &previous.range)) {
+            continue;
+        }
+        let new_cases = cases(&new, &test.name);
+        let old_cases = cases(&old, &test.name);
+        if new_cases.is_empty()
+            || skeleton(after, test, &new_cases) != skeleton(before, previous, &old_cases)
+        {
+            res.push(touched(test, TestChange::Modified));
+            continue;
+        }
+        let changed: Vec<TouchedTest> = new_cases
+            .iter()
+            .filter_map(
+                |case| match old_cases.iter().find(|c| c.name == case.name) {
+                    None => Some(touched(case, TestChange::Added)),
+                    Some(c)
+                        if normalize(text(after, &case.range))
+                            != normalize(text(before, &c.range)) =>
+                    {
+                        Some(touched(case, TestChange::Modified))
+                    }
+                    Some(_) => None,
+                },
+            )
+            .collect();
+        // cases were only removed, the rest of the table still runs
+        match changed.is_empty() {
+            true => res.push(touched(test, TestChange::Modified)),
+            false => res.extend(changed),
+        }
+    }
+    res
+}
+
+// cases
+//
+// The direct subtests of `test`, in the order of the file.
+fn cases<'a>(runnables: &'a [Runnable], test: &str) -> Vec<&'a Runnable> {
+    let prefix = format!("{}/", test);
+    let mut res: Vec<&Runnable> = runnables
+        .iter()
+        .filter(|r| {
+            r.name
+                .strip_prefix(&prefix)
+                .is_some_and(|case| !case.contains('/'))
+        })
+        .collect();
+    res.sort_by_key(|r| (r.range.start.row, r.range.start.col));
+    res
+}
+
+// skeleton
+//
+// The body of `test` without its cases.
+fn skeleton(content: &str, test: &Runnable, cases: &[&Runnable]) -> String {
+    let end = offset(content, &test.range.end);
+    let mut position = offset(content, &test.range.start);
+    let mut res = String::new();
+    for case in cases {
+        let start = offset(content, &case.range.start).clamp(position, end);
+        res.push_str(&content[position..start]);
+        position = offset(content, &case.range.end).clamp(start, end);
+        // along with the comma following a table case
+        if content[position..end].starts_with(',') {
+            position += 1;
+        }
+    }
+    res.push_str(&content[position..end]);
+    normalize(&res)
+}
+
+fn text<'a>(content: &'a str, range: &Range<CursorPosition>) -> &'a str {
+    let start = offset(content, &range.start);
+    &content[start..offset(content, &range.end).max(start)]
+}
+
+// offset
+//
+// The byte offset of a tree-sitter position, rows counted from zero.
+fn offset(content: &str, position: &CursorPosition) -> usize {
+    let line: usize = content
+        .split_inclusive('\n')
+        .take(position.row)
+        .map(|line| line.len())
+        .sum();
+    let mut res = (line + position.col).min(content.len());
+    while !content.is_char_boundary(res) {
+        res -= 1;
+    }
+    res
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// invocations
+//
+// Groups the touched tests by package and build tags. Whole tests share a
+// single `-run` alternation, while cases are run along with their test, the
+// tests whose touched cases bear the same names sharing an invocation.
+fn invocations(tests: &[TouchedTest], config: &Config) -> Vec<TestInvocation> {
+    // whole tests, and the touched cases by test
+    type Package<'a> = (Vec<&'a Runnable>, BTreeMap<&'a str, Vec<&'a Runnable>>);
+    let mut packages: BTreeMap<(PathBuf, Vec<String>), Package> = BTreeMap::new();
+    for test in tests {
+        let runnable = &test.runnable;
+        let directory = Path::new(&runnable.filepath)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let build_tags = runnable
+            .meta
+            .get_meta()
+            .map(|meta| meta.build_tags)
+            .unwrap_or_default();
+        let (whole, cases) = packages.entry((directory, build_tags)).or_default();
+        match runnable.name.split_once('/') {
+            None => whole.push(runnable),
+            Some((parent, _)) => cases.entry(parent).or_default().push(runnable),
+        }
+    }
+
+    let mut res = vec![];
+    for ((directory, build_tags), (whole, cases)) in packages {
+        let command = |pattern: String| {
+            let cmd = Command {
+                command: "go".to_string(),
+                args: vec!["test".to_string(), "-v".to_string()],
+                cwd: Some(directory.clone()),
+                ..Default::default()
+            }
+            .with_config(config);
+            let mut cmd = with_go_config(cmd, config, build_tags.clone());
+            cmd.args.push("-run".to_string());
+            cmd.args.push(pattern);
+            cmd.args.push(directory.display().to_string());
+            cmd
+        };
+        if !whole.is_empty() {
+            let pattern = alternation(whole.iter().map(|r| r.name.as_str()));
+            res.push(TestInvocation {
+                runnables: whole.into_iter().cloned().collect(),
+                command: command(pattern),
+            });
+        }
+
+        let mut tables: BTreeMap<Vec<&str>, Vec<(&str, Vec<&Runnable>)>> = BTreeMap::new();
+        for (parent, runnables) in cases {
+            let mut names: Vec<&str> = runnables
+                .iter()
+                .filter_map(|r| r.name.split_once('/').map(|(_, case)| case))
+                .collect();
+            names.sort();
+            tables.entry(names).or_default().push((parent, runnables));
+        }
+        for (names, parents) in tables {
+            let pattern = format!(
+                "{}/{}",
+                alternation(parents.iter().map(|(parent, _)| *parent)),
+                alternation(names.into_iter())
+            );
+            res.push(TestInvocation {
+                runnables: parents
+                    .into_iter()
+                    .flat_map(|(_, runnables)| runnables)
+                    .cloned()
+                    .collect(),
+                command: command(pattern),
+            });
+        }
+    }
+    res
+}
+
+fn alternation<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names
+        .map(gotest_run_pattern::op::execute)
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use super::{TouchedTest, compare, touched_tests};
+    use crate::core::config::{Config, ConfigSource};
+    use crate::core::errors::FrameworkError;
+    use crate::core::git::test::{commit, repository, run, write};
+    use crate::framework::golang::gotest::GotestProvider;
+
+    const TABLE: &str = r#"package calc
+
+import "testing"
+
+func TestAdd(t *testing.T) {
+	for _, tt := range []struct {
+		name     string
+		a, b     int
+		expected int
+	}{
+		{
+			name:     "zero",
+			a:        0,
+			b:        0,
+			expected: 0,
+		},
+		{
+			name:     "one",
+			a:        1,
+			b:        0,
+			expected: 1,
+		},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			if tt.a+tt.b != tt.expected {
+				t.Fail()
+			}
+		})
+	}
+}
+
+func TestSub(t *testing.T) {
+	if 1-1 != 0 {
+		t.Fail()
+	}
+}
+"#;
+
+    fn summary(tests: &[TouchedTest]) -> Vec<String> {
+        tests
+            .iter()
+            .map(|t| format!("{:?} {}", t.change, t.runnable.name))
+            .collect()
+    }
+
+    fn touched(before: Option<&str>, after: &str) -> Vec<String> {
+        summary(&compare(
+            &GotestProvider::with_config_source(ConfigSource::default()),
+            "/tmp/calc/calc_test.go",
+            before,
+            after,
+        ))
+    }
+
+    #[gtest]
+    fn reports_added_and_modified_tests() {
+        // arrange
+        let after = TABLE
+            .replace("if 1-1 != 0 {", "if 2-1 != 1 {")
+            .replace("\tif tt.a", "\n\tif tt.a")
+            + "\nfunc TestMul(t *testing.T) {}\n";
+
+        // act
+        let res = touched(Some(TABLE), &after);
+
+        // assert
+        assert_that!(res, container_eq(["Modified TestSub", "Added TestMul"]));
+    }
+
+    #[gtest]
+    fn reports_touched_table_cases() {
+        // arrange
+        let after = TABLE.replace("b:        0,\n\t\t\texpected: 1", "b:        1,\n\t\t\texpected: 2").replace(
+            "\t} {\n",
+            "\t\t{\n\t\t\tname:     \"two\",\n\t\t\ta:        1,\n\t\t\tb:        1,\n\t\t\texpected: 2,\n\t\t},\n\t} {\n",
+        );
+
+        // act
+        let res = touched(Some(TABLE), &after);
+
+        // assert
+        assert_that!(
+            res,
+            container_eq(["Modified TestAdd/one", "Added TestAdd/two"])
+        );
+    }
+
+    #[gtest]
+    fn reports_table_test_when_its_body_changes() {
+        // arrange
+        let after = TABLE
+            .replace(
+                "t.Fail()\n\t\t\t}",
+                "t.Errorf(\"%d\", tt.expected)\n\t\t\t}",
+            )
+            .replace("name:     \"one\"", "name:     \"first\"");
+
+        // act
+        let res = touched(Some(TABLE), &after);
+
+        // assert
+        assert_that!(res, container_eq(["Modified TestAdd"]));
+        assert_that!(
+            touched(None, TABLE),
+            container_eq(["Added TestAdd", "Added TestSub"])
+        );
+    }
+
+    #[gtest]
+    fn groups_touched_tests_into_invocations() {
+        // arrange
+        let repository = repository(
+            "touched",
+            &[
+                ("go.mod", "module example.com/calc\n"),
+                ("calc/calc_test.go", TABLE),
+                (
+                    "other/other_test.go",
+                    TABLE.replace("calc", "other").as_str(),
+                ),
+            ],
+        );
+        run(&repository, &["checkout", "-q", "-b", "feature"]);
+        let bump = |source: &str| source.replace("expected: 1,", "expected: 2,");
+        write(
+            &repository,
+            "calc/calc_test.go",
+            &(bump(TABLE) + "\nfunc TestMul(t *testing.T) {}\n"),
+        );
+        write(
+            &repository,
+            "other/other_test.go",
+            &bump(&TABLE.replace("calc", "other")),
+        );
+        commit(&repository, "feature");
+        // changes of the working tree are not committed yet
+        write(&repository, "calc/calc_test.go", "package calc\n");
+        let top = repository.canonicalize().unwrap();
+
+        // act
+        let framework = GotestProvider::with_config_source(ConfigSource::default());
+        let res = touched_tests(
+            &repository.join("calc"),
+            "main",
+            &framework,
+            &Config::default(),
+        )
+        .unwrap();
+
+        // assert
+        assert_that!(
+            summary(&res.tests),
+            container_eq([
+                "Modified TestAdd/one",
+                "Added TestMul",
+                "Modified TestAdd/one"
+            ])
+        );
+        let commands: Vec<String> = res
+            .invocations
+            .iter()
+            .map(|i| i.command.args.join(" "))
+            .collect();
+        let calc = top.join("calc").display().to_string();
+        let other = top.join("other").display().to_string();
+        assert_that!(
+            commands,
+            container_eq([
+                format!("test -v -run ^TestMul$ {}", calc),
+                format!("test -v -run ^TestAdd$/^one$ {}", calc),
+                format!("test -v -run ^TestAdd$/^one$ {}", other),
+            ])
+        );
+        let lua = Lua::new();
+        let table = res.to_lua(&lua).unwrap();
+        assert_that!(
+            table.get::<String>("merge_base").unwrap(),
+            eq(&res.merge_base)
+        );
+        let invocation: Table = table.get::<Table>("invocations").unwrap().get(1).unwrap();
+        assert_that!(
+            invocation.get::<Vec<String>>("runnable_ids").unwrap(),
+            elements_are![ends_with("calc_test.go::TestMul")]
+        );
+        let task: Table = invocation.get("task").unwrap();
+        assert_that!(task.get::<String>("name").unwrap(), eq("GoTest: TestMul"));
+        assert_that!(task.get::<String>("cwd").unwrap(), eq(&calc));
+        assert_that!(
+            touched_tests(&repository, "feature", &framework, &Config::default())
+                .map(|r| r.tests.len()),
+            err(eq(&FrameworkError::NotFoundError(
+                "no test added or changed since `feature`".to_string()
+            )))
+        );
+        let _ = fs::remove_dir_all(&repository);
+    }
+}
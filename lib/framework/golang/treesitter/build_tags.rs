@@ -1,10 +1,13 @@
+use crate::treesitter::query_file::QueryFile;
+
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "build_tags",
+    embedded: include_str!("../queries/build_tags.scm"),
+    captures: &["build_tags"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
+
 pub(crate) fn query() -> String {
-    let pattern = r#"
-            [[((source_file 
-              (comment) @build_tags
-                (package_clause
-                  (package_identifier) @package_name
-                ))(#any_contains? @build_tags "//go:build" "//+build"))]]
-            "#;
-    pattern.to_string()
+    QUERY.source()
 }
@@ -1,17 +1,13 @@
-pub(crate) fn query() -> String {
-    let res = r#"
-            [[((function_declaration 
-                    name: (identifier) @test_name
-                    parameters: (parameter_list
-                        (parameter_declaration
-                                 name: (identifier)
-                                 type: (pointer_type
-                                     (qualified_type
-                                      package: (package_identifier) @_param_package
-                                      name: (type_identifier) @_param_name))))
-                     ) @testfunc
-                  (#contains? @test_name "Test"))]]
-            "#;
+use crate::treesitter::query_file::QueryFile;
+
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "gotest_file_test_methods",
+    embedded: include_str!("../queries/gotest_file_test_methods.scm"),
+    captures: &["test_name", "testfunc"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
 
-    res.to_string()
+pub(crate) fn query() -> String {
+    QUERY.source()
 }
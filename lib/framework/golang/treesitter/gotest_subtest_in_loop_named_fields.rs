@@ -1,3 +1,5 @@
+use crate::treesitter::query_file::QueryFile;
+
 // query
 //
 // Given subtests defined in the loop of within gotest,
@@ -36,60 +38,14 @@
 // This will find the following subtests:
 // - "base case"
 // - "case 1"
-pub(crate) fn query() -> String {
-    let res = r#"
-        [[
-          ((for_statement
-            (range_clause
-              left: (expression_list
-                (identifier)
-                (identifier) @test.loop.case.variable
-              )
-              right: (composite_literal
-                type: (slice_type
-                  element: (struct_type
-                    (field_declaration_list
-                      (field_declaration
-                      	name: (field_identifier) @test.case.definition.field
-                        type: (type_identifier) @test.case.definition.field.type (#eq? @test.case.definition.field.type "string")
-                      )
-                    )
-                  ) @test.case.type
-                )
-                body: (literal_value
-                  (literal_element
-                    (literal_value
-                      (keyed_element
-                        (literal_element
-                          (identifier)
-                        )  @test.case.field.name (#eq? @test.case.field.name @test.case.definition.field)
-                        (literal_element
-                          (interpreted_string_literal) @test.case.field.value
-                        )
-                      )
-                    ) @test.case
-                  )
-                )
-              )
-            )
-            body: (block
-              (expression_statement
-                (call_expression
-                  function: (selector_expression
-                    operand: (identifier) @test.loop.test
-                    field: (field_identifier) @test.loop.test.method (#eq? @test.loop.test.method "Run")
-                  )
-                  arguments: (argument_list
-                    (selector_expression
-                      operand: (identifier) @test.loop.test.variable (#eq? @test.loop.test.variable @test.loop.case.variable)
-                      field: (field_identifier) @test.loop.test.variable.field (#eq? @test.loop.test.variable.field @test.case.definition.field)
-                    ) 
-                  )
-                )
-              )
-            )
-          ))
-        ]]"#;
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "gotest_subtest_in_loop_named_fields",
+    embedded: include_str!("../queries/gotest_subtest_in_loop_named_fields.scm"),
+    captures: &["test.case", "test.case.field.value"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
 
-    res.to_string()
+pub(crate) fn query() -> String {
+    QUERY.source()
 }
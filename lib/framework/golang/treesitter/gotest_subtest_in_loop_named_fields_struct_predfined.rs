@@ -1,3 +1,5 @@
+use crate::treesitter::query_file::QueryFile;
+
 // query
 //
 // Given subtests defined in the loop of within gotest,
@@ -38,67 +40,14 @@
 // This will find the following subtests:
 // - "base case"
 // - "case 1"
-pub(crate) fn query() -> String {
-    let res = r#"
-		        [[
-              (((type_declaration
-                  (type_spec
-                      name: (type_identifier) @test.case.variable.name
-                        type: (struct_type 
-                          (field_declaration_list
-                          		(field_declaration
-                          			name: (field_identifier) @test.case.definition.field
-                          			type: (type_identifier) @test.case.definition.field.type (#eq? @test.case.definition.field.type "string")
-                          		)
-                        	) 
-                    	) @test.case.type
-                  	) 
-                )
-                (for_statement
-                	(range_clause
-                		left: (expression_list
-                			(identifier)
-                			(identifier) @test.loop.case.variable
-                	)
-                		right: (composite_literal
-                			type: (slice_type
-                			element: (type_identifier) @test.loop.case.variable.type (#eq? @test.loop.case.variable.type @test.case.variable.name)
-                		)
-                		body: (literal_value
-                				(literal_element
-                					(literal_value
-                						(keyed_element
-                							(literal_element
-                									(identifier)
-                							)  @test.case.field.name
-                							(literal_element
-                								(interpreted_string_literal) @test.case.field.value
-                						)
-                					)
-                				) @test.case
-                			)
-                		)
-                	)
-                )
-                body: (block
-                		(expression_statement
-                			(call_expression
-                				function: (selector_expression
-                					operand: (identifier) @test.loop.test
-                					field: (field_identifier) @test.loop.test.method (#eq? @test.loop.test.method "Run")
-                				)
-                				arguments: (argument_list
-                					(selector_expression
-                						operand: (identifier) @test.loop.test.variable (#eq? @test.loop.case.variable @test.loop.test.variable)
-                            field: (field_identifier) @test.loop.test.variable.field (#eq? @test.case.definition.field @test.loop.test.variable.field)
-                					)
-                				)
-                			)
-                		)
-                	)
-                )
-              ))
-            ]]"#;
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "gotest_subtest_in_loop_named_fields_struct_predfined",
+    embedded: include_str!("../queries/gotest_subtest_in_loop_named_fields_struct_predfined.scm"),
+    captures: &["test.case", "test.case.field.value"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
 
-    res.to_string()
+pub(crate) fn query() -> String {
+    QUERY.source()
 }
@@ -1,3 +1,5 @@
+use crate::treesitter::query_file::QueryFile;
+
 // query
 //
 // Returns a treesitter query that locates subsets as string literals
@@ -24,37 +26,14 @@
 // }
 //
 // If the position is on TestSample, it will find `case_a` and `cas_b` as subtests
-pub(crate) fn query() -> String {
-    let res = r#"
-            [[
-              ;; string literal sub test
-              (((expression_statement
-                  (call_expression
-                      function: (selector_expression
-                          operand: (identifier) @testing
-                            field: (field_identifier) @testing.method (#eq? @testing.method "Run")
-                        )
-                        arguments: (argument_list
-                          (interpreted_string_literal) @test.case.name.value
-                            (func_literal
-                              parameters: (parameter_list
-                                  (parameter_declaration
-                                      name: (identifier)
-                                        type: (pointer_type
-                                          (qualified_type
-                                            package: (package_identifier) @test.case.package  
-                                              name: (type_identifier) @test.case.package.param 
-                        )	
-                      )
-                                    )
-                                )
-                            )
-                        )
-                    )
-                ) @test.case
-              ))
-            ]]
-        "#;
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "gotest_subtest_string_literal",
+    embedded: include_str!("../queries/gotest_subtest_string_literal.scm"),
+    captures: &["test.case", "test.case.name.value"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
 
-    res.to_string()
+pub(crate) fn query() -> String {
+    QUERY.source()
 }
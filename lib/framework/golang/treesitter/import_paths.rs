@@ -0,0 +1,22 @@
+use crate::treesitter::query_file::QueryFile;
+
+// query
+//
+// Every package a file imports, whether in a single import or an import
+// list, e.g. `"example.com/shop/money"` in
+//
+// import (
+// 	"testing"
+// 	m "example.com/shop/money"
+// )
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "import_paths",
+    embedded: include_str!("../queries/import_paths.scm"),
+    captures: &["import.path"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
+
+pub(crate) fn query() -> String {
+    QUERY.source()
+}
@@ -9,5 +9,30 @@ pub(crate) mod gotest_subtest_out_of_loop_named_fields;
 pub(crate) mod gotest_subtest_out_of_loop_unnamed_fields;
 pub(crate) mod gotest_subtest_string_literal;
 pub(crate) mod gotest_test_function;
+pub(crate) mod import_paths;
 pub(crate) mod package_in_import_list;
 pub(crate) mod package_in_single_import;
+
+use crate::treesitter::query_file::QueryFile;
+
+// queries
+//
+// Every query of the go frameworks, validated before searching so a broken
+// user query is reported instead of silently finding no tests.
+pub(crate) fn queries() -> [&'static QueryFile; 13] {
+    [
+        &build_tags::QUERY,
+        &gotest_file_test_methods::QUERY,
+        &gotest_subtest_in_loop_named_fields::QUERY,
+        &gotest_subtest_in_loop_named_fields_struct_predfined::QUERY,
+        &gotest_subtest_in_loop_unnamed_fields::QUERY,
+        &gotest_subtest_in_loop_unnamed_fields_struct_predefined::QUERY,
+        &gotest_subtest_out_of_loop_named_fields::QUERY,
+        &gotest_subtest_out_of_loop_unnamed_fields::QUERY,
+        &gotest_subtest_string_literal::QUERY,
+        &gotest_test_function::QUERY,
+        &import_paths::QUERY,
+        &package_in_import_list::QUERY,
+        &package_in_single_import::QUERY,
+    ]
+}
@@ -1,4 +1,4 @@
-use crate::framework::golang::treesitter::constants;
+use crate::treesitter::query_file::QueryFile;
 
 // query
 //
@@ -18,19 +18,14 @@ use crate::framework::golang::treesitter::constants;
 // ]]
 //
 // by replacing $PACKAGE with "testing", the query will find a match
-pub(crate) fn query() -> String {
-    let res = format!(
-        r#"
-        [[
-          (import_declaration
-                  (import_spec
-                      path: (interpreted_string_literal) @import.path (#eq? @import.path "\"{}\"")
-                 )
-            )
-        ]]
-    "#,
-        constants::PACKAGE
-    );
+pub(crate) static QUERY: QueryFile = QueryFile {
+    language: "go",
+    name: "package_in_single_import",
+    embedded: include_str!("../queries/package_in_single_import.scm"),
+    captures: &["import.path"],
+    grammar: || tree_sitter_go::LANGUAGE.into(),
+};
 
-    res.to_string()
+pub(crate) fn query() -> String {
+    QUERY.source()
 }
@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::JavaBuild;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::java::operations::get_java_project;
+use crate::framework::java::operations::junit_get_file_tests;
+use crate::framework::java::operations::junit_get_test_classes;
+use crate::framework::java::operations::parse_tree;
+
+// JunitProvider
+//
+// Runs JUnit 4 and 5 tests through the maven or gradle build of the module
+// holding the file. Runnables are named by the fully qualified binary name
+// of their class, e.g. `com.acme.CalculatorTest$WhenEmpty#clears`.
+pub struct JunitProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static FILE_SUFFIX: &str = ".java";
+
+impl JunitProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the test method under the cursor.
+         *       Outside of a method, fall back to the innermost test class holding the cursor
+         *   - Search set to method, return the test method under the cursor
+         *   - Search set to file, return all test methods in the file
+         * */
+        let root = tree.root_node();
+        let tests = junit_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError("JUnit test not found no tests in this file".to_string())
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .find(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => Ok(tests),
+            Search::Method => under_cursor.map(|t| vec![t]).ok_or_else(|| {
+                FrameworkError::NotFoundError("JUnit test not found at position".to_string())
+            }),
+            Search::Nearest => {
+                if let Some(test) = under_cursor {
+                    return Ok(vec![test]);
+                }
+                junit_get_test_classes::op::execute(root, target)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|c| position.in_range(c.range.start.to_point()..c.range.end.to_point()))
+                    .max_by_key(|c| c.range.start.row)
+                    .map(|c| vec![c])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError(
+                            "JUnit test not found at position".to_string(),
+                        )
+                    })
+            }
+        }
+    }
+
+    // command
+    //
+    // The build running the runnables, with the project of the first one.
+    fn command(&self, runnables: &[Runnable]) -> Command {
+        let Some(runnable) = runnables.first() else {
+            return Command::default();
+        };
+        let (root, module, build) = runnable
+            .meta
+            .get_java_meta()
+            .map(|m| (PathBuf::from(m.root), m.module, m.build))
+            .or_else(|| {
+                get_java_project::op::execute(&runnable.filepath)
+                    .map(|p| (p.root, p.module, p.build))
+            })
+            .unwrap_or((PathBuf::new(), String::new(), JavaBuild::Maven));
+        // wrappers and modules are resolved from the build root
+        let mut cmd = Command {
+            command: get_java_project::op::executable(&root, &build),
+            args: vec![],
+            cwd: Some(root).filter(|root| !root.as_os_str().is_empty()),
+            ..Default::default()
+        };
+
+        match build {
+            JavaBuild::Maven => {
+                if !module.is_empty() {
+                    cmd.args.push("-pl".to_string());
+                    cmd.args.push(module);
+                }
+                // surefire selects the fully qualified class, a nested one
+                // following its outer class after a `$`, and the method
+                // after a `#`
+                let selectors: Vec<&str> = runnables.iter().map(|r| r.name.as_str()).collect();
+                cmd.args.push(format!("-Dtest={}", selectors.join(",")));
+                cmd.args.push("test".to_string());
+            }
+            JavaBuild::Gradle => {
+                if module.is_empty() {
+                    cmd.args.push("test".to_string());
+                } else {
+                    cmd.args.push(format!(":{}:test", module.replace('/', ":")));
+                }
+                for runnable in runnables {
+                    cmd.args.push("--tests".to_string());
+                    cmd.args.push(runnable.name.replace('#', "."));
+                }
+            }
+        }
+        cmd
+    }
+}
+
+impl FrameworkProvider for JunitProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(JunitProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "JUnit"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Java
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for JunitProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if junit_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `@Test` methods");
+        }
+
+        Detection::matched(80, "file with `@Test` methods")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_java_project::op::execute(&target.buffer.filepath).ok_or_else(|| {
+            FrameworkError::PreconditionError(format!(
+                "JUnit build not found for {}, expected a pom.xml or build.gradle(.kts)",
+                target.buffer.filepath
+            ))
+        })?;
+        let package = junit_get_file_tests::op::package(tree.root_node(), target.buffer.content);
+        for runnable in res.iter_mut() {
+            if let Some(package) = &package {
+                runnable.name = format!("{}.{}", package, runnable.name);
+            }
+            runnable.meta.set_java_project(
+                project.root.to_string_lossy().to_string(),
+                project.module.to_string(),
+                project.build.clone(),
+            );
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.command(&[runnable])
+    }
+
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        Ok(self.command(&runnables))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
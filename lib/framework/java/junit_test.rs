@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::java::junit,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+package com.acme.billing;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import org.junit.jupiter.api.*;
+import org.junit.jupiter.params.ParameterizedTest;
+import org.junit.jupiter.params.provider.ValueSource;
+
+class InvoiceTest {
+  private final Invoice invoice = new Invoice();
+
+  @Test
+  @DisplayName("totals the lines")
+  void totals() {
+    assertEquals(0, invoice.total());
+  }
+
+  @ParameterizedTest
+  @ValueSource(ints = {1, 2, 3})
+  void addsLines(int amount) {
+    invoice.add(amount);
+    assertEquals(amount, invoice.total());
+  }
+
+  @Nested
+  class WhenPaid {
+    @Test
+    void isClosed() {
+      assertTrue(invoice.pay().isClosed());
+    }
+  }
+}
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/java/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    const MAVEN_FILE: &str =
+        "maven_project/services/billing/src/test/java/com/acme/billing/InvoiceTest.java";
+    const GRADLE_FILE: &str = "gradle_project/app/src/test/java/com/acme/app/AppTest.java";
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["com.acme.billing.InvoiceTest#totals", "com.acme.billing.InvoiceTest#addsLines", "com.acme.billing.InvoiceTest$WhenPaid#isClosed"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(15, 4), vec!["com.acme.billing.InvoiceTest#totals"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(19, 4), vec!["com.acme.billing.InvoiceTest#addsLines"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(29, 6), vec!["com.acme.billing.InvoiceTest$WhenPaid#isClosed"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(26, 2), vec!["com.acme.billing.InvoiceTest$WhenPaid"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(10, 2), vec!["com.acme.billing.InvoiceTest"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(MAVEN_FILE), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = junit::JunitProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture(MAVEN_FILE),
+            types::CursorPosition::new(3, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Nearest);
+        let provider = junit::JunitProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        MAVEN_FILE,
+        types::CursorPosition::new(15, 4),
+        "mvn",
+        vec!["-pl", "services/billing", "-Dtest=com.acme.billing.InvoiceTest#totals", "test"]
+    )]
+    #[case(
+        MAVEN_FILE,
+        types::CursorPosition::new(26, 2),
+        "mvn",
+        vec!["-pl", "services/billing", "-Dtest=com.acme.billing.InvoiceTest$WhenPaid", "test"]
+    )]
+    #[case(
+        MAVEN_FILE,
+        types::CursorPosition::new(29, 6),
+        "mvn",
+        vec!["-pl", "services/billing", "-Dtest=com.acme.billing.InvoiceTest$WhenPaid#isClosed", "test"]
+    )]
+    #[case(
+        GRADLE_FILE,
+        types::CursorPosition::new(26, 2),
+        "./gradlew",
+        vec![":app:test", "--tests", "com.acme.billing.InvoiceTest$WhenPaid"]
+    )]
+    #[case(
+        GRADLE_FILE,
+        types::CursorPosition::new(29, 6),
+        "./gradlew",
+        vec![":app:test", "--tests", "com.acme.billing.InvoiceTest$WhenPaid.isClosed"]
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] position: types::CursorPosition,
+        #[case] expected_command: &str,
+        #[case] expected_args: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = junit::JunitProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq(expected_command));
+        let root = path.split('/').next().unwrap();
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+        let expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        assert_that!(cmd.args, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "InvoiceTest.java", CONTENT, true)]
+    #[case(enums::Capability::Debugger, "InvoiceTest.java", CONTENT, false)]
+    #[case(enums::Capability::TestRunner, "InvoiceTest.kt", CONTENT, false)]
+    #[case(
+        enums::Capability::TestRunner,
+        "Invoice.java",
+        "class Invoice {\n  int total() { return 0; }\n}\n",
+        false
+    )]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = junit::JunitProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
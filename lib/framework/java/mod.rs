@@ -0,0 +1,6 @@
+pub mod junit;
+#[cfg(test)]
+mod junit_test;
+mod operations;
+pub(crate) mod runnable_meta;
+mod treesitter;
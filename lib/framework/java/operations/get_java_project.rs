@@ -0,0 +1,154 @@
+pub(crate) mod op {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::LazyLock,
+    };
+
+    use regex::Regex;
+
+    use crate::core::{
+        metadata::JavaBuild,
+        paths::{absolute, find_ancestor, normalize},
+    };
+
+    static MODULE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"<module>([^<]+)</module>").unwrap());
+
+    const GRADLE_BUILDS: [&str; 2] = ["build.gradle", "build.gradle.kts"];
+    const GRADLE_SETTINGS: [&str; 2] = ["settings.gradle", "settings.gradle.kts"];
+
+    // JavaProject
+    //
+    // The build a java file belongs to: the directory builds run from, the
+    // module holding the file relative to it (empty for the root module) and
+    // the build tool.
+    pub(crate) struct JavaProject {
+        pub(crate) root: PathBuf,
+        pub(crate) module: String,
+        pub(crate) build: JavaBuild,
+    }
+
+    // execute
+    //
+    // Resolves the module from the closest `pom.xml` or `build.gradle(.kts)`.
+    // A maven build runs from the outermost aggregator pom listing the
+    // module through `<modules>`, a gradle build from the directory holding
+    // `settings.gradle(.kts)`.
+    pub(crate) fn execute(filepath: &str) -> Option<JavaProject> {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent()?;
+        let module = find_ancestor(directory, |dir| {
+            dir.join("pom.xml").is_file() || GRADLE_BUILDS.iter().any(|b| dir.join(b).is_file())
+        })?;
+
+        let gradle = GRADLE_BUILDS.iter().any(|b| module.join(b).is_file());
+        let (root, build) = if gradle {
+            let root = find_ancestor(&module, |dir| {
+                GRADLE_SETTINGS.iter().any(|s| dir.join(s).is_file())
+            })
+            .unwrap_or(module.clone());
+            (root, JavaBuild::Gradle)
+        } else {
+            let mut root = module.clone();
+            for dir in module.ancestors().skip(1) {
+                if modules(dir).contains(&root) {
+                    root = dir.to_path_buf();
+                }
+            }
+            (root, JavaBuild::Maven)
+        };
+
+        let module = module
+            .strip_prefix(&root)
+            .map(|m| {
+                m.components()
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+                    .collect::<Vec<String>>()
+                    .join("/")
+            })
+            .unwrap_or_default();
+        Some(JavaProject {
+            root,
+            module,
+            build,
+        })
+    }
+
+    // modules
+    //
+    // Directories of the `<module>` entries of the `pom.xml` in `dir`.
+    fn modules(dir: &Path) -> Vec<PathBuf> {
+        let Ok(content) = fs::read_to_string(dir.join("pom.xml")) else {
+            return vec![];
+        };
+        MODULE
+            .captures_iter(&content)
+            .filter_map(|c| c.get(1))
+            .map(|m| normalize(&dir.join(m.as_str().trim())))
+            .collect()
+    }
+
+    // executable
+    //
+    // The build command, preferring the wrapper checked in at the root.
+    pub(crate) fn executable(root: &Path, build: &JavaBuild) -> String {
+        let (wrapper, global) = match build {
+            JavaBuild::Maven => ("mvnw", "mvn"),
+            JavaBuild::Gradle => ("gradlew", "gradle"),
+        };
+        if root.join(wrapper).is_file() {
+            return format!("./{}", wrapper);
+        }
+        global.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::metadata::JavaBuild;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/java/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "maven_project/services/billing/src/test/java/com/acme/billing/InvoiceTest.java",
+        "maven_project",
+        "services/billing",
+        JavaBuild::Maven,
+        "mvn"
+    )]
+    #[case(
+        "gradle_project/app/src/test/java/com/acme/app/AppTest.java",
+        "gradle_project",
+        "app",
+        JavaBuild::Gradle,
+        "./gradlew"
+    )]
+    fn resolves_project(
+        #[case] path: &str,
+        #[case] root: &str,
+        #[case] module: &str,
+        #[case] build: JavaBuild,
+        #[case] executable: &str,
+    ) {
+        let res = op::execute(&fixture(path)).unwrap();
+
+        assert_that!(res.root.ends_with(root), eq(true));
+        assert_that!(res.module, eq(module));
+        assert_that!(res.build, eq(&build));
+        assert_that!(op::executable(&res.root, &res.build), eq(executable));
+    }
+
+    #[gtest]
+    fn none_outside_of_a_build() {
+        assert_that!(op::execute("/tmp/Standalone.java").is_none(), eq(true));
+    }
+}
@@ -0,0 +1,204 @@
+pub(crate) mod op {
+    use std::collections::HashSet;
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::java::treesitter::junit_test_method,
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns every test method of the file named `Class#method`, nested
+    // classes joined by `$` as in their binary name, e.g.
+    // `CalculatorTest$WhenEmpty#clears`. The `@DisplayName` of the method,
+    // if any, is kept in the meta.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = junit_test_method::query();
+        let query = Query::new(&Language::new(tree_sitter_java::LANGUAGE), &query_pattern).ok()?;
+        let test_name_index = query.capture_index_for_name("test_name")?;
+        let test_method_index = query.capture_index_for_name("testmethod")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(method), Some(name)) = (capture(test_method_index), capture(test_name_index))
+            else {
+                continue;
+            };
+            if !seen.insert(method.id()) {
+                continue;
+            }
+            let Some(path) = class_path(method, content).filter(|p| !p.is_empty()) else {
+                continue;
+            };
+
+            let mut meta = RunnableMeta::default_java();
+            meta.set_display_name(display_name(method, content));
+            runnables.push(Runnable {
+                name: format!("{}#{}", path.join("$"), node::node_text(name, content)),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(method.start_position()),
+                    end: CursorPosition::from_point(method.end_position()),
+                },
+                meta,
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // package
+    //
+    // The package declared by the file, `None` for the default package.
+    pub(crate) fn package(root: Node, content: &str) -> Option<String> {
+        let mut walker = root.walk();
+        let declaration = root
+            .named_children(&mut walker)
+            .find(|c| c.kind() == "package_declaration")?;
+        let mut walker = declaration.walk();
+        let name = declaration
+            .named_children(&mut walker)
+            .find(|c| matches!(c.kind(), "scoped_identifier" | "identifier"))?;
+        Some(node::node_text(name, content))
+    }
+
+    // class_path
+    //
+    // Names of the classes enclosing a declaration, outermost first.
+    // Returns `None` for local and anonymous classes, which cannot be
+    // selected by name.
+    pub(crate) fn class_path(item: Node, content: &str) -> Option<Vec<String>> {
+        let mut res = vec![];
+        let mut parent = item.parent();
+        while let Some(node) = parent {
+            match node.kind() {
+                "class_declaration" => {
+                    res.push(node::node_text(node.child_by_field_name("name")?, content))
+                }
+                "object_creation_expression" | "method_declaration" | "lambda_expression" => {
+                    return None;
+                }
+                _ => {}
+            }
+            parent = node.parent();
+        }
+        res.reverse();
+        Some(res)
+    }
+
+    // display_name
+    //
+    // The text of a `@DisplayName("...")` annotation on a declaration.
+    pub(crate) fn display_name(declaration: Node, content: &str) -> Option<String> {
+        let mut walker = declaration.walk();
+        let modifiers = declaration
+            .children(&mut walker)
+            .find(|c| c.kind() == "modifiers")?;
+        let mut walker = modifiers.walk();
+        let annotation = modifiers.named_children(&mut walker).find(|a| {
+            a.kind() == "annotation"
+                && a.child_by_field_name("name")
+                    .map(|n| node::node_text(n, content))
+                    .is_some_and(|n| n == "DisplayName" || n.ends_with(".DisplayName"))
+        })?;
+        let arguments = annotation.child_by_field_name("arguments")?;
+        let mut walker = arguments.walk();
+        let value = arguments
+            .named_children(&mut walker)
+            .find(|a| a.kind() == "string_literal")?;
+        let text = node::node_text(value, content);
+        Some(text.trim_matches('"').to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::java::operations::parse_tree;
+
+    #[gtest]
+    fn finds_test_methods() {
+        let content = r#"
+package com.acme.math;
+
+import org.junit.jupiter.api.*;
+
+class CalculatorTest {
+  @Test
+  @DisplayName("adds two numbers")
+  void adds() {}
+
+  @ParameterizedTest(name = "{0}")
+  @ValueSource(ints = {1, 2})
+  void positive(int n) {}
+
+  void helper() {
+    new Object() {
+      @Test
+      void anonymous() {}
+    };
+  }
+
+  @Nested
+  class WhenEmpty {
+    @org.junit.Test
+    public void clears() {}
+  }
+}
+"#;
+        let buffer = Buffer::new(
+            content,
+            "src/test/java/com/acme/math/CalculatorTest.java".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "CalculatorTest#adds".to_string(),
+                "CalculatorTest#positive".to_string(),
+                "CalculatorTest$WhenEmpty#clears".to_string(),
+            ])
+        );
+        assert_that!(res[0].range.start.row, eq(6));
+        assert_that!(
+            res[0].meta.get_java_meta().unwrap().display_name,
+            some(eq("adds two numbers"))
+        );
+        assert_that!(
+            op::package(tree.root_node(), content),
+            some(eq("com.acme.math"))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::Node;
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::java::operations::junit_get_file_tests,
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns the classes holding test methods, directly or through
+    // `@Nested` classes, named by their binary name such as
+    // `CalculatorTest$WhenEmpty`.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let tests = junit_get_file_tests::op::execute(root, target)?;
+
+        let mut runnables: Vec<Runnable> = vec![];
+        let mut pending = vec![root];
+        while let Some(current) = pending.pop() {
+            let mut walker = current.walk();
+            let children: Vec<Node> = current.named_children(&mut walker).collect();
+            for child in children.into_iter().rev() {
+                pending.push(child);
+            }
+            if current.kind() != "class_declaration" {
+                continue;
+            }
+            let Some(mut path) = junit_get_file_tests::op::class_path(current, content) else {
+                continue;
+            };
+            let Some(name) = current.child_by_field_name("name") else {
+                continue;
+            };
+            path.push(node::node_text(name, content));
+            let class = path.join("$");
+            let prefix = format!("{}#", class);
+            let nested_prefix = format!("{}$", class);
+            if !tests
+                .iter()
+                .any(|t| t.name.starts_with(&prefix) || t.name.starts_with(&nested_prefix))
+            {
+                continue;
+            }
+
+            let mut meta = RunnableMeta::default_java();
+            meta.set_display_name(junit_get_file_tests::op::display_name(current, content));
+            runnables.push(Runnable {
+                name: class,
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(current.start_position()),
+                    end: CursorPosition::from_point(current.end_position()),
+                },
+                meta,
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::java::operations::parse_tree;
+
+    #[gtest]
+    fn finds_test_classes() {
+        let content = r#"
+class CalculatorTest {
+  @Nested
+  @DisplayName("when empty")
+  class WhenEmpty {
+    @Test
+    void clears() {}
+  }
+
+  static class Helpers {
+    void help() {}
+  }
+}
+"#;
+        let buffer = Buffer::new(
+            content,
+            "CalculatorTest.java".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "CalculatorTest".to_string(),
+                "CalculatorTest$WhenEmpty".to_string(),
+            ])
+        );
+        assert_that!(
+            res[1].meta.get_java_meta().unwrap().display_name,
+            some(eq("when empty"))
+        );
+    }
+}
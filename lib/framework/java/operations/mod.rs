@@ -0,0 +1,4 @@
+pub(crate) mod get_java_project;
+pub(crate) mod junit_get_file_tests;
+pub(crate) mod junit_get_test_classes;
+pub(crate) mod parse_tree;
@@ -0,0 +1,53 @@
+use crate::core::metadata::{JavaBuild, RunnableMeta};
+
+impl RunnableMeta {
+    pub(in crate::framework::java) fn set_display_name(&mut self, value: Option<String>) {
+        if let RunnableMeta::Java { display_name, .. } = self {
+            *display_name = value;
+        }
+    }
+
+    pub(in crate::framework::java) fn set_java_project(
+        &mut self,
+        project_root: String,
+        project_module: String,
+        project_build: JavaBuild,
+    ) {
+        if let RunnableMeta::Java {
+            root,
+            module,
+            build,
+            ..
+        } = self
+        {
+            *root = project_root;
+            *module = project_module;
+            *build = project_build;
+        }
+    }
+
+    pub(in crate::framework::java) fn get_java_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::Java {
+            root,
+            module,
+            build,
+            display_name,
+        } = self
+        {
+            return Some(Meta {
+                root: root.clone(),
+                module: module.clone(),
+                build: build.clone(),
+                display_name: display_name.clone(),
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::java) struct Meta {
+    pub(in crate::framework::java) root: String,
+    pub(in crate::framework::java) module: String,
+    pub(in crate::framework::java) build: JavaBuild,
+    pub(in crate::framework::java) display_name: Option<String>,
+}
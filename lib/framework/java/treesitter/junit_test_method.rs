@@ -0,0 +1,28 @@
+// query
+//
+// Finds methods annotated as JUnit 4 or JUnit 5 tests, by simple or fully
+// qualified annotation name.
+//
+// Example:
+// @ParameterizedTest
+// @ValueSource(ints = {1, 2})
+// void positive(int n) {
+//   assertTrue(n > 0);
+// }
+//
+// This will find the following methods:
+// - positive
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((method_declaration
+                    (modifiers
+                        [
+                          (marker_annotation name: (_) @junit.annotation)
+                          (annotation name: (_) @junit.annotation)
+                        ])
+                    name: (identifier) @test_name) @testmethod
+                  (#match? @junit.annotation "(^|\\.)(Test|ParameterizedTest|RepeatedTest|TestFactory|TestTemplate)$"))]]
+            "#;
+
+    res.to_string()
+}
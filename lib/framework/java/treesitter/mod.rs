@@ -0,0 +1 @@
+pub(crate) mod junit_test_method;
@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::JsRunner;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::javascript::operations::get_js_project;
+use crate::framework::javascript::operations::jest_get_describe_blocks;
+use crate::framework::javascript::operations::jest_get_file_tests;
+use crate::framework::javascript::operations::jest_name_pattern;
+use crate::framework::javascript::operations::parse_tree;
+
+// JestProvider
+//
+// Runs javascript and typescript tests written against the jest api, with
+// jest or vitest depending on what the package depends on.
+pub struct JestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static EXTENSIONS: [&str; 8] = ["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+impl JestProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    // is_test_file
+    //
+    // Matches the default `testMatch` of jest and `include` of vitest:
+    // `*.test.*`, `*.spec.*` and anything under `__tests__`.
+    fn is_test_file(filepath: &str) -> bool {
+        let path = Path::new(filepath);
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut parts = name.rsplit('.');
+        let Some(extension) = parts.next() else {
+            return false;
+        };
+        if !EXTENSIONS.contains(&extension) {
+            return false;
+        }
+        let marked = parts.next().is_some_and(|p| p == "test" || p == "spec");
+        marked || path.components().any(|c| c.as_os_str() == "__tests__")
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the innermost test under the cursor.
+         *       Outside of a test, fall back to the innermost describe block holding the cursor
+         *   - Search set to method, return the test under the cursor
+         *   - Search set to file, return all tests in the file
+         * */
+        let root = tree.root_node();
+        let tests = jest_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError("Jest test not found no tests in this file".to_string())
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .filter(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .max_by_key(|t| t.range.start.row)
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => Ok(tests),
+            Search::Method => under_cursor.map(|t| vec![t]).ok_or_else(|| {
+                FrameworkError::NotFoundError("Jest test not found at position".to_string())
+            }),
+            Search::Nearest => {
+                if let Some(test) = under_cursor {
+                    return Ok(vec![test]);
+                }
+                jest_get_describe_blocks::op::execute(root, target)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|d| position.in_range(d.range.start.to_point()..d.range.end.to_point()))
+                    .max_by_key(|d| d.range.start.row)
+                    .map(|d| vec![d])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError("Jest test not found at position".to_string())
+                    })
+            }
+        }
+    }
+}
+
+impl FrameworkProvider for JestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(JestProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "Jest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::JavaScript
+    }
+
+    fn languages(&self) -> Vec<crate_language> {
+        vec![crate_language::JavaScript, crate_language::TypeScript]
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for JestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !Self::is_test_file(&target.buffer.filepath) {
+            return Detection::rejected("file is not a `*.test.*`, `*.spec.*` or `__tests__` file");
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content, &target.buffer.filepath)
+        else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if jest_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `test` or `it` calls");
+        }
+
+        Detection::matched(80, "test file with `test` or `it` calls")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content, &target.buffer.filepath)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_js_project::op::execute(&target.buffer.filepath);
+        for runnable in res.iter_mut() {
+            runnable.meta.set_js_project(
+                project.root.to_string_lossy().to_string(),
+                project.runner.clone(),
+            );
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        let (project, exact) = runnable
+            .meta
+            .get_js_meta()
+            .map(|m| {
+                let project = get_js_project::op::JsProject {
+                    root: PathBuf::from(m.root),
+                    runner: m.runner,
+                };
+                (project, m.exact)
+            })
+            .unwrap_or_else(|| (get_js_project::op::execute(&runnable.filepath), true));
+        // the file is passed relative to the package root
+        let mut cmd = Command {
+            command: "npx".to_string(),
+            args: vec![],
+            cwd: Some(project.root.clone()),
+            ..Default::default()
+        };
+
+        match project.runner {
+            JsRunner::Jest => cmd.args.push("jest".to_string()),
+            JsRunner::Vitest => {
+                cmd.args.push("vitest".to_string());
+                cmd.args.push("run".to_string());
+            }
+        }
+        cmd.args.push(project.relative_path(&runnable.filepath));
+        // an unnamed runnable stands for every test of its file
+        if !runnable.name.is_empty() {
+            cmd.args.push("-t".to_string());
+            cmd.args
+                .push(jest_name_pattern::op::execute(&runnable.name, exact));
+        }
+        cmd
+    }
+
+    // try_generate_combined_command
+    //
+    // Runnables of a file run as the file.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let runnable = Runnable::combined(&runnables)
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        Ok(self.generate_command(runnable))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
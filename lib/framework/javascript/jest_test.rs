@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::javascript::jest,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+import { describe, expect, it } from "vitest";
+
+const add = (a: number, b: number): number => a + b;
+
+describe("math", () => {
+  describe("add", () => {
+    it("adds two numbers", () => {
+      expect(add(1, 1)).toBe(2);
+    });
+
+    it.each([
+      [1, 2, 3],
+      [2, 2, 4],
+    ])("add(%i, %i) -> %i", (a, b, expected) => {
+      expect(add(a, b)).toBe(expected);
+    });
+  });
+
+  test.only(`handles ${"zero"}`, () => {
+    expect(add(0, 0)).toBe(0);
+  });
+});
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/javascript/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["math add adds two numbers", "math add add(%i, %i) -> %i", "math handles ${\"zero\"}"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(8, 6), vec!["math add adds two numbers"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(13, 8), vec!["math add add(%i, %i) -> %i"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(19, 4), vec!["math handles ${\"zero\"}"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(10, 4), vec!["math add"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(18, 2), vec!["math"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("vitest_workspace/src/math.test.ts"),
+            position,
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = jest::JestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("vitest_workspace/src/math.test.ts"),
+            types::CursorPosition::new(3, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Nearest);
+        let provider = jest::JestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "vitest_workspace/src/math.test.ts",
+        types::CursorPosition::new(8, 6),
+        "vitest_workspace",
+        vec!["vitest", "run", "src/math.test.ts", "-t", "^math add adds two numbers$"]
+    )]
+    #[case(
+        "vitest_workspace/packages/ui/src/App.test.tsx",
+        types::CursorPosition::new(13, 8),
+        "vitest_workspace",
+        vec!["vitest", "run", "packages/ui/src/App.test.tsx", "-t", "^math add add\\(.*, .*\\) -> .*$"]
+    )]
+    #[case(
+        "jest_project/src/math.test.js",
+        types::CursorPosition::new(10, 4),
+        "jest_project",
+        vec!["jest", "src/math.test.js", "-t", "^math add "]
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] position: types::CursorPosition,
+        #[case] root: &str,
+        #[case] expected_args: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = jest::JestProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq("npx"));
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+        let expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        assert_that!(cmd.args, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "src/math.test.ts", true)]
+    #[case(enums::Capability::TestRunner, "src/math.spec.mjs", true)]
+    #[case(enums::Capability::TestRunner, "src/__tests__/math.cts", true)]
+    #[case(enums::Capability::TestRunner, "src/math.ts", false)]
+    #[case(enums::Capability::TestRunner, "src/math.test.py", false)]
+    #[case(enums::Capability::Debugger, "src/math.test.ts", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            CONTENT,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = jest::JestProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod jest;
+#[cfg(test)]
+mod jest_test;
+mod operations;
+pub(crate) mod runnable_meta;
+mod treesitter;
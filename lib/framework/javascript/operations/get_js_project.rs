@@ -0,0 +1,139 @@
+pub(crate) mod op {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::core::{
+        metadata::JsRunner,
+        paths::{absolute, find_ancestor},
+    };
+
+    const VITEST_CONFIGS: [&str; 6] = [
+        "vitest.config.ts",
+        "vitest.config.mts",
+        "vitest.config.cts",
+        "vitest.config.js",
+        "vitest.config.mjs",
+        "vitest.config.cjs",
+    ];
+
+    const JEST_CONFIGS: [&str; 6] = [
+        "jest.config.ts",
+        "jest.config.mts",
+        "jest.config.cts",
+        "jest.config.js",
+        "jest.config.mjs",
+        "jest.config.cjs",
+    ];
+
+    // JsProject
+    //
+    // The package a test file belongs to and the runner it depends on.
+    pub(crate) struct JsProject {
+        pub(crate) root: PathBuf,
+        pub(crate) runner: JsRunner,
+    }
+
+    impl JsProject {
+        // relative_path
+        //
+        // Path of a file relative to the package root, the form passed to
+        // the runner from the root.
+        pub(crate) fn relative_path(&self, filepath: &str) -> String {
+            let file = absolute(Path::new(filepath));
+            let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+    }
+
+    // execute
+    //
+    // Resolves the closest `package.json` that depends on vitest or jest, or
+    // holds one of their configuration files. In a workspace this skips
+    // packages without a runner of their own. Without any, the closest
+    // package runs jest.
+    pub(crate) fn execute(filepath: &str) -> JsProject {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        let configured = find_ancestor(&directory, |dir| runner(dir).is_some());
+        if let Some(root) = configured {
+            let runner = runner(&root).unwrap_or(JsRunner::Jest);
+            return JsProject { root, runner };
+        }
+
+        let root = find_ancestor(&directory, |dir| dir.join("package.json").is_file())
+            .unwrap_or(directory);
+        JsProject {
+            root,
+            runner: JsRunner::Jest,
+        }
+    }
+
+    fn runner(dir: &Path) -> Option<JsRunner> {
+        let manifest = fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())?;
+        let depends_on = |name: &str| {
+            ["dependencies", "devDependencies"]
+                .iter()
+                .any(|section| manifest.get(section).and_then(|s| s.get(name)).is_some())
+        };
+
+        if depends_on("vitest") || VITEST_CONFIGS.iter().any(|c| dir.join(c).is_file()) {
+            return Some(JsRunner::Vitest);
+        }
+        if depends_on("jest")
+            || manifest.get("jest").is_some()
+            || JEST_CONFIGS.iter().any(|c| dir.join(c).is_file())
+        {
+            return Some(JsRunner::Jest);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::metadata::JsRunner;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/javascript/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("jest_project/src/math.test.js", "jest_project", JsRunner::Jest)]
+    #[case(
+        "vitest_workspace/src/math.test.ts",
+        "vitest_workspace",
+        JsRunner::Vitest
+    )]
+    #[case(
+        "vitest_workspace/packages/ui/src/App.test.tsx",
+        "vitest_workspace",
+        JsRunner::Vitest
+    )]
+    fn resolves_root_and_runner(
+        #[case] path: &str,
+        #[case] root: &str,
+        #[case] expected: JsRunner,
+    ) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res.root.ends_with(root), eq(true));
+        assert_that!(res.runner, eq(&expected));
+    }
+}
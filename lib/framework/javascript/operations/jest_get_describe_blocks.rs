@@ -0,0 +1,62 @@
+pub(crate) mod op {
+    use tree_sitter::Node;
+
+    use crate::{
+        core::types::{Runnable, Target},
+        framework::javascript::operations::jest_get_file_tests,
+    };
+
+    // execute
+    //
+    // Returns the `describe` blocks of the file, named by their full suite
+    // path. Their runnables are not exact: the whole suite matches.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> = jest_get_file_tests::op::blocks(root, target)?
+            .into_iter()
+            .filter(|b| b.suite)
+            .map(|b| jest_get_file_tests::op::runnable(&b, target))
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::javascript::operations::parse_tree;
+
+    #[gtest]
+    fn finds_describe_blocks() {
+        let content = r#"
+describe("math", () => {
+  describe.each([1, 2])("with %i", (n) => {
+    it("adds", () => {});
+  });
+});
+"#;
+        let buffer = Buffer::new(
+            content,
+            "src/math.test.js".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content, "src/math.test.js").unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec!["math".to_string(), "math with %i".to_string()])
+        );
+    }
+}
@@ -0,0 +1,290 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::{JsRunner, RunnableMeta},
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::javascript::{operations::parse_tree, treesitter::jest_call},
+        treesitter::node,
+    };
+
+    const SUITES: [&str; 4] = ["describe", "fdescribe", "xdescribe", "suite"];
+    const TESTS: [&str; 5] = ["it", "test", "fit", "xit", "xtest"];
+    const MODIFIERS: [&str; 12] = [
+        "only",
+        "skip",
+        "todo",
+        "concurrent",
+        "sequential",
+        "shuffle",
+        "failing",
+        "fails",
+        "each",
+        "for",
+        "skipIf",
+        "runIf",
+    ];
+
+    // Block
+    //
+    // A `describe` or `it`/`test` call with its full name, the names of the
+    // enclosing suites and its own joined by spaces as jest and vitest match
+    // `-t` against.
+    pub(crate) struct Block<'a> {
+        pub(crate) name: String,
+        pub(crate) suite: bool,
+        pub(crate) node: Node<'a>,
+    }
+
+    // execute
+    //
+    // Returns every runnable test of the file. `.todo` tests have no body
+    // and are left out, `.each` tables keep their placeholders in the name
+    // and run every row.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> = blocks(root, target)?
+            .into_iter()
+            .filter(|b| !b.suite)
+            .map(|b| runnable(&b, target))
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // runnable
+    //
+    // Converts a block to a runnable, suites match by prefix rather than
+    // exactly.
+    pub(crate) fn runnable(block: &Block, target: &Target) -> Runnable {
+        Runnable {
+            name: block.name.to_string(),
+            filepath: target.buffer.filepath.to_string(),
+            range: Range {
+                start: CursorPosition::from_point(block.node.start_position()),
+                end: CursorPosition::from_point(block.node.end_position()),
+            },
+            meta: RunnableMeta::JavaScript {
+                root: String::new(),
+                runner: JsRunner::Jest,
+                exact: !block.suite,
+            },
+        }
+    }
+
+    // blocks
+    //
+    // Returns the `describe` and `it`/`test` calls of the file in source
+    // order.
+    pub(crate) fn blocks<'a>(root: Node<'a>, target: &Target) -> Option<Vec<Block<'a>>> {
+        let content = target.buffer.content;
+        let query_pattern = jest_call::query();
+        let query = Query::new(
+            &parse_tree::op::language(&target.buffer.filepath),
+            &query_pattern,
+        )
+        .ok()?;
+        let callee_index = query.capture_index_for_name("jest.callee")?;
+        let name_index = query.capture_index_for_name("jest.name")?;
+        let call_index = query.capture_index_for_name("jest.call")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut calls: Vec<(Node, String, bool)> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(call), Some(callee), Some(name)) = (
+                capture(call_index),
+                capture(callee_index),
+                capture(name_index),
+            ) else {
+                continue;
+            };
+
+            // `test.each` with a tagged template is itself a call named by
+            // the table, only the outer call holds the test name
+            if call
+                .parent()
+                .and_then(|p| p.child_by_field_name("function"))
+                .is_some_and(|f| f.id() == call.id())
+            {
+                continue;
+            }
+
+            let Some(segments) = segments(callee, content) else {
+                continue;
+            };
+            if segments.iter().any(|s| s == "todo") {
+                continue;
+            }
+            let base = segments.first().map(|s| s.as_str()).unwrap_or_default();
+            let suite = SUITES.contains(&base);
+            if !suite && !TESTS.contains(&base) {
+                continue;
+            }
+            if !segments[1..]
+                .iter()
+                .all(|s| MODIFIERS.contains(&s.as_str()))
+            {
+                continue;
+            }
+            calls.push((call, literal(name, content), suite));
+        }
+
+        let blocks: Vec<Block> = calls
+            .iter()
+            .map(|(call, name, suite)| {
+                let mut path: Vec<&str> = calls
+                    .iter()
+                    .filter(|(parent, _, parent_suite)| {
+                        *parent_suite
+                            && parent.id() != call.id()
+                            && parent.start_byte() <= call.start_byte()
+                            && call.end_byte() <= parent.end_byte()
+                    })
+                    .map(|(_, parent_name, _)| parent_name.as_str())
+                    .collect();
+                path.push(name);
+                Block {
+                    name: path.join(" "),
+                    suite: *suite,
+                    node: *call,
+                }
+            })
+            .collect();
+
+        if blocks.is_empty() {
+            None
+        } else {
+            Some(blocks)
+        }
+    }
+
+    // segments
+    //
+    // Flattens a callee such as `it.concurrent.each(table)` to
+    // `["it", "concurrent", "each"]`, `None` for anything else than a chain
+    // of identifiers.
+    fn segments(callee: Node, content: &str) -> Option<Vec<String>> {
+        match callee.kind() {
+            "identifier" => Some(vec![node::node_text(callee, content)]),
+            "member_expression" => {
+                let mut res = segments(callee.child_by_field_name("object")?, content)?;
+                res.push(node::node_text(
+                    callee.child_by_field_name("property")?,
+                    content,
+                ));
+                Some(res)
+            }
+            "call_expression" => segments(callee.child_by_field_name("function")?, content),
+            _ => None,
+        }
+    }
+
+    // literal
+    //
+    // Text of a string or template literal without its delimiters.
+    fn literal(node: Node, content: &str) -> String {
+        let text = node::node_text(node, content);
+        let mut chars = text.chars();
+        chars.next();
+        chars.next_back();
+        chars
+            .as_str()
+            .replace("\\'", "'")
+            .replace("\\\"", "\"")
+            .replace("\\`", "`")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::javascript::operations::parse_tree;
+
+    #[gtest]
+    fn finds_tests_with_full_names() {
+        let content = r#"
+import { describe, it, test, expect } from "vitest";
+
+describe("math", () => {
+  describe.only(`nested ${kind}`, () => {
+    it("adds", () => {
+      expect(1 + 1).toBe(2);
+    });
+
+    it.each([[1, 2]])("adds %i", (a, b) => {
+      expect(a + 1).toBe(b);
+    });
+
+    test.each`
+      a    | b
+      ${1} | ${2}
+    `("returns $b", ({ a, b }) => {
+      expect(a + 1).toBe(b);
+    });
+  });
+
+  test.skip('don\'t subtract', () => {});
+  test.todo("divides");
+});
+
+helper("not a test", () => {});
+"#;
+        let buffer = Buffer::new(
+            content,
+            "src/math.test.ts".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content, "src/math.test.ts").unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "math nested ${kind} adds".to_string(),
+                "math nested ${kind} adds %i".to_string(),
+                "math nested ${kind} returns $b".to_string(),
+                "math don't subtract".to_string(),
+            ])
+        );
+        assert_that!(res[2].range.start.row, eq(13));
+        assert_that!(res[2].range.end.row, eq(18));
+    }
+
+    #[gtest]
+    fn parses_jsx() {
+        let content = "it(\"renders\", () => {\n  render(<App />);\n});\n";
+        let buffer = Buffer::new(
+            content,
+            "src/App.test.jsx".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content, "src/App.test.jsx").unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        assert_that!(res[0].name, eq("renders"));
+    }
+}
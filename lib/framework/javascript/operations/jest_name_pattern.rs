@@ -0,0 +1,78 @@
+pub(crate) mod op {
+    // execute
+    //
+    // Converts a full test name into the anchored expression passed to
+    // `-t`, e.g. `math adds %i` into `^math adds .*$`. The placeholders of
+    // `.each` tables (`%s`, `$variable`) and template substitutions match any
+    // text so every row runs. A suite is matched as a prefix of the names of
+    // the tests it holds.
+    pub(crate) fn execute(name: &str, exact: bool) -> String {
+        let mut res = String::with_capacity(name.len() + 2);
+        res.push('^');
+        let mut chars = name.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('%', Some('%')) => {
+                    chars.next();
+                    res.push('%');
+                }
+                ('%', Some(p)) if "sdifjopP#".contains(*p) => {
+                    chars.next();
+                    res.push_str(".*");
+                }
+                ('$', Some('{')) => {
+                    let mut depth = 0;
+                    for s in chars.by_ref() {
+                        match s {
+                            '{' => depth += 1,
+                            '}' if depth == 1 => break,
+                            '}' => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                    res.push_str(".*");
+                }
+                ('$', Some(p)) if p.is_alphabetic() || *p == '_' || *p == '#' => {
+                    while chars
+                        .peek()
+                        .is_some_and(|s| s.is_alphanumeric() || "_.#".contains(*s))
+                    {
+                        chars.next();
+                    }
+                    res.push_str(".*");
+                }
+                _ => {
+                    if "\\.+*?()|[]{}^$".contains(c) {
+                        res.push('\\');
+                    }
+                    res.push(c);
+                }
+            }
+        }
+        if exact {
+            res.push('$');
+        } else {
+            res.push(' ');
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case("math adds", true, "^math adds$")]
+    #[case("math", false, "^math ")]
+    #[case("math adds %i to %d", true, "^math adds .* to .*$")]
+    #[case("returns $b and $a.value", true, "^returns .* and .*$")]
+    #[case("nested ${kind({ a: 1 })} works", true, "^nested .* works$")]
+    #[case("costs 100%% (or $5)", true, "^costs 100% \\(or \\$5\\)$")]
+    fn name_pattern(#[case] name: &str, #[case] exact: bool, #[case] expected: &str) {
+        assert_that!(op::execute(name, exact), eq(expected))
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod get_js_project;
+pub(crate) mod jest_get_describe_blocks;
+pub(crate) mod jest_get_file_tests;
+pub(crate) mod jest_name_pattern;
+pub(crate) mod parse_tree;
@@ -0,0 +1,34 @@
+pub(crate) mod op {
+    use tree_sitter::{Language, Parser, Tree};
+
+    use crate::core::errors::FrameworkError;
+
+    // language
+    //
+    // Grammar for a file: typescript and tsx are separate grammars, while the
+    // javascript grammar covers jsx.
+    pub(crate) fn language(filepath: &str) -> Language {
+        let extension = filepath.rsplit('.').next().unwrap_or_default();
+        match extension {
+            "ts" | "mts" | "cts" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            "tsx" => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            _ => tree_sitter_javascript::LANGUAGE.into(),
+        }
+    }
+
+    pub(crate) fn execute(content: &str, filepath: &str) -> Result<Tree, FrameworkError> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&language(filepath))
+            .expect("Error loading JavaScript parser");
+
+        let tree = parser.parse(content, None);
+        if tree.is_none() {
+            return Err(FrameworkError::ParsingError(
+                "failed to parse content to tree".to_string(),
+            ));
+        }
+
+        Ok(tree.unwrap())
+    }
+}
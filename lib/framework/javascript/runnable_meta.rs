@@ -0,0 +1,36 @@
+use crate::core::metadata::{JsRunner, RunnableMeta};
+
+impl RunnableMeta {
+    pub(in crate::framework::javascript) fn set_js_project(
+        &mut self,
+        project_root: String,
+        project_runner: JsRunner,
+    ) {
+        if let RunnableMeta::JavaScript { root, runner, .. } = self {
+            *root = project_root;
+            *runner = project_runner;
+        }
+    }
+
+    pub(in crate::framework::javascript) fn get_js_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::JavaScript {
+            root,
+            runner,
+            exact,
+        } = self
+        {
+            return Some(Meta {
+                root: root.clone(),
+                runner: runner.clone(),
+                exact: *exact,
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::javascript) struct Meta {
+    pub(in crate::framework::javascript) root: String,
+    pub(in crate::framework::javascript) runner: JsRunner,
+    pub(in crate::framework::javascript) exact: bool,
+}
@@ -0,0 +1,25 @@
+// query
+//
+// Finds calls named by a leading string or template literal, the shape of
+// every jest and vitest block. The callee is classified after the match
+// since it may be a chain such as `describe.only` or `test.each(table)`.
+//
+// Example:
+// describe("math", () => {
+//   it.each([[1, 2]])("adds %i", (a, b) => {
+//     expect(a + 1).toBe(b);
+//   });
+// });
+//
+// This will find the following calls:
+// - describe("math", ...)
+// - it.each([[1, 2]])("adds %i", ...)
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((call_expression
+                    function: (_) @jest.callee
+                    arguments: (arguments . [(string) (template_string)] @jest.name)) @jest.call)]]
+            "#;
+
+    res.to_string()
+}
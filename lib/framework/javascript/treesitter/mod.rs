@@ -0,0 +1 @@
+pub(crate) mod jest_call;
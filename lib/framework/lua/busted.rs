@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::LuaRunner;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::lua::operations::busted_filter_pattern;
+use crate::framework::lua::operations::busted_get_describe_blocks;
+use crate::framework::lua::operations::busted_get_file_tests;
+use crate::framework::lua::operations::get_lua_project;
+use crate::framework::lua::operations::parse_tree;
+
+// BustedProvider
+//
+// Runs lua `*_spec.lua` files with busted, or with plenary.nvim inside a
+// headless neovim for plugins that ship a minimal init. Plenary cannot
+// filter tests, so every search runs the whole spec there.
+pub struct BustedProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static FILE_SUFFIX: &str = "_spec.lua";
+
+impl BustedProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the innermost it block under the cursor.
+         *       Outside of a test, fall back to the innermost describe block holding the cursor
+         *   - Search set to method, return the it block under the cursor
+         *   - Search set to file, return all it blocks in the spec
+         * */
+        let root = tree.root_node();
+        let tests = busted_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError("Busted test not found no tests in this file".to_string())
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .filter(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .max_by_key(|t| t.range.start.row)
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => Ok(tests),
+            Search::Method => under_cursor.map(|t| vec![t]).ok_or_else(|| {
+                FrameworkError::NotFoundError("Busted test not found at position".to_string())
+            }),
+            Search::Nearest => {
+                if let Some(test) = under_cursor {
+                    return Ok(vec![test]);
+                }
+                busted_get_describe_blocks::op::execute(root, target)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|d| position.in_range(d.range.start.to_point()..d.range.end.to_point()))
+                    .max_by_key(|d| d.range.start.row)
+                    .map(|d| vec![d])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError(
+                            "Busted test not found at position".to_string(),
+                        )
+                    })
+            }
+        }
+    }
+}
+
+impl FrameworkProvider for BustedProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(BustedProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "Busted"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Lua
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for BustedProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if busted_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `it` blocks");
+        }
+
+        Detection::matched(80, format!("`{}` file with `it` blocks", FILE_SUFFIX))
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_lua_project::op::execute(&target.buffer.filepath);
+        for runnable in res.iter_mut() {
+            runnable.meta.set_lua_project(
+                project.root.to_string_lossy().to_string(),
+                project.runner.clone(),
+            );
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        let (project, exact) = runnable
+            .meta
+            .get_lua_meta()
+            .map(|m| {
+                let project = get_lua_project::op::LuaProject {
+                    root: PathBuf::from(m.root),
+                    runner: m.runner,
+                };
+                (project, m.exact)
+            })
+            .unwrap_or_else(|| (get_lua_project::op::execute(&runnable.filepath), true));
+        let filepath = project.relative_path(&runnable.filepath);
+        // specs are passed relative to the project root
+        let cwd = Some(project.root.clone());
+
+        match project.runner {
+            LuaRunner::Busted => {
+                let mut args = vec![];
+                // an unnamed runnable stands for every test of its file
+                if !runnable.name.is_empty() {
+                    args.push("--filter".to_string());
+                    args.push(busted_filter_pattern::op::execute(&runnable.name, exact));
+                }
+                args.push(filepath);
+                Command {
+                    command: "busted".to_string(),
+                    args,
+                    cwd,
+                    ..Default::default()
+                }
+            }
+            LuaRunner::Plenary => {
+                let mut args = vec!["--headless".to_string(), "--noplugin".to_string()];
+                if let Some(init) = project.minimal_init() {
+                    args.extend(["-u".to_string(), init.to_string()]);
+                }
+                args.extend([
+                    "-c".to_string(),
+                    format!("PlenaryBustedFile {}", fnameescape(&filepath)),
+                ]);
+                Command {
+                    command: "nvim".to_string(),
+                    args,
+                    cwd,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+
+    // try_generate_combined_command
+    //
+    // Runnables of a file run as the file.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let runnable = Runnable::combined(&runnables)
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        Ok(self.generate_command(runnable))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
+
+// fnameescape
+//
+// Escapes a path as vim's `fnameescape()` does, for it to stay a single
+// argument of an ex command.
+pub(crate) fn fnameescape(path: &str) -> String {
+    let mut res = String::with_capacity(path.len());
+    for (i, c) in path.chars().enumerate() {
+        if " \t\n*?[{`$\\%#'\"|!<".contains(c) || (i == 0 && "+->".contains(c)) {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::lua::busted,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+local config = require("plugin.config")
+
+describe("config", function()
+  describe("defaults", function()
+    it("sets the runner", function()
+      assert.are.equal("busted", config.runner)
+    end)
+
+    it("keeps 100% of options", function()
+      assert.are.same({}, config.options)
+    end)
+  end)
+
+  it("merges user options", function()
+    assert.is_true(config.setup({ runner = "plenary" }))
+  end)
+end)
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/lua/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["config defaults sets the runner", "config defaults keeps 100% of options", "config merges user options"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(6, 6), vec!["config defaults sets the runner"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(10, 6), vec!["config defaults keeps 100% of options"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(8, 4), vec!["config defaults"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(13, 2), vec!["config"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("busted_project/spec/config_spec.lua"),
+            position,
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = busted::BustedProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("busted_project/spec/config_spec.lua"),
+            types::CursorPosition::new(1, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Nearest);
+        let provider = busted::BustedProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "busted_project/spec/config_spec.lua",
+        types::CursorPosition::new(10, 6),
+        "busted",
+        vec!["--filter", "^config defaults keeps 100%% of options$", "spec/config_spec.lua"]
+    )]
+    #[case(
+        "busted_project/spec/config_spec.lua",
+        types::CursorPosition::new(8, 4),
+        "busted",
+        vec!["--filter", "^config defaults ", "spec/config_spec.lua"]
+    )]
+    #[case(
+        "plenary_plugin/tests/config_spec.lua",
+        types::CursorPosition::new(10, 6),
+        "nvim",
+        vec![
+            "--headless",
+            "--noplugin",
+            "-u",
+            "tests/minimal_init.lua",
+            "-c",
+            "PlenaryBustedFile tests/config_spec.lua"
+        ]
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] position: types::CursorPosition,
+        #[case] expected_command: &str,
+        #[case] expected_args: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = busted::BustedProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq(expected_command));
+        let root = path.split('/').next().unwrap();
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+        let expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        assert_that!(cmd.args, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "spec/config_spec.lua", true)]
+    #[case(enums::Capability::TestRunner, "lua/plugin/config.lua", false)]
+    #[case(enums::Capability::Debugger, "spec/config_spec.lua", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            CONTENT,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = busted::BustedProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("tests/config_spec.lua", "tests/config_spec.lua")]
+    #[case("tests/my spec_spec.lua", "tests/my\\ spec_spec.lua")]
+    #[case("tests/100%|#1_spec.lua", "tests/100\\%\\|\\#1_spec.lua")]
+    #[case("+x_spec.lua", "\\+x_spec.lua")]
+    fn escapes_plenary_spec_path(#[case] path: &str, #[case] expected: &str) {
+        // act
+        let res = busted::fnameescape(path);
+
+        // assert
+        assert_that!(res, eq(expected));
+    }
+}
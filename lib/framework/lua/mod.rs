@@ -0,0 +1,6 @@
+pub mod busted;
+#[cfg(test)]
+mod busted_test;
+mod operations;
+pub(crate) mod runnable_meta;
+mod treesitter;
@@ -0,0 +1,40 @@
+pub(crate) mod op {
+    // execute
+    //
+    // Converts a full test name into the anchored lua pattern accepted by
+    // `busted --filter`, e.g. `config sets 100%` into `^config sets 100%%$`.
+    // A describe block is matched as a prefix of the names of its tests.
+    pub(crate) fn execute(name: &str, exact: bool) -> String {
+        let mut res = String::with_capacity(name.len() + 2);
+        res.push('^');
+        for c in name.chars() {
+            if "^$()%.[]*+-?".contains(c) {
+                res.push('%');
+            }
+            res.push(c);
+        }
+        if exact {
+            res.push('$');
+        } else {
+            res.push(' ');
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::op;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    #[gtest]
+    #[rstest]
+    #[case("config sets the runner", true, "^config sets the runner$")]
+    #[case("config", false, "^config ")]
+    #[case("config sets 100% (max)", true, "^config sets 100%% %(max%)$")]
+    #[case("parses a.b-c", true, "^parses a%.b%-c$")]
+    fn filter_pattern(#[case] name: &str, #[case] exact: bool, #[case] expected: &str) {
+        assert_that!(op::execute(name, exact), eq(expected))
+    }
+}
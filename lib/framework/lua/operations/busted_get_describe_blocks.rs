@@ -0,0 +1,63 @@
+pub(crate) mod op {
+    use tree_sitter::Node;
+
+    use crate::{
+        core::types::{Runnable, Target},
+        framework::lua::operations::busted_get_file_tests,
+    };
+
+    // execute
+    //
+    // Returns the `describe` blocks of the spec, named by their full path.
+    // Their runnables are not exact: the whole block matches.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> =
+            busted_get_file_tests::op::blocks(root, target.buffer.content)?
+                .into_iter()
+                .filter(|b| b.suite)
+                .map(|b| busted_get_file_tests::op::runnable(&b, target))
+                .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::lua::operations::parse_tree;
+
+    #[gtest]
+    fn finds_describe_blocks() {
+        let content = r#"
+describe("config", function()
+  describe("defaults", function()
+    it("sets the runner", function() end)
+  end)
+end)
+"#;
+        let buffer = Buffer::new(
+            content,
+            "spec/config_spec.lua".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec!["config".to_string(), "config defaults".to_string()])
+        );
+    }
+}
@@ -0,0 +1,194 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::{LuaRunner, RunnableMeta},
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::lua::treesitter::busted_call,
+        treesitter::node,
+    };
+
+    const SUITES: [&str; 4] = ["describe", "context", "insulate", "expose"];
+    const TESTS: [&str; 3] = ["it", "spec", "test"];
+
+    // Block
+    //
+    // A `describe` or `it` call with its full name, the names of the
+    // enclosing blocks and its own joined by spaces as busted reports them.
+    pub(crate) struct Block<'a> {
+        pub(crate) name: String,
+        pub(crate) suite: bool,
+        pub(crate) node: Node<'a>,
+    }
+
+    // execute
+    //
+    // Returns every `it` block of the spec. `pending` blocks have nothing to
+    // run and are left out.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> = blocks(root, target.buffer.content)?
+            .into_iter()
+            .filter(|b| !b.suite)
+            .map(|b| runnable(&b, target))
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // runnable
+    //
+    // Converts a block to a runnable, describe blocks match by prefix rather
+    // than exactly.
+    pub(crate) fn runnable(block: &Block, target: &Target) -> Runnable {
+        Runnable {
+            name: block.name.to_string(),
+            filepath: target.buffer.filepath.to_string(),
+            range: Range {
+                start: CursorPosition::from_point(block.node.start_position()),
+                end: CursorPosition::from_point(block.node.end_position()),
+            },
+            meta: RunnableMeta::Lua {
+                root: String::new(),
+                runner: LuaRunner::Busted,
+                exact: !block.suite,
+            },
+        }
+    }
+
+    // blocks
+    //
+    // Returns the `describe` and `it` calls of the spec in source order.
+    // `a.describe`/`a.it` from `plenary.async.tests` count as well.
+    pub(crate) fn blocks<'a>(root: Node<'a>, content: &str) -> Option<Vec<Block<'a>>> {
+        let query_pattern = busted_call::query();
+        let query = Query::new(&Language::new(tree_sitter_lua::LANGUAGE), &query_pattern).ok()?;
+        let callee_index = query.capture_index_for_name("busted.callee")?;
+        let name_index = query.capture_index_for_name("busted.name")?;
+        let call_index = query.capture_index_for_name("busted.call")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut calls: Vec<(Node, String, bool)> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(call), Some(callee), Some(name)) = (
+                capture(call_index),
+                capture(callee_index),
+                capture(name_index),
+            ) else {
+                continue;
+            };
+
+            let callee = match callee.kind() {
+                "identifier" => node::node_text(callee, content),
+                "dot_index_expression" => match callee.child_by_field_name("field") {
+                    Some(field) => node::node_text(field, content),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let suite = SUITES.contains(&callee.as_str());
+            if !suite && !TESTS.contains(&callee.as_str()) {
+                continue;
+            }
+            let name = name
+                .child_by_field_name("content")
+                .map(|c| node::node_text(c, content))
+                .unwrap_or_default();
+            calls.push((call, name, suite));
+        }
+
+        let blocks: Vec<Block> = calls
+            .iter()
+            .map(|(call, name, suite)| {
+                let mut path: Vec<&str> = calls
+                    .iter()
+                    .filter(|(parent, _, parent_suite)| {
+                        *parent_suite
+                            && parent.id() != call.id()
+                            && parent.start_byte() <= call.start_byte()
+                            && call.end_byte() <= parent.end_byte()
+                    })
+                    .map(|(_, parent_name, _)| parent_name.as_str())
+                    .collect();
+                path.push(name);
+                Block {
+                    name: path.join(" "),
+                    suite: *suite,
+                    node: *call,
+                }
+            })
+            .collect();
+
+        if blocks.is_empty() {
+            None
+        } else {
+            Some(blocks)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::lua::operations::parse_tree;
+
+    #[gtest]
+    fn finds_tests_with_full_names() {
+        let content = r#"
+local a = require("plenary.async.tests")
+
+describe("config", function()
+  context("defaults", function()
+    it("sets the runner", function()
+      assert.are.equal("busted", require("config").runner)
+    end)
+  end)
+
+  a.it("loads asynchronously", function() end)
+
+  pending("merges user options")
+end)
+
+helper("not a test", function() end)
+"#;
+        let buffer = Buffer::new(
+            content,
+            "spec/config_spec.lua".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "config defaults sets the runner".to_string(),
+                "config loads asynchronously".to_string(),
+            ])
+        );
+        assert_that!(res[0].range.start.row, eq(5));
+        assert_that!(res[0].range.end.row, eq(7));
+    }
+}
@@ -0,0 +1,127 @@
+pub(crate) mod op {
+    use std::path::{Path, PathBuf};
+
+    use crate::core::{
+        metadata::LuaRunner,
+        paths::{absolute, find_ancestor},
+    };
+
+    // PLENARY_MARKERS
+    //
+    // Minimal init files neovim plugins load before running their specs
+    // with plenary.nvim.
+    const PLENARY_MARKERS: [&str; 4] = [
+        "tests/minimal_init.lua",
+        "tests/minimal_init.vim",
+        "test/minimal_init.lua",
+        "scripts/minimal_init.lua",
+    ];
+
+    // LuaProject
+    //
+    // The directory specs run from and the runner the project uses.
+    pub(crate) struct LuaProject {
+        pub(crate) root: PathBuf,
+        pub(crate) runner: LuaRunner,
+    }
+
+    impl LuaProject {
+        // relative_path
+        //
+        // Path of a spec relative to the project root.
+        pub(crate) fn relative_path(&self, filepath: &str) -> String {
+            let file = absolute(Path::new(filepath));
+            let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+
+        // minimal_init
+        //
+        // The plenary minimal init of the project, relative to its root.
+        pub(crate) fn minimal_init(&self) -> Option<&'static str> {
+            PLENARY_MARKERS
+                .iter()
+                .find(|m| self.root.join(m).is_file())
+                .copied()
+        }
+    }
+
+    // execute
+    //
+    // Resolves the closest directory holding a `.busted` configuration or a
+    // plenary minimal init. Without either, specs run with busted from the
+    // repository root.
+    pub(crate) fn execute(filepath: &str) -> LuaProject {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        let configured = find_ancestor(&directory, |dir| runner(dir).is_some());
+        if let Some(root) = configured {
+            let runner = runner(&root).unwrap_or(LuaRunner::Busted);
+            return LuaProject { root, runner };
+        }
+
+        let root = find_ancestor(&directory, |dir| dir.join(".git").exists()).unwrap_or(directory);
+        LuaProject {
+            root,
+            runner: LuaRunner::Busted,
+        }
+    }
+
+    fn runner(dir: &Path) -> Option<LuaRunner> {
+        if dir.join(".busted").is_file() {
+            return Some(LuaRunner::Busted);
+        }
+        if PLENARY_MARKERS.iter().any(|m| dir.join(m).is_file()) {
+            return Some(LuaRunner::Plenary);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::metadata::LuaRunner;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/lua/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "busted_project/spec/config_spec.lua",
+        "busted_project",
+        LuaRunner::Busted,
+        None
+    )]
+    #[case(
+        "plenary_plugin/tests/config_spec.lua",
+        "plenary_plugin",
+        LuaRunner::Plenary,
+        Some("tests/minimal_init.lua")
+    )]
+    fn resolves_root_and_runner(
+        #[case] path: &str,
+        #[case] root: &str,
+        #[case] expected: LuaRunner,
+        #[case] init: Option<&str>,
+    ) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res.root.ends_with(root), eq(true));
+        assert_that!(res.runner, eq(&expected));
+        assert_that!(
+            res.relative_path(&fixture(path)),
+            ends_with("config_spec.lua")
+        );
+        assert_that!(res.minimal_init(), eq(init));
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod busted_filter_pattern;
+pub(crate) mod busted_get_describe_blocks;
+pub(crate) mod busted_get_file_tests;
+pub(crate) mod get_lua_project;
+pub(crate) mod parse_tree;
@@ -0,0 +1,36 @@
+use crate::core::metadata::{LuaRunner, RunnableMeta};
+
+impl RunnableMeta {
+    pub(in crate::framework::lua) fn set_lua_project(
+        &mut self,
+        project_root: String,
+        project_runner: LuaRunner,
+    ) {
+        if let RunnableMeta::Lua { root, runner, .. } = self {
+            *root = project_root;
+            *runner = project_runner;
+        }
+    }
+
+    pub(in crate::framework::lua) fn get_lua_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::Lua {
+            root,
+            runner,
+            exact,
+        } = self
+        {
+            return Some(Meta {
+                root: root.clone(),
+                runner: runner.clone(),
+                exact: *exact,
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::lua) struct Meta {
+    pub(in crate::framework::lua) root: String,
+    pub(in crate::framework::lua) runner: LuaRunner,
+    pub(in crate::framework::lua) exact: bool,
+}
@@ -0,0 +1,24 @@
+// query
+//
+// Finds calls whose first argument is a string, the shape of busted and
+// plenary `describe`/`it` blocks. The callee is checked after the match.
+//
+// Example:
+// describe("math", function()
+//   it("adds", function()
+//     assert.are.equal(2, 1 + 1)
+//   end)
+// end)
+//
+// This will find the following calls:
+// - describe("math", ...)
+// - it("adds", ...)
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((function_call
+                    name: (_) @busted.callee
+                    arguments: (arguments . (string) @busted.name)) @busted.call)]]
+            "#;
+
+    res.to_string()
+}
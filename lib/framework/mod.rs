@@ -1 +1,10 @@
+pub(crate) mod cpp;
+pub(crate) mod declarative;
 pub(crate) mod golang;
+pub(crate) mod java;
+pub(crate) mod javascript;
+pub(crate) mod lua;
+pub(crate) mod python;
+pub(crate) mod ruby;
+pub(crate) mod rust;
+pub(crate) mod scripted;
@@ -0,0 +1,9 @@
+mod operations;
+pub mod pytest;
+#[cfg(test)]
+mod pytest_test;
+pub(crate) mod runnable_meta;
+mod treesitter;
+pub mod unittest;
+#[cfg(test)]
+mod unittest_test;
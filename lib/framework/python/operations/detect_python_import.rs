@@ -0,0 +1,61 @@
+pub(crate) mod op {
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{framework::python::treesitter::python_import, treesitter::node::node_text};
+
+    // execute
+    //
+    // Returns whether the file imports the top level package, e.g.
+    // `pytest` matches `import pytest` as well as `from pytest import mark`.
+    pub(crate) fn execute(root: Node, content: &str, package: &str) -> bool {
+        let query_pattern = python_import::query();
+        let query = Query::new(&Language::new(tree_sitter_python::LANGUAGE), &query_pattern);
+        let Ok(query) = query else {
+            return false;
+        };
+        let Some(module_index) = query.capture_index_for_name("import.module") else {
+            return false;
+        };
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+        for node_matched in query_matches {
+            for capture in node_matched.captures.iter() {
+                if capture.index != module_index {
+                    continue;
+                }
+                let module = node_text(capture.node, content);
+                if module.split('.').next() == Some(package) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::framework::python::operations::parse_tree;
+
+    #[gtest]
+    #[rstest]
+    #[case("import unittest\n", "unittest", true)]
+    #[case("import unittest.mock as mock\n", "unittest", true)]
+    #[case("from unittest import TestCase\n", "unittest", true)]
+    #[case("from unittest.mock import patch\n", "unittest", true)]
+    #[case("import pytest\n", "unittest", false)]
+    #[case("import unittest_extras\n", "unittest", false)]
+    fn detects_import(#[case] content: &str, #[case] package: &str, #[case] expected: bool) {
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        assert_that!(
+            op::execute(tree.root_node(), content, package),
+            eq(expected)
+        );
+    }
+}
@@ -0,0 +1,69 @@
+pub(crate) mod op {
+    use tree_sitter::Node;
+
+    use crate::{
+        core::types::Target,
+        framework::python::operations::{
+            detect_python_import, pytest_get_file_tests, unittest_get_test_classes,
+        },
+    };
+
+    // execute
+    //
+    // Decides whether a file belongs to unittest rather than pytest. Pytest
+    // collects `TestCase` subclasses as well, so a file is only handed to
+    // unittest when it defines test cases, does not import pytest and has no
+    // pytest style tests outside of its test cases.
+    pub(crate) fn execute(root: Node, target: &Target) -> bool {
+        let content = target.buffer.content;
+        if detect_python_import::op::execute(root, content, "pytest") {
+            return false;
+        }
+        let Some(classes) = unittest_get_test_classes::op::execute(root, target) else {
+            return false;
+        };
+        let pytest_tests = pytest_get_file_tests::op::execute(root, target).unwrap_or_default();
+        pytest_tests.iter().all(|test| {
+            let class = test.name.split("::").next().unwrap_or_default();
+            classes.iter().any(|(c, _)| c.name == class)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::python::operations::parse_tree;
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "import unittest\n\n\nclass TestUsers(unittest.TestCase):\n    def test_create(self):\n        pass\n",
+        true
+    )]
+    #[case(
+        "import pytest\nimport unittest\n\n\nclass TestUsers(unittest.TestCase):\n    def test_create(self):\n        pass\n",
+        false
+    )]
+    #[case(
+        "import unittest\n\n\nclass TestUsers(unittest.TestCase):\n    def test_create(self):\n        pass\n\n\ndef test_health():\n    pass\n",
+        false
+    )]
+    #[case("def test_health():\n    pass\n", false)]
+    fn detects_unittest_file(#[case] content: &str, #[case] expected: bool) {
+        let buffer = Buffer::new(
+            content,
+            "tests/test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        assert_that!(op::execute(tree.root_node(), &target), eq(expected));
+    }
+}
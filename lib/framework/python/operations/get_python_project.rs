@@ -0,0 +1,181 @@
+pub(crate) mod op {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::core::paths::{absolute, find_ancestor};
+
+    // ROOT_MARKERS
+    //
+    // Files pytest uses to determine its rootdir, the directory node ids are
+    // relative to.
+    const ROOT_MARKERS: [&str; 5] = [
+        "pytest.ini",
+        "pyproject.toml",
+        "tox.ini",
+        "setup.cfg",
+        "setup.py",
+    ];
+
+    // VIRTUAL_ENVS
+    //
+    // Conventional in-project virtual environment interpreters.
+    const VIRTUAL_ENVS: [&str; 4] = [
+        ".venv/bin/python",
+        "venv/bin/python",
+        ".venv/Scripts/python.exe",
+        "venv/Scripts/python.exe",
+    ];
+
+    // PythonProject
+    //
+    // The root directory of a python project and the interpreter command
+    // used to run its tests.
+    pub(crate) struct PythonProject {
+        pub(crate) root: PathBuf,
+        pub(crate) interpreter: Vec<String>,
+    }
+
+    impl PythonProject {
+        // relative_path
+        //
+        // Path of a file relative to the project root, with `/` separators as
+        // used in pytest node ids.
+        pub(crate) fn relative_path(&self, filepath: &str) -> String {
+            let file = absolute(Path::new(filepath));
+            let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+
+        // module_name
+        //
+        // Dotted module name of a file relative to the project root, as
+        // imported by `python -m unittest`, e.g. `tests.test_api`.
+        pub(crate) fn module_name(&self, filepath: &str) -> String {
+            let relative = self.relative_path(filepath);
+            relative
+                .strip_suffix(".py")
+                .unwrap_or(&relative)
+                .replace('/', ".")
+        }
+    }
+
+    // execute
+    //
+    // Resolves the project owning the file: the closest directory holding a
+    // pytest rootdir marker, falling back to the directory of the file.
+    pub(crate) fn execute(filepath: &str) -> PythonProject {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        let root = find_ancestor(&directory, |dir| {
+            ROOT_MARKERS.iter().any(|m| dir.join(m).is_file())
+        })
+        .unwrap_or(directory);
+        let interpreter = interpreter(&root);
+        PythonProject { root, interpreter }
+    }
+
+    // interpreter
+    //
+    // Picks the interpreter for a project, preferring an in-project virtual
+    // environment, then uv and poetry managed projects, then an activated
+    // virtual environment and finally `python3` from the PATH.
+    pub(crate) fn interpreter(root: &Path) -> Vec<String> {
+        let venv = root.ancestors().find_map(|dir| {
+            VIRTUAL_ENVS
+                .iter()
+                .map(|v| dir.join(v))
+                .find(|python| python.exists())
+        });
+        if let Some(python) = venv {
+            return vec![python.to_string_lossy().to_string()];
+        }
+
+        if find_ancestor(root, |dir| dir.join("uv.lock").is_file()).is_some() {
+            return vec!["uv".to_string(), "run".to_string(), "python".to_string()];
+        }
+
+        let poetry = find_ancestor(root, |dir| {
+            dir.join("poetry.lock").is_file()
+                || fs::read_to_string(dir.join("pyproject.toml"))
+                    .ok()
+                    .and_then(|c| c.parse::<toml::Table>().ok())
+                    .is_some_and(|t| t.get("tool").and_then(|t| t.get("poetry")).is_some())
+        });
+        if poetry.is_some() {
+            return vec![
+                "poetry".to_string(),
+                "run".to_string(),
+                "python".to_string(),
+            ];
+        }
+
+        if let Ok(virtual_env) = std::env::var("VIRTUAL_ENV") {
+            let python = Path::new(&virtual_env).join("bin/python");
+            if python.exists() {
+                return vec![python.to_string_lossy().to_string()];
+            }
+        }
+
+        vec!["python3".to_string()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/python/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("uv_project/tests/test_api.py", "uv_project", vec!["uv", "run", "python"])]
+    #[case("poetry_project/tests/test_api.py", "poetry_project", vec!["poetry", "run", "python"])]
+    fn resolves_root_and_managed_interpreter(
+        #[case] path: &str,
+        #[case] root: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res.root.ends_with(root), eq(true));
+        assert_that!(
+            res.interpreter,
+            container_eq(
+                expected
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+            )
+        );
+        assert_that!(res.relative_path(&fixture(path)), eq("tests/test_api.py"));
+        assert_that!(res.module_name(&fixture(path)), eq("tests.test_api"));
+    }
+
+    #[gtest]
+    fn resolves_in_project_virtual_environment() {
+        let path = fixture("venv_project/tests/test_api.py");
+
+        let res = op::execute(&path);
+
+        assert_that!(res.root.ends_with("venv_project"), eq(true));
+        assert_that!(
+            res.interpreter,
+            elements_are![ends_with("venv_project/.venv/bin/python")]
+        );
+    }
+}
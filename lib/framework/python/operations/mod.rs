@@ -0,0 +1,9 @@
+pub(crate) mod detect_python_import;
+pub(crate) mod detect_unittest_file;
+pub(crate) mod get_python_project;
+pub(crate) mod parse_tree;
+pub(crate) mod pytest_get_file_tests;
+pub(crate) mod pytest_get_parametrize_ids;
+pub(crate) mod pytest_get_test_classes;
+pub(crate) mod unittest_get_file_tests;
+pub(crate) mod unittest_get_test_classes;
@@ -0,0 +1,200 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::python::treesitter::pytest_test_function,
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns every test function pytest collects from the file. Runnable
+    // names are relative to the file, e.g. `TestUsers::test_create`, and
+    // their range includes the decorators so the cursor on
+    // `@pytest.mark.parametrize` still resolves to the function.
+    pub fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = pytest_test_function::query();
+        let query =
+            Query::new(&Language::new(tree_sitter_python::LANGUAGE), &query_pattern).ok()?;
+        let test_name_index = query.capture_index_for_name("test_name")?;
+        let test_function_index = query.capture_index_for_name("testfunc")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let function_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == test_function_index)
+                .map(|c| c.node);
+            let name_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == test_name_index)
+                .map(|c| c.node);
+
+            let (Some(function_node), Some(name_node)) = (function_node, name_node) else {
+                continue;
+            };
+
+            let Some(mut path) = class_path(function_node, content) else {
+                continue;
+            };
+            path.push(node::node_text(name_node, content));
+            let definition = definition(function_node);
+            runnables.push(Runnable {
+                name: path.join("::"),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(definition.start_position()),
+                    end: CursorPosition::from_point(definition.end_position()),
+                },
+                meta: RunnableMeta::default_python(),
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // definition
+    //
+    // Returns the decorated definition wrapping a function or class, or the
+    // node itself when it has no decorators.
+    pub(crate) fn definition(node: Node) -> Node {
+        match node.parent() {
+            Some(parent) if parent.kind() == "decorated_definition" => parent,
+            _ => node,
+        }
+    }
+
+    // class_path
+    //
+    // Names of the classes enclosing a definition, outermost first. Returns
+    // `None` when pytest would not collect the definition: it is nested in a
+    // function, or one of its classes is not named `Test*` or defines
+    // `__init__`.
+    pub(crate) fn class_path(item: Node, content: &str) -> Option<Vec<String>> {
+        let mut res = vec![];
+        let mut parent = item.parent();
+        while let Some(node) = parent {
+            match node.kind() {
+                "function_definition" | "lambda" => return None,
+                "class_definition" => {
+                    let name = node::node_text(node.child_by_field_name("name")?, content);
+                    if !name.starts_with("Test") || has_constructor(node, content) {
+                        return None;
+                    }
+                    res.push(name);
+                }
+                _ => {}
+            }
+            parent = node.parent();
+        }
+        res.reverse();
+        Some(res)
+    }
+
+    fn has_constructor(class: Node, content: &str) -> bool {
+        let Some(body) = class.child_by_field_name("body") else {
+            return false;
+        };
+        let mut walker = body.walk();
+        body.named_children(&mut walker).any(|child| {
+            let function = match child.kind() {
+                "decorated_definition" => child.child_by_field_name("definition"),
+                "function_definition" => Some(child),
+                _ => None,
+            };
+            function
+                .and_then(|f| f.child_by_field_name("name"))
+                .is_some_and(|n| node::node_text(n, content) == "__init__")
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::python::operations::parse_tree;
+
+    #[gtest]
+    fn finds_collected_tests() {
+        let content = r#"
+import pytest
+
+
+def helper():
+    def test_nested():
+        pass
+
+
+def test_module_level():
+    assert helper() is None
+
+
+class TestUsers:
+    @pytest.mark.slow
+    def test_create(self):
+        pass
+
+    def create(self):
+        pass
+
+    class TestAdmins:
+        def test_promote(self):
+            pass
+
+
+class TestWithInit:
+    def __init__(self):
+        pass
+
+    def test_ignored(self):
+        pass
+
+
+class Helpers:
+    def test_ignored(self):
+        pass
+"#;
+        let tree = parse_tree::op::execute(content).unwrap();
+        let buffer = Buffer::new(
+            content,
+            "test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+
+        let res = op::execute(tree.root_node(), &target);
+
+        assert_that!(res, some(anything()));
+        let res = res.unwrap();
+        let names: Vec<String> = res.iter().map(|r| r.name.clone()).collect();
+        assert_that!(
+            names,
+            elements_are![
+                eq("test_module_level"),
+                eq("TestUsers::test_create"),
+                eq("TestUsers::TestAdmins::test_promote")
+            ]
+        );
+        // the decorator belongs to the test
+        assert_that!(res[1].range.start.row, eq(14));
+    }
+}
@@ -0,0 +1,437 @@
+pub(crate) mod op {
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    use tree_sitter::Node;
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable},
+        },
+        treesitter::node::node_text,
+    };
+
+    // Param
+    //
+    // A single entry of a `@pytest.mark.parametrize` argument list.
+    struct Param<'a> {
+        id: String,
+        node: Node<'a>,
+    }
+
+    // execute
+    //
+    // Expands the `@pytest.mark.parametrize` decorators of a test into the
+    // ids pytest generates, e.g. `test_create[admin]`. Ids follow pytest's
+    // rules: explicit `ids=[...]` or `pytest.param(..., id=...)` win, then
+    // strings, numbers, booleans and `None` are used verbatim and any other
+    // value becomes `<argname><index>`. Stacked decorators produce the
+    // cartesian product, with the decorator closest to the function first.
+    //
+    // Returns `None` when the test is not parametrized or its values cannot
+    // be read statically, e.g. `argvalues` coming from a variable.
+    pub(crate) fn execute(
+        root: Node,
+        content: &str,
+        parent: &Runnable,
+        cursor_position: Option<CursorPosition>,
+    ) -> Option<Vec<Runnable>> {
+        let definition = root.named_descendant_for_point_range(
+            parent.range.start.to_point(),
+            parent.range.end.to_point(),
+        )?;
+        if definition.kind() != "decorated_definition" {
+            return None;
+        }
+
+        let mut walker = definition.walk();
+        let decorators: Vec<Node> = definition
+            .named_children(&mut walker)
+            .filter(|n| n.kind() == "decorator")
+            .collect();
+
+        let mut combinations: Vec<Vec<Param>> = vec![vec![]];
+        let mut parametrized = false;
+        for decorator in decorators.into_iter().rev() {
+            let Some(call) = decorator.named_child(0).filter(|n| n.kind() == "call") else {
+                continue;
+            };
+            let function = call
+                .child_by_field_name("function")
+                .map(|f| node_text(f, content).replace(char::is_whitespace, ""))
+                .unwrap_or_default();
+            if !function.ends_with("mark.parametrize") {
+                continue;
+            }
+            let params = parametrize(call, content)?;
+            parametrized = true;
+            combinations = combinations
+                .into_iter()
+                .flat_map(|combination| {
+                    params.iter().map(move |p| {
+                        let mut next: Vec<Param> = combination
+                            .iter()
+                            .map(|c| Param {
+                                id: c.id.clone(),
+                                node: c.node,
+                            })
+                            .collect();
+                        next.push(Param {
+                            id: p.id.clone(),
+                            node: p.node,
+                        });
+                        next
+                    })
+                })
+                .collect();
+        }
+
+        if !parametrized {
+            return None;
+        }
+
+        let ids = unique(
+            combinations
+                .iter()
+                .map(|c| {
+                    c.iter()
+                        .map(|p| p.id.as_str())
+                        .collect::<Vec<&str>>()
+                        .join("-")
+                })
+                .collect(),
+        );
+
+        let mut runnables = vec![];
+        for (id, combination) in ids.into_iter().zip(combinations.iter()) {
+            if let Some(position) = cursor_position
+                && !combination.iter().any(|p| {
+                    let r = p.node.range();
+                    position.in_range(Range {
+                        start: r.start_point,
+                        end: r.end_point,
+                    })
+                })
+            {
+                continue;
+            }
+            let Some(first) = combination.first() else {
+                continue;
+            };
+            runnables.push(Runnable {
+                name: format!("{}[{}]", parent.name, id),
+                filepath: parent.filepath.clone(),
+                range: Range {
+                    start: CursorPosition::from_point(first.node.start_position()),
+                    end: CursorPosition::from_point(first.node.end_position()),
+                },
+                meta: RunnableMeta::default_python(),
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    fn parametrize<'a>(call: Node<'a>, content: &str) -> Option<Vec<Param<'a>>> {
+        let arguments = call.child_by_field_name("arguments")?;
+        let mut walker = arguments.walk();
+        let mut positional = vec![];
+        let mut keywords = HashMap::new();
+        for argument in arguments.named_children(&mut walker) {
+            match argument.kind() {
+                "comment" => {}
+                "keyword_argument" => {
+                    let name = node_text(argument.child_by_field_name("name")?, content);
+                    keywords.insert(name, argument.child_by_field_name("value")?);
+                }
+                _ => positional.push(argument),
+            }
+        }
+
+        let argnames = positional
+            .first()
+            .copied()
+            .or_else(|| keywords.get("argnames").copied())?;
+        let argnames: Vec<String> = match argnames.kind() {
+            "string" => string_value(argnames, content)?
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            "list" | "tuple" => elements(argnames)
+                .into_iter()
+                .map(|n| string_value(n, content))
+                .collect::<Option<Vec<String>>>()?,
+            _ => return None,
+        };
+
+        let argvalues = positional
+            .get(1)
+            .copied()
+            .or_else(|| keywords.get("argvalues").copied())
+            .filter(|n| n.kind() == "list" || n.kind() == "tuple")?;
+        let explicit_ids: Vec<Option<String>> = keywords
+            .get("ids")
+            .filter(|n| n.kind() == "list" || n.kind() == "tuple")
+            .map(|n| {
+                elements(*n)
+                    .into_iter()
+                    .map(|e| string_value(e, content))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut params = vec![];
+        for (index, value) in elements(argvalues).into_iter().enumerate() {
+            let id = explicit_ids
+                .get(index)
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| param_id(value, content, &argnames, index));
+            params.push(Param { id, node: value });
+        }
+        Some(params)
+    }
+
+    fn param_id(value: Node, content: &str, argnames: &[String], index: usize) -> String {
+        let mut values = vec![value];
+        if value.kind() == "call" {
+            let function = value
+                .child_by_field_name("function")
+                .map(|f| node_text(f, content))
+                .unwrap_or_default();
+            if function.ends_with("param")
+                && let Some(arguments) = value.child_by_field_name("arguments")
+            {
+                let mut walker = arguments.walk();
+                let mut positional = vec![];
+                for argument in arguments.named_children(&mut walker) {
+                    if argument.kind() != "keyword_argument" {
+                        positional.push(argument);
+                        continue;
+                    }
+                    let is_id = argument
+                        .child_by_field_name("name")
+                        .is_some_and(|n| node_text(n, content) == "id");
+                    if let Some(id) = argument
+                        .child_by_field_name("value")
+                        .filter(|_| is_id)
+                        .and_then(|v| string_value(v, content))
+                    {
+                        return id;
+                    }
+                }
+                values = positional;
+            }
+        } else if argnames.len() > 1 && (value.kind() == "tuple" || value.kind() == "list") {
+            values = elements(value);
+        }
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(position, v)| {
+                let argname = argnames.get(position).map(|a| a.as_str()).unwrap_or("arg");
+                value_id(v, content).unwrap_or_else(|| format!("{}{}", argname, index))
+            })
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+
+    fn value_id(value: Node, content: &str) -> Option<String> {
+        match value.kind() {
+            "string" => string_value(value, content),
+            "integer" | "float" => Some(node_text(value, content)),
+            "unary_operator" => value
+                .child_by_field_name("argument")
+                .filter(|a| a.kind() == "integer" || a.kind() == "float")
+                .map(|_| node_text(value, content).replace(char::is_whitespace, "")),
+            "true" => Some("True".to_string()),
+            "false" => Some("False".to_string()),
+            "none" => Some("None".to_string()),
+            _ => None,
+        }
+    }
+
+    // string_value
+    //
+    // Content of a plain string literal. f-strings and other interpolated
+    // strings cannot be resolved statically.
+    fn string_value(node: Node, content: &str) -> Option<String> {
+        if node.kind() != "string" {
+            return None;
+        }
+        let mut walker = node.walk();
+        let mut res = String::new();
+        for child in node.named_children(&mut walker) {
+            match child.kind() {
+                "string_content" | "escape_sequence" => res.push_str(&node_text(child, content)),
+                "interpolation" => return None,
+                _ => {}
+            }
+        }
+        Some(res)
+    }
+
+    fn elements(node: Node) -> Vec<Node> {
+        let mut walker = node.walk();
+        node.named_children(&mut walker)
+            .filter(|n| n.kind() != "comment")
+            .collect()
+    }
+
+    // unique
+    //
+    // pytest suffixes ids that occur more than once with their occurrence
+    // index, e.g. `a0` and `a1`.
+    fn unique(ids: Vec<String>) -> Vec<String> {
+        let mut totals: HashMap<String, usize> = HashMap::new();
+        for id in ids.iter() {
+            *totals.entry(id.clone()).or_default() += 1;
+        }
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        ids.into_iter()
+            .map(|id| {
+                if totals.get(&id).copied().unwrap_or_default() < 2 {
+                    return id;
+                }
+                let occurrence = seen.entry(id.clone()).or_default();
+                let res = format!("{}{}", id, occurrence);
+                *occurrence += 1;
+                res
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::python::operations::{parse_tree, pytest_get_file_tests};
+
+    fn expand(content: &str, cursor_position: Option<CursorPosition>) -> Vec<String> {
+        let tree = parse_tree::op::execute(content).unwrap();
+        let buffer = Buffer::new(
+            content,
+            "test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let parent = pytest_get_file_tests::op::execute(tree.root_node(), &target)
+            .unwrap()
+            .remove(0);
+        op::execute(tree.root_node(), content, &parent, cursor_position)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r| r.name)
+            .collect()
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        r#"
+@pytest.mark.parametrize("role", ["admin", "user"])
+def test_create(role):
+    pass
+"#,
+        vec!["test_create[admin]", "test_create[user]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize("a, b", [(1, 2), (-3, 4.5), (True, None), ({}, [])])
+def test_create(a, b):
+    pass
+"#,
+        vec!["test_create[1-2]", "test_create[-3-4.5]", "test_create[True-None]", "test_create[a3-b3]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize(
+    ("role", "active"),
+    [
+        pytest.param("admin", True, id="administrator"),
+        pytest.param("user", False),
+    ],
+)
+def test_create(role, active):
+    pass
+"#,
+        vec!["test_create[administrator]", "test_create[user-False]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize("role", ["admin", "user"], ids=["a", None])
+def test_create(role):
+    pass
+"#,
+        vec!["test_create[a]", "test_create[user]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize("x", [0, 1])
+@pytest.mark.parametrize("y", [2, 3])
+def test_create(x, y):
+    pass
+"#,
+        vec!["test_create[2-0]", "test_create[2-1]", "test_create[3-0]", "test_create[3-1]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize("role", ["admin", "admin"])
+def test_create(role):
+    pass
+"#,
+        vec!["test_create[admin0]", "test_create[admin1]"]
+    )]
+    #[case(
+        r#"
+@pytest.mark.parametrize("role", ROLES)
+def test_create(role):
+    pass
+"#,
+        vec![]
+    )]
+    fn parametrize_ids(#[case] content: &str, #[case] expected: Vec<&str>) {
+        assert_that!(
+            expand(content, None),
+            container_eq(
+                expected
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+            )
+        );
+    }
+
+    #[gtest]
+    fn parametrize_id_under_cursor() {
+        let content = r#"
+@pytest.mark.parametrize(
+    "role",
+    [
+        "admin",
+        "user",
+    ],
+)
+def test_create(role):
+    pass
+"#;
+
+        assert_that!(
+            expand(content, Some(CursorPosition::new(5, 8))),
+            elements_are![eq("test_create[user]")]
+        );
+        assert_that!(expand(content, Some(CursorPosition::new(9, 4))), is_empty());
+    }
+}
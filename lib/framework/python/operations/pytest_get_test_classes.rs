@@ -0,0 +1,76 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::python::{operations::pytest_get_file_tests, treesitter::pytest_test_class},
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns the `Test*` classes pytest collects from the file, named by
+    // their class path, e.g. `TestUsers::TestAdmins`.
+    pub fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = pytest_test_class::query();
+        let query =
+            Query::new(&Language::new(tree_sitter_python::LANGUAGE), &query_pattern).ok()?;
+        let class_name_index = query.capture_index_for_name("test_class_name")?;
+        let class_index = query.capture_index_for_name("testclass")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let class_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == class_index)
+                .map(|c| c.node);
+            let name_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == class_name_index)
+                .map(|c| c.node);
+
+            let (Some(class_node), Some(name_node)) = (class_node, name_node) else {
+                continue;
+            };
+
+            // the class itself must be collectable too, so it is checked
+            // from its own body
+            let Some(body) = class_node.child_by_field_name("body") else {
+                continue;
+            };
+            let Some(path) = pytest_get_file_tests::op::class_path(body, content) else {
+                continue;
+            };
+            if path.last() != Some(&node::node_text(name_node, content)) {
+                continue;
+            }
+
+            let definition = pytest_get_file_tests::op::definition(class_node);
+            runnables.push(Runnable {
+                name: path.join("::"),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(definition.start_position()),
+                    end: CursorPosition::from_point(definition.end_position()),
+                },
+                meta: RunnableMeta::default_python(),
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
@@ -0,0 +1,120 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::Node;
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::python::operations::unittest_get_test_classes,
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns the `test*` methods of every test case in the file, named
+    // `Class.test_method` as the dotted ids `python -m unittest` accepts.
+    //
+    // `self.subTest(...)` blocks are not separate runnables: unittest cannot
+    // select a single subtest, so they resolve to their enclosing method.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let classes = unittest_get_test_classes::op::execute(root, target)?;
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for (class, class_node) in classes.iter() {
+            let Some(body) = class_node.child_by_field_name("body") else {
+                continue;
+            };
+            let mut walker = body.walk();
+            for child in body.named_children(&mut walker) {
+                let function = match child.kind() {
+                    "decorated_definition" => child.child_by_field_name("definition"),
+                    "function_definition" => Some(child),
+                    _ => None,
+                };
+                let Some(name) = function
+                    .and_then(|f| f.child_by_field_name("name"))
+                    .map(|n| node::node_text(n, content))
+                else {
+                    continue;
+                };
+                if !name.starts_with("test") {
+                    continue;
+                }
+                runnables.push(Runnable {
+                    name: format!("{}.{}", class.name, name),
+                    filepath: target.buffer.filepath.to_string(),
+                    range: Range {
+                        start: CursorPosition::from_point(child.start_position()),
+                        end: CursorPosition::from_point(child.end_position()),
+                    },
+                    meta: RunnableMeta::default_python(),
+                });
+            }
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::python::operations::parse_tree;
+
+    #[gtest]
+    fn finds_test_methods() {
+        let content = r#"
+import unittest
+
+
+class TestUsers(unittest.TestCase):
+    def setUp(self):
+        self.users = []
+
+    def test_create(self):
+        self.assertEqual(self.users, [])
+
+    @unittest.skip("flaky")
+    def test_delete(self):
+        pass
+
+    def helper_test(self):
+        pass
+
+
+def test_module_level():
+    pass
+"#;
+        let buffer = Buffer::new(
+            content,
+            "tests/test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "TestUsers.test_create".to_string(),
+                "TestUsers.test_delete".to_string(),
+            ])
+        );
+        assert_that!(res[1].range.start.row, eq(11));
+    }
+}
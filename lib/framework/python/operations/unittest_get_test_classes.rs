@@ -0,0 +1,194 @@
+pub(crate) mod op {
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::python::{operations::pytest_get_file_tests, treesitter::unittest_test_class},
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns the module level `unittest.TestCase` subclasses of the file,
+    // paired with their class node. A class is a test case when one of its
+    // bases ends in `TestCase` (`unittest.TestCase`, `IsolatedAsyncioTestCase`,
+    // `django.test.TestCase`, ...) or is itself a test case of the same file.
+    pub(crate) fn execute<'a>(
+        root: Node<'a>,
+        target: &Target,
+    ) -> Option<Vec<(Runnable, Node<'a>)>> {
+        let content = target.buffer.content;
+        let query_pattern = unittest_test_class::query();
+        let query =
+            Query::new(&Language::new(tree_sitter_python::LANGUAGE), &query_pattern).ok()?;
+        let class_name_index = query.capture_index_for_name("test_class_name")?;
+        let class_bases_index = query.capture_index_for_name("test_class_bases")?;
+        let class_index = query.capture_index_for_name("testclass")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut candidates: Vec<(String, Vec<String>, Node)> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(class_node), Some(name_node), Some(bases_node)) = (
+                capture(class_index),
+                capture(class_name_index),
+                capture(class_bases_index),
+            ) else {
+                continue;
+            };
+
+            // the loader only sees module attributes
+            if pytest_get_file_tests::op::definition(class_node)
+                .parent()
+                .is_none_or(|p| p.kind() != "module")
+            {
+                continue;
+            }
+
+            let mut walker = bases_node.walk();
+            let bases = bases_node
+                .named_children(&mut walker)
+                .filter(|b| matches!(b.kind(), "identifier" | "attribute"))
+                .map(|b| node::node_text(b, content))
+                .collect();
+            candidates.push((node::node_text(name_node, content), bases, class_node));
+        }
+
+        let mut test_cases: HashMap<String, bool> = HashMap::new();
+        loop {
+            let mut changed = false;
+            for (name, bases, _) in candidates.iter() {
+                if test_cases.contains_key(name) {
+                    continue;
+                }
+                let is_test_case = bases.iter().any(|base| {
+                    base.rsplit('.')
+                        .next()
+                        .is_some_and(|b| b.ends_with("TestCase"))
+                        || test_cases.contains_key(base)
+                });
+                if is_test_case {
+                    test_cases.insert(name.to_string(), true);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let runnables: Vec<(Runnable, Node)> = candidates
+            .into_iter()
+            .filter(|(name, _, _)| test_cases.contains_key(name))
+            .map(|(name, _, class_node)| {
+                let definition = pytest_get_file_tests::op::definition(class_node);
+                let runnable = Runnable {
+                    name,
+                    filepath: target.buffer.filepath.to_string(),
+                    range: Range {
+                        start: CursorPosition::from_point(definition.start_position()),
+                        end: CursorPosition::from_point(definition.end_position()),
+                    },
+                    meta: RunnableMeta::default_python(),
+                };
+                (runnable, class_node)
+            })
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::python::operations::parse_tree;
+
+    #[gtest]
+    fn finds_test_cases() {
+        let content = r#"
+import unittest
+from django import test
+
+
+class Mixin(object):
+    pass
+
+
+class BaseCase(unittest.TestCase):
+    pass
+
+
+class TestUsers(Mixin, BaseCase):
+    pass
+
+
+class TestViews(test.TestCase):
+    pass
+
+
+class TestAsync(unittest.IsolatedAsyncioTestCase):
+    pass
+
+
+def factory():
+    class TestLocal(unittest.TestCase):
+        pass
+"#;
+        let buffer = Buffer::new(
+            content,
+            "tests/test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.into_iter().map(|(r, _)| r.name).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "BaseCase".to_string(),
+                "TestUsers".to_string(),
+                "TestViews".to_string(),
+                "TestAsync".to_string(),
+            ])
+        );
+    }
+
+    #[gtest]
+    fn none_without_test_cases() {
+        let content = "import unittest\n\n\nclass Helper(object):\n    pass\n";
+        let buffer = Buffer::new(
+            content,
+            "tests/test_api.py".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        assert_that!(op::execute(tree.root_node(), &target), none());
+    }
+}
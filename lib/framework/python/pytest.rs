@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::python::operations::detect_unittest_file;
+use crate::framework::python::operations::get_python_project;
+use crate::framework::python::operations::parse_tree;
+use crate::framework::python::operations::pytest_get_file_tests;
+use crate::framework::python::operations::pytest_get_parametrize_ids;
+use crate::framework::python::operations::pytest_get_test_classes;
+
+pub struct PytestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+impl PytestProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    // is_test_file
+    //
+    // Matches pytest's default `python_files = test_*.py *_test.py`.
+    fn is_test_file(filepath: &str) -> bool {
+        let name = Path::new(filepath)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        name.ends_with(".py") && (name.starts_with("test_") || name.ends_with("_test.py"))
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the test under the cursor.
+         *       If the test is parametrized, check if a parameter set contains the cursor position.
+         *       Outside of a test, fall back to the innermost test class holding the cursor
+         *   - Search set to method, return the test under the cursor
+         *   - Search set to file, return all tests in the file, expanding parameter ids
+         * */
+        let root = tree.root_node();
+        let content = target.buffer.content;
+        let tests = pytest_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError("Pytest test not found no tests in this file".to_string())
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .filter(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .max_by_key(|t| t.range.start.row)
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => {
+                let mut res: Vec<Runnable> = vec![];
+                for test in tests.into_iter() {
+                    match pytest_get_parametrize_ids::op::execute(root, content, &test, None) {
+                        Some(ids) => res.extend(ids),
+                        None => res.push(test),
+                    }
+                }
+                Ok(res)
+            }
+            Search::Method => under_cursor.map(|t| vec![t]).ok_or_else(|| {
+                FrameworkError::NotFoundError("Pytest test not found at position".to_string())
+            }),
+            Search::Nearest => {
+                if let Some(test) = under_cursor {
+                    if let Some(ids) = pytest_get_parametrize_ids::op::execute(
+                        root,
+                        content,
+                        &test,
+                        Some(position),
+                    ) {
+                        return Ok(ids);
+                    }
+                    return Ok(vec![test]);
+                }
+                pytest_get_test_classes::op::execute(root, target)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|c| position.in_range(c.range.start.to_point()..c.range.end.to_point()))
+                    .max_by_key(|c| c.range.start.row)
+                    .map(|c| vec![c])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError(
+                            "Pytest test not found at position".to_string(),
+                        )
+                    })
+            }
+        }
+    }
+
+    // command
+    //
+    // pytest running the node ids of the runnables, from the root of the
+    // project of the first one.
+    fn command(&self, runnables: Vec<Runnable>) -> Command {
+        let Some(runnable) = runnables.first() else {
+            return Command::default();
+        };
+        let root = runnable
+            .meta
+            .get_python_meta()
+            .map(|m| PathBuf::from(m.root))
+            .unwrap_or_else(|| get_python_project::op::execute(&runnable.filepath).root);
+        let mut interpreter = get_python_project::op::interpreter(&root).into_iter();
+        // node ids are relative to the project root
+        let mut cmd = Command {
+            command: interpreter.next().unwrap_or("python3".to_string()),
+            args: interpreter.collect(),
+            cwd: Some(root),
+            ..Default::default()
+        };
+
+        cmd.args.push("-m".to_string());
+        cmd.args.push("pytest".to_string());
+        cmd.args.extend(runnables.into_iter().map(|r| r.name));
+        cmd
+    }
+}
+
+impl FrameworkProvider for PytestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(PytestProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "Pytest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Python
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for PytestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !Self::is_test_file(&target.buffer.filepath) {
+            return Detection::rejected("file name is not `test_*.py` or `*_test.py`");
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if detect_unittest_file::op::execute(tree.root_node(), target) {
+            return Detection::rejected("file defines `unittest.TestCase` classes");
+        }
+        if pytest_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `test_` functions");
+        }
+
+        Detection::matched(80, "test file with `test_` functions")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_python_project::op::execute(&target.buffer.filepath);
+        let relative_path = project.relative_path(&target.buffer.filepath);
+        for runnable in res.iter_mut() {
+            runnable.name = format!("{}::{}", relative_path, runnable.name);
+            runnable
+                .meta
+                .set_root(project.root.to_string_lossy().to_string());
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.command(vec![runnable])
+    }
+
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        Ok(self.command(runnables))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::python::pytest,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+import pytest
+
+
+def create_user(role):
+    return {"role": role}
+
+
+class TestUsers:
+    @pytest.mark.parametrize(
+        "role",
+        [
+            "admin",
+            "user",
+        ],
+    )
+    def test_create(self, role):
+        assert create_user(role)["role"] == role
+
+    def test_delete(self):
+        assert True
+
+
+def test_health():
+    assert True
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/python/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["tests/test_api.py::TestUsers::test_create[admin]", "tests/test_api.py::TestUsers::test_create[user]", "tests/test_api.py::TestUsers::test_delete", "tests/test_api.py::test_health"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(13, 8), vec!["tests/test_api.py::TestUsers::test_create"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(13, 8), vec!["tests/test_api.py::TestUsers::test_create[user]"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(17, 8), vec!["tests/test_api.py::TestUsers::test_create"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(20, 8), vec!["tests/test_api.py::TestUsers::test_delete"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(18, 4), vec!["tests/test_api.py::TestUsers"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(24, 4), vec!["tests/test_api.py::test_health"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture("uv_project/tests/test_api.py"), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = pytest::PytestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("uv_project/tests/test_api.py"),
+            types::CursorPosition::new(5, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Nearest);
+        let provider = pytest::PytestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "uv_project/tests/test_api.py",
+        "uv run python -m pytest tests/test_api.py::TestUsers::test_create[user]"
+    )]
+    #[case(
+        "poetry_project/tests/test_api.py",
+        "poetry run python -m pytest tests/test_api.py::TestUsers::test_create[user]"
+    )]
+    fn generate_command(#[case] path: &str, #[case] expected: &str) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), types::CursorPosition::new(13, 8));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = pytest::PytestProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let actual = format!("{} {}", cmd.command, cmd.args.join(" "));
+        assert_that!(actual, eq(expected));
+        let root = path.split('/').next().unwrap();
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "tests/test_api.py", CONTENT, true)]
+    #[case(enums::Capability::TestRunner, "tests/api_test.py", CONTENT, true)]
+    #[case(enums::Capability::Debugger, "tests/test_api.py", CONTENT, false)]
+    #[case(enums::Capability::TestRunner, "tests/api.py", CONTENT, false)]
+    #[case(
+        enums::Capability::TestRunner,
+        "tests/test_api.py",
+        "import os\n",
+        false
+    )]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = pytest::PytestProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::core::metadata::RunnableMeta;
+
+impl RunnableMeta {
+    pub(in crate::framework::python) fn set_root(&mut self, value: String) {
+        if let RunnableMeta::Python { root } = self {
+            *root = value;
+        }
+    }
+
+    pub(in crate::framework::python) fn get_python_meta(&self) -> Option<Meta> {
+        match self {
+            RunnableMeta::Python { root } => Some(Meta { root: root.clone() }),
+            _ => None,
+        }
+    }
+}
+
+pub(in crate::framework::python) struct Meta {
+    pub(in crate::framework::python) root: String,
+}
@@ -0,0 +1,4 @@
+pub(crate) mod pytest_test_class;
+pub(crate) mod pytest_test_function;
+pub(crate) mod python_import;
+pub(crate) mod unittest_test_class;
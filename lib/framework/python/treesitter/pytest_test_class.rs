@@ -0,0 +1,22 @@
+// query
+//
+// Finds classes following pytest's default `python_classes = Test*`
+// naming.
+//
+// Example:
+// class TestUsers:
+//     def test_create(self):
+//         assert create_user("admin")
+//
+// This will find the following classes:
+// - TestUsers
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((class_definition
+                    name: (identifier) @test_class_name
+                    body: (block)) @testclass
+                  (#match? @test_class_name "^Test"))]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,28 @@
+// query
+//
+// Finds functions following pytest's default `python_functions = test*`
+// naming. Whether the function is collected also depends on where it is
+// defined (module level or inside a `Test*` class), which is checked after
+// the match.
+//
+// Example:
+// class TestUsers:
+//     def test_create(self):
+//         assert create_user("admin")
+//
+// def test_delete():
+//     assert delete_user("admin")
+//
+// This will find the following functions:
+// - test_create
+// - test_delete
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((function_definition
+                    name: (identifier) @test_name
+                    body: (block)) @testfunc
+                  (#match? @test_name "^test"))]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,27 @@
+// query
+//
+// Finds the modules imported by a python file, through both
+// `import unittest` and `from unittest import TestCase`.
+//
+// Example:
+// import os.path
+// from unittest import mock
+//
+// This will find the following modules:
+// - os.path
+// - unittest
+pub(crate) fn query() -> String {
+    let res = r#"
+            [
+              (import_statement
+                name: [
+                  (dotted_name) @import.module
+                  (aliased_import name: (dotted_name) @import.module)
+                ])
+              (import_from_statement
+                module_name: (dotted_name) @import.module)
+            ]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,23 @@
+// query
+//
+// Finds classes with base classes, the candidates for `unittest.TestCase`
+// subclasses. Whether a base is a TestCase is resolved after the match since
+// it may be another class of the same file.
+//
+// Example:
+// class TestUsers(unittest.TestCase):
+//     def test_create(self):
+//         self.assertTrue(create_user("admin"))
+//
+// This will find the following classes:
+// - TestUsers
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((class_definition
+                    name: (identifier) @test_class_name
+                    superclasses: (argument_list) @test_class_bases
+                    body: (block)) @testclass)]]
+            "#;
+
+    res.to_string()
+}
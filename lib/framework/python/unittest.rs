@@ -0,0 +1,213 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::python::operations::detect_unittest_file;
+use crate::framework::python::operations::get_python_project;
+use crate::framework::python::operations::parse_tree;
+use crate::framework::python::operations::unittest_get_file_tests;
+use crate::framework::python::operations::unittest_get_test_classes;
+
+// UnittestProvider
+//
+// Runs `unittest.TestCase` suites through `python -m unittest` with dotted
+// ids such as `tests.test_api.TestUsers.test_create`. Files mixing in pytest
+// are left to `PytestProvider`, which collects test cases as well.
+pub struct UnittestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+impl UnittestProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the test method under the cursor.
+         *       A cursor inside a `self.subTest` block runs the enclosing method.
+         *       Outside of a method, fall back to the test case holding the cursor
+         *   - Search set to method, return the test method under the cursor
+         *   - Search set to file, return all test methods in the file
+         * */
+        let root = tree.root_node();
+        let tests = unittest_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError(
+                "Unittest test not found no tests in this file".to_string(),
+            )
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .find(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => Ok(tests),
+            Search::Method => under_cursor.map(|t| vec![t]).ok_or_else(|| {
+                FrameworkError::NotFoundError("Unittest test not found at position".to_string())
+            }),
+            Search::Nearest => {
+                if let Some(test) = under_cursor {
+                    return Ok(vec![test]);
+                }
+                unittest_get_test_classes::op::execute(root, target)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(c, _)| c)
+                    .find(|c| position.in_range(c.range.start.to_point()..c.range.end.to_point()))
+                    .map(|c| vec![c])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError(
+                            "Unittest test not found at position".to_string(),
+                        )
+                    })
+            }
+        }
+    }
+
+    // command
+    //
+    // unittest running the dotted names of the runnables, from the root of
+    // the project of the first one.
+    fn command(&self, runnables: Vec<Runnable>) -> Command {
+        let Some(runnable) = runnables.first() else {
+            return Command::default();
+        };
+        let root = runnable
+            .meta
+            .get_python_meta()
+            .map(|m| PathBuf::from(m.root))
+            .unwrap_or_else(|| get_python_project::op::execute(&runnable.filepath).root);
+        let mut interpreter = get_python_project::op::interpreter(&root).into_iter();
+        // dotted module names are relative to the project root
+        let mut cmd = Command {
+            command: interpreter.next().unwrap_or("python3".to_string()),
+            args: interpreter.collect(),
+            cwd: Some(root),
+            ..Default::default()
+        };
+
+        cmd.args.push("-m".to_string());
+        cmd.args.push("unittest".to_string());
+        cmd.args.extend(runnables.into_iter().map(|r| r.name));
+        cmd
+    }
+}
+
+impl FrameworkProvider for UnittestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(UnittestProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "Unittest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Python
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for UnittestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(".py") {
+            return Detection::rejected("file name does not end with `.py`");
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if !detect_unittest_file::op::execute(tree.root_node(), target) {
+            return Detection::rejected("file defines no `unittest.TestCase` classes");
+        }
+
+        Detection::matched(90, "file defining `unittest.TestCase` classes")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_python_project::op::execute(&target.buffer.filepath);
+        let module_name = project.module_name(&target.buffer.filepath);
+        for runnable in res.iter_mut() {
+            runnable.name = format!("{}.{}", module_name, runnable.name);
+            runnable
+                .meta
+                .set_root(project.root.to_string_lossy().to_string());
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.command(vec![runnable])
+    }
+
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        Ok(self.command(runnables))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
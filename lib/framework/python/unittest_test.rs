@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::python::{pytest, unittest},
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+import unittest
+
+
+def create_user(role):
+    return {"role": role}
+
+
+class TestUsers(unittest.TestCase):
+    def setUp(self):
+        self.roles = ["admin", "user"]
+
+    def test_create(self):
+        for role in self.roles:
+            with self.subTest(role=role):
+                self.assertEqual(create_user(role)["role"], role)
+
+    def test_delete(self):
+        self.assertTrue(True)
+
+
+if __name__ == "__main__":
+    unittest.main()
+"#;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/python/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(1, 0), vec!["tests.test_api.TestUsers.test_create", "tests.test_api.TestUsers.test_delete"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(15, 16), vec!["tests.test_api.TestUsers.test_create"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(15, 16), vec!["tests.test_api.TestUsers.test_create"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(18, 8), vec!["tests.test_api.TestUsers.test_delete"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(10, 8), vec!["tests.test_api.TestUsers"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture("uv_project/tests/test_api.py"), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = unittest::UnittestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("uv_project/tests/test_api.py"),
+            types::CursorPosition::new(5, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Nearest);
+        let provider = unittest::UnittestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "uv_project/tests/test_api.py",
+        "uv run python -m unittest tests.test_api.TestUsers.test_create"
+    )]
+    #[case(
+        "poetry_project/tests/test_api.py",
+        "poetry run python -m unittest tests.test_api.TestUsers.test_create"
+    )]
+    fn generate_command(#[case] path: &str, #[case] expected: &str) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), types::CursorPosition::new(15, 16));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = unittest::UnittestProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let actual = format!("{} {}", cmd.command, cmd.args.join(" "));
+        assert_that!(actual, eq(expected));
+        let root = path.split('/').next().unwrap();
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("tests/test_api.py", CONTENT, true, false)]
+    #[case(
+        "tests/test_api.py",
+        "import pytest\nimport unittest\n\n\nclass TestUsers(unittest.TestCase):\n    def test_create(self):\n        pass\n",
+        false,
+        true
+    )]
+    #[case(
+        "tests/test_api.py",
+        "def test_health():\n    assert True\n",
+        false,
+        true
+    )]
+    #[case("tests/api.py", "import os\n", false, false)]
+    fn detect_decides_between_pytest_and_unittest(
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected_unittest: bool,
+        #[case] expected_pytest: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+
+        assert_that!(
+            unittest::UnittestProvider::new().detect(&target),
+            eq(expected_unittest)
+        );
+        assert_that!(
+            pytest::PytestProvider::new().detect(&target),
+            eq(expected_pytest)
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod operations;
+pub mod rspec;
+#[cfg(test)]
+mod rspec_test;
+pub(crate) mod runnable_meta;
+mod treesitter;
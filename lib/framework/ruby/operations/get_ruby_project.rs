@@ -0,0 +1,85 @@
+pub(crate) mod op {
+    use std::path::{Path, PathBuf};
+
+    use crate::core::paths::{absolute, find_ancestor};
+
+    // RubyProject
+    //
+    // The directory specs run from and whether rspec runs through bundler.
+    pub(crate) struct RubyProject {
+        pub(crate) root: PathBuf,
+        pub(crate) bundler: bool,
+    }
+
+    impl RubyProject {
+        // relative_path
+        //
+        // Path of a spec relative to the project root, as passed to rspec.
+        pub(crate) fn relative_path(&self, filepath: &str) -> String {
+            let file = absolute(Path::new(filepath));
+            let relative = file.strip_prefix(&self.root).unwrap_or(&file);
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        }
+    }
+
+    // execute
+    //
+    // Resolves the closest directory holding a `Gemfile`, which runs rspec
+    // through `bundle exec`. Without one, the closest `.rspec` configuration
+    // or the parent of the `spec` directory is the root.
+    pub(crate) fn execute(filepath: &str) -> RubyProject {
+        let file = absolute(Path::new(filepath));
+        let directory = file.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        if let Some(root) = find_ancestor(&directory, |dir| dir.join("Gemfile").is_file()) {
+            return RubyProject {
+                root,
+                bundler: true,
+            };
+        }
+
+        let root = find_ancestor(&directory, |dir| dir.join(".rspec").is_file())
+            .or_else(|| {
+                directory
+                    .ancestors()
+                    .find(|dir| dir.file_name().is_some_and(|n| n == "spec"))
+                    .and_then(|spec| spec.parent())
+                    .map(|dir| dir.to_path_buf())
+            })
+            .unwrap_or(directory);
+        RubyProject {
+            root,
+            bundler: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/ruby/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "bundler_project/spec/models/calculator_spec.rb",
+        "bundler_project",
+        true
+    )]
+    #[case("plain_project/spec/calculator_spec.rb", "plain_project", false)]
+    fn resolves_root_and_bundler(#[case] path: &str, #[case] root: &str, #[case] bundler: bool) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res.root.ends_with(root), eq(true));
+        assert_that!(res.bundler, eq(bundler));
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod get_ruby_project;
+pub(crate) mod parse_tree;
+pub(crate) mod rspec_get_example_groups;
+pub(crate) mod rspec_get_file_tests;
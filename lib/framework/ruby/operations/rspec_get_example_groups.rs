@@ -0,0 +1,72 @@
+pub(crate) mod op {
+    use tree_sitter::Node;
+
+    use crate::{
+        core::types::{Runnable, Target},
+        framework::ruby::operations::rspec_get_file_tests::{self, op::Kind},
+    };
+
+    // execute
+    //
+    // Returns the runnable `describe`/`context` groups of the spec, each
+    // selected by its line like examples are.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> =
+            rspec_get_file_tests::op::blocks(root, target.buffer.content)?
+                .iter()
+                .filter(|b| b.runnable && b.kind == Kind::Group)
+                .map(|b| rspec_get_file_tests::op::runnable(b, target))
+                .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::ruby::operations::parse_tree;
+
+    #[gtest]
+    fn finds_example_groups() {
+        let content = r##"
+describe Calculator do
+  context "with negatives" do
+    it "sums" do
+    end
+  end
+
+  shared_context "logged in" do
+    context "as admin" do
+    end
+  end
+end
+"##;
+        let buffer = Buffer::new(
+            content,
+            "spec/calculator_spec.rb".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "Calculator".to_string(),
+                "Calculator with negatives".to_string(),
+            ])
+        );
+    }
+}
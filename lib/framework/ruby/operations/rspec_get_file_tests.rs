@@ -0,0 +1,317 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::ruby::treesitter::rspec_block,
+        treesitter::node,
+    };
+
+    const GROUPS: [&str; 8] = [
+        "describe",
+        "context",
+        "feature",
+        "example_group",
+        "xdescribe",
+        "xcontext",
+        "fdescribe",
+        "fcontext",
+    ];
+    const SHARED: [&str; 3] = ["shared_examples", "shared_examples_for", "shared_context"];
+    const INCLUDES: [&str; 4] = [
+        "it_behaves_like",
+        "it_should_behave_like",
+        "include_examples",
+        "include_context",
+    ];
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub(crate) enum Kind {
+        Group,
+        Example,
+        Shared,
+        Include,
+    }
+
+    // Block
+    //
+    // An rspec dsl call with its full description, the descriptions of the
+    // enclosing groups and its own joined as rspec does. Blocks defined in
+    // `shared_examples`, and the includes pulling them in, are discovered
+    // but not runnable by themselves.
+    pub(crate) struct Block<'a> {
+        pub(crate) name: String,
+        pub(crate) kind: Kind,
+        pub(crate) node: Node<'a>,
+        pub(crate) runnable: bool,
+    }
+
+    // execute
+    //
+    // Returns every runnable example of the spec, each selected by its line.
+    pub(crate) fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let runnables: Vec<Runnable> = blocks(root, target.buffer.content)?
+            .iter()
+            .filter(|b| b.runnable && b.kind == Kind::Example)
+            .map(|b| runnable(b, target))
+            .collect();
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // runnable
+    //
+    // Converts a block to a runnable, rspec selects it by its first line.
+    pub(crate) fn runnable(block: &Block, target: &Target) -> Runnable {
+        let mut meta = RunnableMeta::default_ruby();
+        meta.set_line(Some(block.node.start_position().row + 1));
+        Runnable {
+            name: block.name.to_string(),
+            filepath: target.buffer.filepath.to_string(),
+            range: Range {
+                start: CursorPosition::from_point(block.node.start_position()),
+                end: CursorPosition::from_point(block.node.end_position()),
+            },
+            meta,
+        }
+    }
+
+    // blocks
+    //
+    // Returns the rspec dsl calls of the spec in source order.
+    pub(crate) fn blocks<'a>(root: Node<'a>, content: &str) -> Option<Vec<Block<'a>>> {
+        let query_pattern = rspec_block::query();
+        let query = Query::new(&Language::new(tree_sitter_ruby::LANGUAGE), &query_pattern).ok()?;
+        let method_index = query.capture_index_for_name("rspec.method")?;
+        let call_index = query.capture_index_for_name("rspec.call")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut calls: Vec<(Node, String, Kind)> = vec![];
+        let mut foreign: Vec<Node> = vec![];
+        for node_matched in query_matches {
+            let capture = |index: u32| {
+                node_matched
+                    .captures
+                    .iter()
+                    .find(|c| c.index == index)
+                    .map(|c| c.node)
+            };
+            let (Some(call), Some(method)) = (capture(call_index), capture(method_index)) else {
+                continue;
+            };
+            if call
+                .child_by_field_name("receiver")
+                .is_some_and(|r| node::node_text(r, content) != "RSpec")
+            {
+                foreign.push(call);
+                continue;
+            }
+
+            let method = node::node_text(method, content);
+            let kind = if GROUPS.contains(&method.as_str()) {
+                Kind::Group
+            } else if SHARED.contains(&method.as_str()) {
+                Kind::Shared
+            } else if INCLUDES.contains(&method.as_str()) {
+                Kind::Include
+            } else {
+                Kind::Example
+            };
+            // groups and examples need a body, `it "later"` is pending
+            if matches!(kind, Kind::Group | Kind::Example | Kind::Shared)
+                && call.child_by_field_name("block").is_none()
+            {
+                continue;
+            }
+            calls.push((call, description(call, content), kind));
+        }
+
+        let contains = |parent: &Node, child: &Node| {
+            parent.id() != child.id()
+                && parent.start_byte() <= child.start_byte()
+                && child.end_byte() <= parent.end_byte()
+        };
+        // nothing nested in another dsl, `Other.describe`, belongs to rspec
+        calls.retain(|(call, _, _)| !foreign.iter().any(|f| contains(f, call)));
+        let blocks: Vec<Block> = calls
+            .iter()
+            .map(|(call, description, kind)| {
+                let shared = *kind == Kind::Shared
+                    || calls
+                        .iter()
+                        .any(|(parent, _, k)| *k == Kind::Shared && contains(parent, call));
+                let name = calls
+                    .iter()
+                    .filter(|(parent, _, k)| *k == Kind::Group && contains(parent, call))
+                    .fold(String::new(), |acc, (_, parent, _)| join(&acc, parent));
+                Block {
+                    name: join(&name, description),
+                    kind: *kind,
+                    node: *call,
+                    runnable: !shared && *kind != Kind::Include,
+                }
+            })
+            .collect();
+
+        if blocks.is_empty() {
+            None
+        } else {
+            Some(blocks)
+        }
+    }
+
+    // description
+    //
+    // Description of a dsl call from its leading string, constant or symbol
+    // arguments, e.g. `describe Calculator, "#add"` reads `Calculator#add`.
+    fn description(call: Node, content: &str) -> String {
+        let Some(arguments) = call.child_by_field_name("arguments") else {
+            return String::new();
+        };
+        let mut walker = arguments.walk();
+        arguments
+            .named_children(&mut walker)
+            .map_while(|argument| match argument.kind() {
+                "string" => {
+                    let text = node::node_text(argument, content);
+                    let mut chars = text.chars();
+                    chars.next();
+                    chars.next_back();
+                    Some(chars.as_str().to_string())
+                }
+                "constant" | "scope_resolution" => Some(node::node_text(argument, content)),
+                "simple_symbol" => Some(
+                    node::node_text(argument, content)
+                        .trim_start_matches(':')
+                        .to_string(),
+                ),
+                _ => None,
+            })
+            .take(2)
+            .fold(String::new(), |acc, part| join(&acc, &part))
+    }
+
+    // join
+    //
+    // Appends a description the way rspec builds full descriptions: with a
+    // space, unless it starts with `#`, `.` or `::` as method descriptions do.
+    fn join(prefix: &str, description: &str) -> String {
+        if prefix.is_empty() {
+            return description.to_string();
+        }
+        if description.is_empty() {
+            return prefix.to_string();
+        }
+        if description.starts_with('#')
+            || description.starts_with('.')
+            || description.starts_with("::")
+        {
+            return format!("{}{}", prefix, description);
+        }
+        format!("{} {}", prefix, description)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::ruby::operations::parse_tree;
+
+    #[gtest]
+    fn finds_examples_with_full_descriptions() {
+        let content = r##"
+RSpec.describe Calculator, type: :model do
+  describe "#add" do
+    it("sums two numbers") { expect(subject.add(1, 1)).to eq(2) }
+
+    it "handles nil"
+
+    it_behaves_like "a number"
+  end
+
+  shared_examples "a number" do
+    it "is numeric" do
+      expect(subject).to be_a(Numeric)
+    end
+  end
+
+  context :negative do
+    specify { expect(subject.add(-1, -1)).to eq(-2) }
+  end
+end
+
+Other.describe "not rspec" do
+  it "is ignored" do
+  end
+end
+"##;
+        let buffer = Buffer::new(
+            content,
+            "spec/calculator_spec.rb".to_string(),
+            CursorPosition::new(0, 0),
+        );
+        let target = Target::new(Capability::TestRunner, buffer);
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::execute(tree.root_node(), &target).unwrap();
+
+        let names: Vec<String> = res.iter().map(|r| r.name.to_string()).collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "Calculator#add sums two numbers".to_string(),
+                "Calculator negative".to_string(),
+            ])
+        );
+        let lines: Vec<Option<usize>> = res
+            .iter()
+            .map(|r| r.meta.get_ruby_meta().unwrap().line)
+            .collect();
+        assert_that!(lines, container_eq(vec![Some(4), Some(18)]));
+    }
+
+    #[gtest]
+    fn marks_shared_examples_and_includes_as_not_runnable() {
+        let content = r##"
+RSpec.shared_examples "a number" do
+  it "is numeric" do
+  end
+end
+
+RSpec.describe Integer do
+  include_examples "a number"
+end
+"##;
+        let tree = parse_tree::op::execute(content).unwrap();
+
+        let res = op::blocks(tree.root_node(), content).unwrap();
+
+        let runnable: Vec<(String, bool)> = res
+            .iter()
+            .map(|b| (b.name.to_string(), b.runnable))
+            .collect();
+        assert_that!(
+            runnable,
+            container_eq(vec![
+                ("a number".to_string(), false),
+                ("is numeric".to_string(), false),
+                ("Integer".to_string(), true),
+                ("Integer a number".to_string(), false),
+            ])
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::PathBuf;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::RunnableMeta;
+use crate::core::types::Command;
+use crate::core::types::CursorPosition;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::ruby::operations::get_ruby_project;
+use crate::framework::ruby::operations::parse_tree;
+use crate::framework::ruby::operations::rspec_get_example_groups;
+use crate::framework::ruby::operations::rspec_get_file_tests;
+
+// RspecProvider
+//
+// Runs rspec examples and groups by line, `rspec spec/foo_spec.rb:12`,
+// through `bundle exec` when the project has a Gemfile.
+pub struct RspecProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static FILE_SUFFIX: &str = "_spec.rb";
+
+impl RspecProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the innermost example under the cursor.
+         *       Outside of an example, fall back to the innermost group holding the cursor
+         *   - Search set to method, return the example under the cursor
+         *   - Search set to file, return the whole spec file
+         *   - Shared examples only run through the groups including them
+         * */
+        let root = tree.root_node();
+        let content = target.buffer.content;
+        let blocks = rspec_get_file_tests::op::blocks(root, content).ok_or_else(|| {
+            FrameworkError::NotFoundError(
+                "RSpec example not found no examples in this file".to_string(),
+            )
+        })?;
+        let position = target.buffer.position;
+
+        if let Search::File = target.search_strategy {
+            let mut meta = RunnableMeta::default_ruby();
+            meta.set_line(None);
+            return Ok(vec![Runnable {
+                name: String::new(),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(root.start_position()),
+                    end: CursorPosition::from_point(root.end_position()),
+                },
+                meta,
+            }]);
+        }
+
+        let in_shared = blocks.iter().any(|b| {
+            b.kind == rspec_get_file_tests::op::Kind::Shared
+                && position.in_range(b.node.start_position()..b.node.end_position())
+        });
+        if in_shared {
+            return Err(FrameworkError::PreconditionError(
+                "RSpec shared examples are not runnable, run a group including them".to_string(),
+            ));
+        }
+
+        let example = rspec_get_file_tests::op::execute(root, target)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| position.in_range(e.range.start.to_point()..e.range.end.to_point()))
+            .max_by_key(|e| e.range.start.row);
+        if let Some(example) = example {
+            return Ok(vec![example]);
+        }
+        if let Search::Method = target.search_strategy {
+            return Err(FrameworkError::NotFoundError(
+                "RSpec example not found at position".to_string(),
+            ));
+        }
+
+        rspec_get_example_groups::op::execute(root, target)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|g| position.in_range(g.range.start.to_point()..g.range.end.to_point()))
+            .max_by_key(|g| g.range.start.row)
+            .map(|g| vec![g])
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError("RSpec example not found at position".to_string())
+            })
+    }
+}
+
+impl FrameworkProvider for RspecProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(RspecProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "RSpec"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Ruby
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for RspecProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if rspec_get_file_tests::op::blocks(tree.root_node(), target.buffer.content).is_none() {
+            return Detection::rejected("file has no `describe` or `it` blocks");
+        }
+
+        Detection::matched(
+            80,
+            format!("`{}` file with `describe` or `it` blocks", FILE_SUFFIX),
+        )
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let project = get_ruby_project::op::execute(&target.buffer.filepath);
+        for runnable in res.iter_mut() {
+            if runnable.name.is_empty() {
+                runnable.name = project.relative_path(&target.buffer.filepath);
+            }
+            runnable
+                .meta
+                .set_ruby_project(project.root.to_string_lossy().to_string(), project.bundler);
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        let (project, line) = runnable
+            .meta
+            .get_ruby_meta()
+            .map(|m| {
+                let project = get_ruby_project::op::RubyProject {
+                    root: PathBuf::from(m.root),
+                    bundler: m.bundler,
+                };
+                (project, m.line)
+            })
+            .unwrap_or_else(|| (get_ruby_project::op::execute(&runnable.filepath), None));
+        let mut cmd = if project.bundler {
+            Command {
+                command: "bundle".to_string(),
+                args: vec!["exec".to_string(), "rspec".to_string()],
+                ..Default::default()
+            }
+        } else {
+            Command {
+                command: "rspec".to_string(),
+                args: vec![],
+                ..Default::default()
+            }
+        };
+
+        // specs are passed relative to the project root
+        cmd.cwd = Some(project.root.clone());
+        let filepath = project.relative_path(&runnable.filepath);
+        match line {
+            Some(line) => cmd.args.push(format!("{}:{}", filepath, line)),
+            None => cmd.args.push(filepath),
+        }
+        cmd
+    }
+
+    // try_generate_combined_command
+    //
+    // Runnables of a file run as the file, without a line.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let mut runnable = Runnable::combined(&runnables)
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        runnable.meta.set_line(None);
+        Ok(self.generate_command(runnable))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::ruby::rspec,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r##"
+require "spec_helper"
+
+RSpec.describe Calculator do
+  subject(:calculator) { described_class.new }
+
+  shared_examples "a commutative operation" do |operation|
+    it "ignores the operand order" do
+      expect(calculator.public_send(operation, 1, 2)).to eq(calculator.public_send(operation, 2, 1))
+    end
+  end
+
+  describe "#add" do
+    it "sums two numbers" do
+      expect(calculator.add(1, 1)).to eq(2)
+    end
+
+    it_behaves_like "a commutative operation", :add
+  end
+
+  context "with negatives" do
+    it("keeps the sign") { expect(calculator.add(-1, -1)).to eq(-2) }
+  end
+end
+"##;
+
+    const BUNDLER_FILE: &str = "bundler_project/spec/models/calculator_spec.rb";
+    const PLAIN_FILE: &str = "plain_project/spec/calculator_spec.rb";
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/ruby/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(14, 6), vec!["spec/models/calculator_spec.rb"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(14, 6), vec!["Calculator#add sums two numbers"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(21, 6), vec!["Calculator with negatives keeps the sign"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(17, 4), vec!["Calculator#add"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(4, 2), vec!["Calculator"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(BUNDLER_FILE), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = rspec::RspecProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(1, 4))]
+    #[case(enums::Search::Method, types::CursorPosition::new(4, 2))]
+    fn test_not_found_at_position(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(BUNDLER_FILE), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = rspec::RspecProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    fn shared_examples_are_not_runnable() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture(BUNDLER_FILE),
+            types::CursorPosition::new(8, 6),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = rspec::RspecProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::PreconditionError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        BUNDLER_FILE,
+        enums::Search::Nearest,
+        types::CursorPosition::new(14, 6),
+        "bundle exec rspec spec/models/calculator_spec.rb:14"
+    )]
+    #[case(
+        BUNDLER_FILE,
+        enums::Search::File,
+        types::CursorPosition::new(14, 6),
+        "bundle exec rspec spec/models/calculator_spec.rb"
+    )]
+    #[case(
+        PLAIN_FILE,
+        enums::Search::Nearest,
+        types::CursorPosition::new(21, 6),
+        "rspec spec/calculator_spec.rb:22"
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected: &str,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = rspec::RspecProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let actual = format!("{} {}", cmd.command, cmd.args.join(" "));
+        assert_that!(actual, eq(expected));
+        let root = path.split('/').next().unwrap();
+        assert_that!(cmd.cwd, some(eq(&PathBuf::from(fixture(root)))));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        enums::Capability::TestRunner,
+        "spec/calculator_spec.rb",
+        CONTENT,
+        true
+    )]
+    #[case(enums::Capability::Debugger, "spec/calculator_spec.rb", CONTENT, false)]
+    #[case(enums::Capability::TestRunner, "lib/calculator.rb", CONTENT, false)]
+    #[case(
+        enums::Capability::TestRunner,
+        "spec/support_spec.rb",
+        "class Support\nend\n",
+        false
+    )]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = rspec::RspecProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::core::metadata::RunnableMeta;
+
+impl RunnableMeta {
+    pub(in crate::framework::ruby) fn set_line(&mut self, value: Option<usize>) {
+        if let RunnableMeta::Ruby { line, .. } = self {
+            *line = value;
+        }
+    }
+
+    pub(in crate::framework::ruby) fn set_ruby_project(
+        &mut self,
+        project_root: String,
+        project_bundler: bool,
+    ) {
+        if let RunnableMeta::Ruby { root, bundler, .. } = self {
+            *root = project_root;
+            *bundler = project_bundler;
+        }
+    }
+
+    pub(in crate::framework::ruby) fn get_ruby_meta(&self) -> Option<Meta> {
+        if let RunnableMeta::Ruby {
+            root,
+            bundler,
+            line,
+        } = self
+        {
+            return Some(Meta {
+                root: root.clone(),
+                bundler: *bundler,
+                line: *line,
+            });
+        }
+        None
+    }
+}
+
+pub(in crate::framework::ruby) struct Meta {
+    pub(in crate::framework::ruby) root: String,
+    pub(in crate::framework::ruby) bundler: bool,
+    pub(in crate::framework::ruby) line: Option<usize>,
+}
@@ -0,0 +1,28 @@
+// query
+//
+// Finds the rspec dsl calls of a spec: example groups, examples, shared
+// example definitions and their includes. The receiver, when present, is
+// checked to be `RSpec` after the match.
+//
+// Example:
+// RSpec.describe Calculator do
+//   it "adds" do
+//     expect(Calculator.new.add(1, 1)).to eq(2)
+//   end
+//
+//   it_behaves_like "a number"
+// end
+//
+// This will find the following calls:
+// - RSpec.describe Calculator
+// - it "adds"
+// - it_behaves_like "a number"
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((call
+                    method: (identifier) @rspec.method) @rspec.call
+                  (#match? @rspec.method "^([xf]?(describe|context|it|specify|example)|feature|example_group|scenario|its|focus|pending|skip|shared_examples|shared_examples_for|shared_context|it_behaves_like|it_should_behave_like|include_examples|include_context)$"))]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+
+use tree_sitter::Tree;
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::CargoTarget;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::rust::operations::cargotest_get_file_tests;
+use crate::framework::rust::operations::cargotest_get_test_modules;
+use crate::framework::rust::operations::get_cargo_context;
+use crate::framework::rust::operations::parse_tree;
+use crate::framework::rust::operations::rstest_get_cases;
+
+pub struct CargotestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+static FILE_SUFFIX: &str = ".rs";
+
+impl CargotestProvider {
+    pub fn new() -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+        }
+    }
+
+    fn search(&self, tree: &Tree, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        /*
+         * Goals
+         *   - Search set to nearest, return the test under the cursor.
+         *       If the test is an rstest with cases, check if a `#[case]` contains the cursor position.
+         *       Outside of a test, fall back to the innermost test module holding the cursor
+         *   - Search set to method, return the test under the cursor
+         *   - Search set to file, return all tests in the file, expanding rstest cases
+         * */
+        let root = tree.root_node();
+        let content = target.buffer.content;
+        let tests = cargotest_get_file_tests::op::execute(root, target).ok_or_else(|| {
+            FrameworkError::NotFoundError("Rust test not found no tests in this file".to_string())
+        })?;
+        let position = target.buffer.position;
+        let under_cursor = tests
+            .iter()
+            .find(|t| position.in_range(t.range.start.to_point()..t.range.end.to_point()))
+            .cloned();
+
+        match target.search_strategy {
+            Search::File => {
+                let mut res: Vec<Runnable> = vec![];
+                for test in tests.into_iter() {
+                    match rstest_get_cases::op::execute(root, content, &test, None) {
+                        Some(cases) => res.extend(cases),
+                        None => res.push(test),
+                    }
+                }
+                Ok(res)
+            }
+            Search::Method => {
+                let mut test = under_cursor.ok_or_else(|| {
+                    FrameworkError::NotFoundError("Rust test not found at position".to_string())
+                })?;
+                if rstest_get_cases::op::execute(root, content, &test, None).is_some() {
+                    test.meta.set_exact(false);
+                }
+                Ok(vec![test])
+            }
+            Search::Nearest => {
+                if let Some(mut test) = under_cursor {
+                    if rstest_get_cases::op::execute(root, content, &test, None).is_some() {
+                        if let Some(cases) =
+                            rstest_get_cases::op::execute(root, content, &test, Some(position))
+                        {
+                            return Ok(cases);
+                        }
+                        test.meta.set_exact(false);
+                    }
+                    return Ok(vec![test]);
+                }
+                cargotest_get_test_modules::op::execute(root, target, &tests)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|m| position.in_range(m.range.start.to_point()..m.range.end.to_point()))
+                    .max_by_key(|m| m.range.start.row)
+                    .map(|m| vec![m])
+                    .ok_or_else(|| {
+                        FrameworkError::NotFoundError("Rust test not found at position".to_string())
+                    })
+            }
+        }
+    }
+
+    // command
+    //
+    // `cargo test` selecting the package and target of the runnable.
+    fn command(&self, runnable: &Runnable) -> Command {
+        let mut cmd = Command {
+            command: "cargo".to_string(),
+            args: vec!["test".to_string()],
+            ..Default::default()
+        };
+
+        if let Some(meta) = runnable.meta.get_cargo_meta() {
+            if !meta.package.is_empty() {
+                cmd.args.push("-p".to_string());
+                cmd.args.push(meta.package.clone());
+            }
+            match &meta.target {
+                CargoTarget::Lib => cmd.args.push("--lib".to_string()),
+                CargoTarget::Bin(name) => {
+                    cmd.args.push("--bin".to_string());
+                    cmd.args.push(name.clone());
+                }
+                CargoTarget::Test(name) => {
+                    cmd.args.push("--test".to_string());
+                    cmd.args.push(name.clone());
+                }
+            }
+        }
+        cmd
+    }
+}
+
+impl FrameworkProvider for CargotestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(CargotestProvider::new())
+    }
+
+    fn name(&self) -> &'static str {
+        "CargoTest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Rust
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // Test runners rank above debuggers detecting the same file, cargo
+    // test below nextest in the workspaces opting into it.
+    fn priority(&self) -> u8 {
+        1
+    }
+}
+
+impl Framework for CargotestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability() {
+            return Detection::rejected("runs tests, not debug sessions");
+        }
+        if !target.buffer.filepath.ends_with(FILE_SUFFIX) {
+            return Detection::rejected(format!("file name does not end with `{}`", FILE_SUFFIX));
+        }
+        let Ok(tree) = parse_tree::op::execute(target.buffer.content) else {
+            return Detection::rejected("file could not be parsed");
+        };
+        if cargotest_get_file_tests::op::execute(tree.root_node(), target).is_none() {
+            return Detection::rejected("file has no `#[test]` functions");
+        }
+
+        Detection::matched(50, "file with `#[test]` functions")
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let tree = parse_tree::op::execute(target.buffer.content)?;
+        let mut res = self.search(&tree, target)?;
+        let context = get_cargo_context::op::execute(&target.buffer.filepath)?;
+        for runnable in res.iter_mut() {
+            let mut path = context.module_path.clone();
+            path.push(runnable.name.clone());
+            runnable.name = path.join("::");
+            runnable
+                .meta
+                .set_cargo_context(context.package.clone(), context.target.clone());
+        }
+        Ok(res)
+    }
+
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        let mut cmd = self.command(&runnable);
+        let exact = runnable.meta.get_cargo_meta().is_none_or(|m| m.exact);
+        cmd.args.push(runnable.name);
+        if exact {
+            cmd.args.push("--".to_string());
+            cmd.args.push("--exact".to_string());
+        }
+        cmd
+    }
+
+    // try_generate_combined_command
+    //
+    // The test binary takes every name as a filter, exact unless one of the
+    // runnables is a group of tests.
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        let runnable = runnables
+            .first()
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        let mut cmd = self.command(runnable);
+        cmd.args.push("--".to_string());
+        let exact = runnables
+            .iter()
+            .all(|r| r.meta.get_cargo_meta().is_none_or(|m| m.exact));
+        for runnable in runnables {
+            cmd.args.push(runnable.name);
+        }
+        if exact {
+            cmd.args.push("--exact".to_string());
+        }
+        Ok(cmd)
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod test {
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::rust::cargotest,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+        pub fn tokens(input: &str) -> Vec<&str> {
+            input.split_whitespace().collect()
+        }
+
+        #[cfg(test)]
+        mod test {
+            use super::*;
+
+            #[test]
+            fn splits_on_whitespace() {
+                assert_eq!(vec!["a", "b"], tokens("a b"));
+            }
+
+            mod empty {
+                use super::*;
+
+                #[tokio::test]
+                async fn empty_input() {
+                    assert!(tokens("").is_empty());
+                }
+            }
+        }
+        "#;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/rust/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(10, 3), vec!["parser::lexer::test::splits_on_whitespace", "parser::lexer::test::empty::empty_input"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(11, 3), vec!["parser::lexer::test::splits_on_whitespace"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(9, 3), vec!["parser::lexer::test::splits_on_whitespace"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(19, 3), vec!["parser::lexer::test::empty::empty_input"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(16, 3), vec!["parser::lexer::test::empty"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(7, 3), vec!["parser::lexer::test"])]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_names: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("workspace/crates/alpha/src/parser/lexer.rs"),
+            position,
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = cargotest::CargotestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        let expected: Vec<String> = expected_test_names.iter().map(|n| n.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("workspace/crates/alpha/src/parser/lexer.rs"),
+            types::CursorPosition::new(2, 3),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(enums::Search::Method);
+        let provider = cargotest::CargotestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(&FrameworkError::NotFoundError(_))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        "workspace/crates/alpha/src/parser/lexer.rs",
+        enums::Search::Nearest,
+        types::CursorPosition::new(11, 3),
+        "cargo test -p alpha --lib parser::lexer::test::splits_on_whitespace -- --exact"
+    )]
+    #[case(
+        "workspace/crates/alpha/src/parser/lexer.rs",
+        enums::Search::Nearest,
+        types::CursorPosition::new(16, 3),
+        "cargo test -p alpha --lib parser::lexer::test::empty"
+    )]
+    #[case(
+        "workspace/crates/alpha/tests/suite/helpers.rs",
+        enums::Search::Method,
+        types::CursorPosition::new(11, 3),
+        "cargo test -p alpha --test suite helpers::test::splits_on_whitespace -- --exact"
+    )]
+    #[case(
+        "standalone/src/lib.rs",
+        enums::Search::Method,
+        types::CursorPosition::new(19, 3),
+        "cargo test -p standalone --lib test::empty::empty_input -- --exact"
+    )]
+    fn generate_command(
+        #[case] path: &str,
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected: &str,
+    ) {
+        // arrange
+        let buffer = Buffer::new(CONTENT, fixture(path), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = cargotest::CargotestProvider::new();
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let actual = format!("{} {}", cmd.command, cmd.args.join(" "));
+        assert_that!(actual, eq(expected));
+    }
+
+    const RSTEST_CONTENT: &str = r#"
+        #[cfg(test)]
+        mod test {
+            use rstest::rstest;
+
+            #[rstest]
+            #[case(1, 2)]
+            #[case::negative(-1, -2)]
+            fn doubles(#[case] input: i32, #[case] expected: i32) {
+                assert_eq!(expected, input * 2);
+            }
+        }
+        "#;
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::File, types::CursorPosition::new(8, 3), vec!["test::doubles::case_1 -- --exact", "test::doubles::case_2_negative -- --exact"])]
+    #[case(enums::Search::Method, types::CursorPosition::new(6, 3), vec!["test::doubles"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(6, 3), vec!["test::doubles::case_1 -- --exact"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(7, 3), vec!["test::doubles::case_2_negative -- --exact"])]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(9, 3), vec!["test::doubles"])]
+    fn get_rstest_cases(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_filters: Vec<&str>,
+    ) {
+        // arrange
+        let buffer = Buffer::new(RSTEST_CONTENT, fixture("standalone/src/lib.rs"), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = cargotest::CargotestProvider::new();
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(res.is_ok(), eq(true));
+        let commands: Vec<String> = res
+            .unwrap()
+            .into_iter()
+            .map(|r| provider.generate_command(r).args.join(" "))
+            .collect();
+        let expected: Vec<String> = expected_filters
+            .iter()
+            .map(|f| format!("test -p standalone --lib {}", f))
+            .collect();
+        assert_that!(commands, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "lexer.rs", CONTENT, true)]
+    #[case(enums::Capability::Debugger, "lexer.rs", CONTENT, false)]
+    #[case(enums::Capability::TestRunner, "lexer.go", CONTENT, false)]
+    #[case(enums::Capability::TestRunner, "lexer.rs", "fn main() {}", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] content: &str,
+        #[case] expected: bool,
+    ) {
+        let buffer = Buffer::new(
+            content,
+            filepath.to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = cargotest::CargotestProvider::new();
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod cargotest;
+#[cfg(test)]
+mod cargotest_test;
+pub mod nextest;
+#[cfg(test)]
+mod nextest_test;
+mod operations;
+pub(crate) mod runnable_meta;
+mod treesitter;
@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::config::ConfigSource;
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::CargoTarget;
+use crate::core::types::Command;
+use crate::core::types::Detection;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::types::TestResult;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+use crate::framework::rust::cargotest::CargotestProvider;
+use crate::framework::rust::operations::get_cargo_context;
+use crate::framework::rust::operations::get_nextest_config;
+use crate::framework::rust::operations::nextest_parse_junit;
+
+// NextestProvider
+//
+// Runs rust tests through cargo-nextest. Runnables are discovered exactly
+// like `CargotestProvider` does and turned into filtersets, so the same
+// test, rstest case or module is selected under either runner. The profile
+// comes from the `[rust]` section of the configuration.
+pub struct NextestProvider {
+    search_capabilities: HashSet<CapabilityDetails>,
+    cargotest: CargotestProvider,
+    config: ConfigSource,
+}
+
+impl NextestProvider {
+    pub fn new() -> Self {
+        Self::with_config_source(ConfigSource::user())
+    }
+
+    // with_config_source
+    //
+    // Reads the profile from `config` rather than from the files of the user.
+    pub(crate) fn with_config_source(config: ConfigSource) -> Self {
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Nearest,
+            description: "Test Nearest".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::Method,
+            description: "Test Function".to_string(),
+        });
+        res.insert(CapabilityDetails {
+            capability: Capability::TestRunner,
+            search: Search::File,
+            description: "Test File".to_string(),
+        });
+        Self {
+            search_capabilities: res,
+            cargotest: CargotestProvider::new(),
+            config,
+        }
+    }
+
+    // profile
+    //
+    // The profile configured for the file when the workspace defines it,
+    // nextest's `default` otherwise.
+    fn profile(&self, filepath: &str) -> Result<Option<String>, FrameworkError> {
+        let Some(configured) = self.config.load(Path::new(filepath))?.rust.nextest_profile else {
+            return Ok(None);
+        };
+        Ok(self
+            .profiles(filepath)
+            .is_ok_and(|profiles| profiles.contains(&configured))
+            .then_some(configured))
+    }
+
+    // command
+    //
+    // `cargo nextest run` filtering the runnables of a search, the target
+    // and package being those of the first one.
+    fn command(&self, runnables: &[Runnable]) -> Result<Command, FrameworkError> {
+        let runnable = runnables
+            .first()
+            .ok_or_else(|| FrameworkError::NotFoundError("no runnable to run".to_string()))?;
+        let mut cmd = Command {
+            command: "cargo".to_string(),
+            args: vec!["nextest".to_string(), "run".to_string()],
+            ..Default::default()
+        };
+
+        if let Some(profile) = self.profile(&runnable.filepath)? {
+            cmd.args.push("--profile".to_string());
+            cmd.args.push(profile);
+        }
+
+        if let Some(meta) = runnable.meta.get_cargo_meta() {
+            if !meta.package.is_empty() {
+                cmd.args.push("-p".to_string());
+                cmd.args.push(meta.package.clone());
+            }
+            match &meta.target {
+                CargoTarget::Lib => cmd.args.push("--lib".to_string()),
+                CargoTarget::Bin(name) => {
+                    cmd.args.push("--bin".to_string());
+                    cmd.args.push(name.clone());
+                }
+                CargoTarget::Test(name) => {
+                    cmd.args.push("--test".to_string());
+                    cmd.args.push(name.clone());
+                }
+            }
+        }
+
+        let filters: Vec<String> = runnables
+            .iter()
+            .map(|r| match r.meta.get_cargo_meta().is_none_or(|m| m.exact) {
+                true => format!("test(={})", r.name),
+                false => format!("test(/^{}::/)", r.name),
+            })
+            .collect();
+        cmd.args.push("-E".to_string());
+        cmd.args.push(filters.join(" | "));
+        Ok(cmd)
+    }
+
+    // profiles
+    //
+    // Lists the nextest profiles available to the workspace owning the file.
+    pub fn profiles(&self, filepath: &str) -> Result<Vec<String>, FrameworkError> {
+        let context = get_cargo_context::op::execute(filepath)?;
+        let config = get_nextest_config::op::execute(&context.workspace_root)?;
+        Ok(config.profiles.into_keys().collect())
+    }
+
+    // junit_path
+    //
+    // Location of the JUnit report nextest writes for the runnable's
+    // workspace with the selected profile, if the profile enables one.
+    pub fn junit_path(&self, runnable: &Runnable) -> Result<Option<PathBuf>, FrameworkError> {
+        let context = get_cargo_context::op::execute(&runnable.filepath)?;
+        let config = get_nextest_config::op::execute(&context.workspace_root)?;
+        let profile = self.profile(&runnable.filepath)?;
+        Ok(config.junit_path(profile.as_deref().unwrap_or("default")))
+    }
+}
+
+impl FrameworkProvider for NextestProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(NextestProvider::with_config_source(self.config.clone()))
+    }
+
+    fn name(&self) -> &'static str {
+        "Nextest"
+    }
+
+    fn language(&self) -> crate_language {
+        crate_language::Rust
+    }
+
+    fn capability(&self) -> Capability {
+        Capability::TestRunner
+    }
+
+    // priority
+    //
+    // A workspace configuring nextest runs its tests with it rather than
+    // with cargo test.
+    fn priority(&self) -> u8 {
+        2
+    }
+}
+
+impl Framework for NextestProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    fn detection(&self, target: &Target) -> Detection {
+        let res = self.cargotest.detection(target);
+        if !res.matched {
+            return res;
+        }
+        // nextest is opted into per workspace through its configuration file
+        let configured = get_cargo_context::op::execute(&target.buffer.filepath)
+            .is_ok_and(|c| c.workspace_root.join(".config/nextest.toml").exists());
+        if !configured {
+            return Detection::rejected("workspace has no `.config/nextest.toml`");
+        }
+
+        Detection::matched(
+            90,
+            "file with `#[test]` functions in a workspace with `.config/nextest.toml`",
+        )
+    }
+
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        self.cargotest.runnables(target)
+    }
+
+    // generate_command
+    //
+    // An unreadable configuration yields an empty command, see
+    // `try_generate_command` for its error.
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.try_generate_command(runnable).unwrap_or_default()
+    }
+
+    fn try_generate_command(&self, runnable: Runnable) -> Result<Command, FrameworkError> {
+        self.command(&[runnable])
+    }
+
+    fn try_generate_combined_command(
+        &self,
+        runnables: Vec<Runnable>,
+    ) -> Result<Command, FrameworkError> {
+        self.command(&runnables)
+    }
+
+    // results
+    //
+    // Per-test results of the last run of the runnable's workspace, read from
+    // the JUnit report of the selected profile.
+    fn results(&self, runnable: &Runnable) -> Result<Vec<TestResult>, FrameworkError> {
+        let path = self.junit_path(runnable)?.ok_or_else(|| {
+            FrameworkError::NotFoundError(
+                "the nextest profile does not write a JUnit report".to_string(),
+            )
+        })?;
+        let xml = fs::read_to_string(&path)
+            .map_err(|e| FrameworkError::NotFoundError(format!("`{}`: {}", path.display(), e)))?;
+        nextest_parse_junit::op::execute(&xml)
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
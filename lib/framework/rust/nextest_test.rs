@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::config::ConfigSource;
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::Framework;
+    use crate::{
+        core::types::{self, Buffer, Target, TestResult},
+        framework::rust::nextest,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"
+        #[cfg(test)]
+        mod test {
+            use rstest::rstest;
+
+            #[test]
+            fn splits_on_whitespace() {}
+
+            #[rstest]
+            #[case(1, 2)]
+            #[case::negative(-1, -2)]
+            fn doubles(#[case] input: i32, #[case] expected: i32) {}
+        }
+        "#;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/rust/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    // provider
+    //
+    // A provider reading the user configuration fixture, which selects the
+    // `ci` profile, or no configuration at all.
+    fn provider(configured: bool) -> nextest::NextestProvider {
+        let user = configured.then(|| {
+            PathBuf::from(format!(
+                "{}/lib/fixtures/config/user/config.toml",
+                env!("CARGO_MANIFEST_DIR")
+            ))
+        });
+        nextest::NextestProvider::with_config_source(ConfigSource { user, trust: None })
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        false,
+        types::CursorPosition::new(6, 3),
+        "cargo nextest run -p alpha --lib -E test(=parser::lexer::test::splits_on_whitespace)"
+    )]
+    #[case(
+        true,
+        types::CursorPosition::new(10, 3),
+        "cargo nextest run --profile ci -p alpha --lib -E test(=parser::lexer::test::doubles::case_2_negative)"
+    )]
+    #[case(
+        false,
+        types::CursorPosition::new(11, 3),
+        "cargo nextest run -p alpha --lib -E test(/^parser::lexer::test::doubles::/)"
+    )]
+    #[case(
+        false,
+        types::CursorPosition::new(4, 3),
+        "cargo nextest run -p alpha --lib -E test(/^parser::lexer::test::/)"
+    )]
+    fn generate_command(
+        #[case] configured: bool,
+        #[case] position: types::CursorPosition,
+        #[case] expected: &str,
+    ) {
+        // arrange
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("workspace/crates/alpha/src/parser/lexer.rs"),
+            position,
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider(configured);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let actual = format!("{} {}", cmd.command, cmd.args.join(" "));
+        assert_that!(actual, eq(expected));
+    }
+
+    #[gtest]
+    fn ignores_profile_the_workspace_does_not_define() {
+        // arrange
+        let user =
+            std::env::temp_dir().join(format!("examen-nextest-config-{}.toml", std::process::id()));
+        fs::write(&user, "[rust]\nnextest_profile = \"nightly\"\n").unwrap();
+        let provider = nextest::NextestProvider::with_config_source(ConfigSource {
+            user: Some(user.clone()),
+            trust: None,
+        });
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("workspace/crates/alpha/src/parser/lexer.rs"),
+            types::CursorPosition::new(6, 3),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        let _ = fs::remove_file(&user);
+        assert_that!(cmd.args, not(contains(eq("--profile"))));
+    }
+
+    #[gtest]
+    fn reports_invalid_config() {
+        // arrange
+        let user = std::env::temp_dir().join(format!(
+            "examen-nextest-invalid-{}.toml",
+            std::process::id()
+        ));
+        fs::write(&user, "[rust]\nprofile = \"ci\"\n").unwrap();
+        let provider = nextest::NextestProvider::with_config_source(ConfigSource {
+            user: Some(user.clone()),
+            trust: None,
+        });
+        let buffer = Buffer::new(
+            CONTENT,
+            fixture("workspace/crates/alpha/src/parser/lexer.rs"),
+            types::CursorPosition::new(6, 3),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let res = provider.try_generate_command(runnable);
+
+        // assert
+        let _ = fs::remove_file(&user);
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::ConfigError(
+                contains_substring("unknown field `profile`")
+            )))
+        );
+    }
+
+    #[gtest]
+    fn profiles_and_junit_path() {
+        // arrange
+        let filepath = fixture("workspace/crates/alpha/src/parser/lexer.rs");
+        let buffer = Buffer::new(CONTENT, filepath.clone(), types::CursorPosition::new(6, 3));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider(true);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let profiles = provider.profiles(&filepath);
+        let junit_path = provider.junit_path(&runnable);
+
+        // assert
+        assert_that!(profiles, ok(elements_are![eq("ci"), eq("default")]));
+        assert_that!(junit_path.is_ok(), eq(true));
+        let junit_path = junit_path.unwrap();
+        assert_that!(junit_path, some(anything()));
+        assert_that!(
+            junit_path
+                .unwrap()
+                .ends_with("workspace/target/nextest/ci/junit.xml"),
+            eq(true)
+        );
+    }
+
+    #[gtest]
+    fn results_read_junit_report() {
+        // arrange
+        let filepath = fixture("workspace/crates/alpha/src/parser/lexer.rs");
+        let buffer = Buffer::new(CONTENT, filepath, types::CursorPosition::new(6, 3));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider(false);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+        let report = provider.junit_path(&runnable).unwrap().unwrap();
+        let _ = fs::remove_file(&report);
+        let missing = provider.results(&runnable);
+        fs::create_dir_all(report.parent().unwrap()).unwrap();
+        fs::write(
+            &report,
+            r#"<testsuites name="nextest-run" tests="1" failures="0">
+              <testsuite name="alpha" tests="1" failures="0">
+                <testcase name="parser::lexer::test::splits_on_whitespace" classname="alpha" time="0.004"/>
+              </testsuite>
+            </testsuites>"#,
+        )
+        .unwrap();
+
+        // act
+        let res = provider.results(&runnable);
+
+        // assert
+        let _ = fs::remove_file(&report);
+        assert_that!(missing.is_err(), eq(true));
+        assert_that!(
+            res,
+            ok(elements_are![field!(
+                TestResult.status,
+                eq(&enums::TestStatus::Passed)
+            )])
+        );
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("workspace/crates/alpha/src/parser/lexer.rs", true)]
+    #[case("standalone/src/lib.rs", false)]
+    fn detect(#[case] path: &str, #[case] expected: bool) {
+        let buffer = Buffer::new(CONTENT, fixture(path), types::CursorPosition::new(0, 0));
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider(false);
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+}
@@ -0,0 +1,207 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::rust::treesitter::cargotest_test_function,
+        treesitter::node,
+    };
+
+    // TEST_ATTRIBUTES
+    //
+    // Attribute paths that turn a function into a test picked up by the
+    // libtest harness.
+    const TEST_ATTRIBUTES: [&str; 8] = [
+        "test",
+        "tokio::test",
+        "async_std::test",
+        "rstest",
+        "rstest::rstest",
+        "gtest",
+        "googletest::test",
+        "googletest::gtest",
+    ];
+
+    // execute
+    //
+    // Returns every test function in the file. Runnable names are relative to
+    // the file, e.g. `test::adds` for a test nested in `mod test`, and their
+    // range starts at the first attribute so the cursor on `#[test]` still
+    // resolves to the function.
+    pub fn execute(root: Node, target: &Target) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = cargotest_test_function::query();
+        let query = Query::new(&Language::new(tree_sitter_rust::LANGUAGE), &query_pattern).ok()?;
+        let test_name_index = query.capture_index_for_name("test_name")?;
+        let test_function_index = query.capture_index_for_name("testfunc")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let function_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == test_function_index)
+                .map(|c| c.node);
+            let name_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == test_name_index)
+                .map(|c| c.node);
+
+            let (Some(function_node), Some(name_node)) = (function_node, name_node) else {
+                continue;
+            };
+
+            let attributes = attributes(function_node);
+            if !attributes
+                .iter()
+                .any(|a| TEST_ATTRIBUTES.contains(&attribute_path(*a, content).as_str()))
+            {
+                continue;
+            }
+
+            let mut path = module_path(function_node, content);
+            path.push(node::node_text(name_node, content));
+            let start = attributes.last().unwrap_or(&function_node).start_position();
+            runnables.push(Runnable {
+                name: path.join("::"),
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(start),
+                    end: CursorPosition::from_point(function_node.end_position()),
+                },
+                meta: RunnableMeta::default_rust(),
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+
+    // attributes
+    //
+    // Collects the attribute items directly preceding an item, nearest
+    // first. Comments between attributes are skipped.
+    pub(crate) fn attributes(item: Node) -> Vec<Node> {
+        let mut res = vec![];
+        let mut sibling = item.prev_named_sibling();
+        while let Some(node) = sibling {
+            match node.kind() {
+                "attribute_item" => res.push(node),
+                "line_comment" | "block_comment" => {}
+                _ => break,
+            }
+            sibling = node.prev_named_sibling();
+        }
+        res
+    }
+
+    // attribute_path
+    //
+    // Returns the path of an attribute item, e.g. `tokio::test` for
+    // `#[tokio::test(flavor = "multi_thread")]`.
+    pub(crate) fn attribute_path(attribute_item: Node, content: &str) -> String {
+        attribute_item
+            .named_child(0)
+            .and_then(|attribute| attribute.named_child(0))
+            .map(|path| node::node_text(path, content).replace(char::is_whitespace, ""))
+            .unwrap_or_default()
+    }
+
+    // module_path
+    //
+    // Names of the inline `mod` blocks enclosing the node, outermost first.
+    pub(crate) fn module_path(item: Node, content: &str) -> Vec<String> {
+        let mut res = vec![];
+        let mut parent = item.parent();
+        while let Some(node) = parent {
+            if node.kind() == "mod_item"
+                && let Some(name) = node.child_by_field_name("name")
+            {
+                res.push(node::node_text(name, content));
+            }
+            parent = node.parent();
+        }
+        res.reverse();
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::rust::operations::parse_tree;
+
+    #[gtest]
+    fn finds_test_functions_in_nested_modules() {
+        let content = r#"
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        #[test]
+        fn top_level() {}
+
+        #[cfg(test)]
+        mod test {
+            use super::*;
+
+            #[tokio::test(flavor = "multi_thread")]
+            async fn async_case() {}
+
+            fn helper() {}
+
+            mod nested {
+                #[gtest]
+                // comment between attributes
+                #[rstest]
+                fn deep() {}
+            }
+        }
+        "#;
+        let tree = parse_tree::op::execute(content).unwrap();
+        let buffer = Buffer::new(content, "lib.rs".to_string(), CursorPosition::new(0, 0));
+        let target = Target::new(Capability::TestRunner, buffer);
+
+        let res = op::execute(tree.root_node(), &target);
+
+        assert_that!(res, some(anything()));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        assert_that!(
+            names,
+            elements_are![
+                eq("top_level"),
+                eq("test::async_case"),
+                eq("test::nested::deep")
+            ]
+        );
+    }
+
+    #[gtest]
+    fn no_test_functions() {
+        let content = r#"
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+        "#;
+        let tree = parse_tree::op::execute(content).unwrap();
+        let buffer = Buffer::new(content, "lib.rs".to_string(), CursorPosition::new(0, 0));
+        let target = Target::new(Capability::TestRunner, buffer);
+
+        assert_that!(op::execute(tree.root_node(), &target), none());
+    }
+}
@@ -0,0 +1,79 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable, Target},
+        },
+        framework::rust::{
+            operations::cargotest_get_file_tests, treesitter::cargotest_test_module,
+        },
+        treesitter::node,
+    };
+
+    // execute
+    //
+    // Returns the inline modules that hold at least one of the given tests.
+    // Module runnables are not exact: running `test::nested` selects every
+    // test under that path.
+    pub fn execute(root: Node, target: &Target, tests: &[Runnable]) -> Option<Vec<Runnable>> {
+        let content = target.buffer.content;
+        let query_pattern = cargotest_test_module::query();
+        let query = Query::new(&Language::new(tree_sitter_rust::LANGUAGE), &query_pattern).ok()?;
+        let module_name_index = query.capture_index_for_name("module_name")?;
+        let module_index = query.capture_index_for_name("module")?;
+        let mut cursor = QueryCursor::new();
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut runnables: Vec<Runnable> = vec![];
+        for node_matched in query_matches {
+            let module_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == module_index)
+                .map(|c| c.node);
+            let name_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == module_name_index)
+                .map(|c| c.node);
+
+            let (Some(module_node), Some(name_node)) = (module_node, name_node) else {
+                continue;
+            };
+
+            let mut path = cargotest_get_file_tests::op::module_path(module_node, content);
+            path.push(node::node_text(name_node, content));
+            let name = path.join("::");
+            let prefix = format!("{}::", name);
+            if !tests.iter().any(|t| t.name.starts_with(&prefix)) {
+                continue;
+            }
+
+            let start = cargotest_get_file_tests::op::attributes(module_node)
+                .last()
+                .unwrap_or(&module_node)
+                .start_position();
+            let mut meta = RunnableMeta::default_rust();
+            meta.set_exact(false);
+            runnables.push(Runnable {
+                name,
+                filepath: target.buffer.filepath.to_string(),
+                range: Range {
+                    start: CursorPosition::from_point(start),
+                    end: CursorPosition::from_point(module_node.end_position()),
+                },
+                meta,
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
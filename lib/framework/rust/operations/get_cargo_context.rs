@@ -0,0 +1,300 @@
+pub(crate) mod op {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::core::{
+        errors::FrameworkError,
+        metadata::CargoTarget,
+        paths::{absolute, normalize},
+    };
+
+    static MANIFEST: &str = "Cargo.toml";
+
+    // CargoContext
+    //
+    // Describes where a rust source file lives within its cargo package.
+    pub(crate) struct CargoContext {
+        pub(crate) package: String,
+        pub(crate) manifest_dir: PathBuf,
+        pub(crate) workspace_root: PathBuf,
+        pub(crate) target: CargoTarget,
+        pub(crate) module_path: Vec<String>,
+    }
+
+    // execute
+    //
+    // Resolves the package owning the file by walking up to the nearest
+    // `Cargo.toml` with a `[package]` table, then works out the compilation
+    // target and the module path of the file within that target.
+    //
+    // Example: for `crates/alpha/src/parser/lexer.rs` this yields the
+    // package `alpha`, the `--lib` target and the module path
+    // `parser::lexer`.
+    pub(crate) fn execute(filepath: &str) -> Result<CargoContext, FrameworkError> {
+        let file = absolute(Path::new(filepath));
+        let (manifest_dir, manifest) = file
+            .ancestors()
+            .skip(1)
+            .find_map(|dir| {
+                let manifest = read_manifest(dir)?;
+                manifest.contains_key("package").then_some((dir, manifest))
+            })
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!("no cargo package owns `{}`", filepath))
+            })?;
+
+        let package = manifest
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| {
+                FrameworkError::ParsingError(format!(
+                    "`{}` has no package name",
+                    manifest_dir.join(MANIFEST).display()
+                ))
+            })?
+            .to_string();
+
+        let workspace_root = if manifest.contains_key("workspace") {
+            manifest_dir.to_path_buf()
+        } else {
+            manifest_dir
+                .ancestors()
+                .skip(1)
+                .find(|dir| read_manifest(dir).is_some_and(|m| m.contains_key("workspace")))
+                .unwrap_or(manifest_dir)
+                .to_path_buf()
+        };
+
+        let relative = file.strip_prefix(manifest_dir).map_err(|_| {
+            FrameworkError::PreconditionError(format!(
+                "`{}` is not inside `{}`",
+                filepath,
+                manifest_dir.display()
+            ))
+        })?;
+
+        let (target, root) = resolve_target(&package, manifest_dir, &manifest, relative)
+            .ok_or_else(|| {
+                FrameworkError::NotFoundError(format!(
+                    "`{}` does not belong to a test target of `{}`",
+                    filepath, package
+                ))
+            })?;
+
+        Ok(CargoContext {
+            package,
+            manifest_dir: manifest_dir.to_path_buf(),
+            workspace_root,
+            target,
+            module_path: module_path(relative, &root),
+        })
+    }
+
+    fn read_manifest(dir: &Path) -> Option<toml::Table> {
+        let content = fs::read_to_string(dir.join(MANIFEST)).ok()?;
+        content.parse::<toml::Table>().ok()
+    }
+
+    // resolve_target
+    //
+    // Returns the target of a file relative to the manifest directory and
+    // the root file (crate root) of that target.
+    fn resolve_target(
+        package: &str,
+        manifest_dir: &Path,
+        manifest: &toml::Table,
+        relative: &Path,
+    ) -> Option<(CargoTarget, PathBuf)> {
+        if let Ok(rest) = relative.strip_prefix("tests") {
+            let mut components = rest.components();
+            let first = components.next()?.as_os_str().to_string_lossy().to_string();
+            if components.next().is_none() {
+                let name = first.strip_suffix(".rs")?.to_string();
+                return Some((
+                    CargoTarget::Test(name.clone()),
+                    PathBuf::from("tests").join(first),
+                ));
+            }
+            return Some((
+                CargoTarget::Test(first.clone()),
+                PathBuf::from("tests").join(first).join("main.rs"),
+            ));
+        }
+
+        let bins = manifest
+            .get("bin")
+            .and_then(|b| b.as_array())
+            .cloned()
+            .unwrap_or_default();
+        for bin in bins.iter() {
+            let name = bin.get("name").and_then(|n| n.as_str());
+            let path = bin.get("path").and_then(|p| p.as_str());
+            if let (Some(name), Some(path)) = (name, path)
+                && normalize(Path::new(path)) == relative
+            {
+                return Some((CargoTarget::Bin(name.to_string()), relative.to_path_buf()));
+            }
+        }
+
+        if let Ok(rest) = relative.strip_prefix("src/bin") {
+            let mut components = rest.components();
+            let first = components.next()?.as_os_str().to_string_lossy().to_string();
+            if components.next().is_none() {
+                let name = first.strip_suffix(".rs")?.to_string();
+                return Some((CargoTarget::Bin(name), relative.to_path_buf()));
+            }
+            return Some((
+                CargoTarget::Bin(first.clone()),
+                PathBuf::from("src/bin").join(first).join("main.rs"),
+            ));
+        }
+
+        let main = PathBuf::from("src/main.rs");
+        if relative == main {
+            return Some((CargoTarget::Bin(package.to_string()), main));
+        }
+
+        let lib = manifest
+            .get("lib")
+            .and_then(|l| l.get("path"))
+            .and_then(|p| p.as_str())
+            .map(|p| normalize(Path::new(p)))
+            .unwrap_or_else(|| PathBuf::from("src/lib.rs"));
+        let lib_declared = manifest.contains_key("lib") || manifest_dir.join(&lib).exists();
+        if lib_declared && relative.starts_with(lib.parent().unwrap_or(Path::new(""))) {
+            return Some((CargoTarget::Lib, lib));
+        }
+
+        if relative.starts_with("src") && manifest_dir.join(&main).exists() {
+            return Some((CargoTarget::Bin(package.to_string()), main));
+        }
+
+        None
+    }
+
+    // module_path
+    //
+    // Module path of a file relative to its crate root, following the
+    // `foo.rs` / `foo/mod.rs` conventions.
+    fn module_path(relative: &Path, root: &Path) -> Vec<String> {
+        if relative == root {
+            return vec![];
+        }
+        let root_dir = root.parent().unwrap_or(Path::new(""));
+        let Ok(inner) = relative.strip_prefix(root_dir) else {
+            return vec![];
+        };
+
+        let mut res: Vec<String> = inner
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        if let Some(last) = res.pop() {
+            let stem = last.strip_suffix(".rs").unwrap_or(&last).to_string();
+            if stem != "mod" {
+                res.push(stem);
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::errors::FrameworkError;
+    use crate::core::metadata::CargoTarget;
+
+    fn fixture(path: &str) -> String {
+        format!("{}/lib/fixtures/rust/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("workspace/crates/alpha/src/lib.rs", "alpha", CargoTarget::Lib, "")]
+    #[case(
+        "workspace/crates/alpha/src/parser/mod.rs",
+        "alpha",
+        CargoTarget::Lib,
+        "parser"
+    )]
+    #[case(
+        "workspace/crates/alpha/src/parser/lexer.rs",
+        "alpha",
+        CargoTarget::Lib,
+        "parser::lexer"
+    )]
+    #[case(
+        "workspace/crates/alpha/tests/integration.rs",
+        "alpha",
+        CargoTarget::Test("integration".to_string()),
+        ""
+    )]
+    #[case(
+        "workspace/crates/alpha/tests/suite/helpers.rs",
+        "alpha",
+        CargoTarget::Test("suite".to_string()),
+        "helpers"
+    )]
+    #[case(
+        "workspace/crates/alpha/src/main.rs",
+        "alpha",
+        CargoTarget::Bin("alpha".to_string()),
+        ""
+    )]
+    #[case(
+        "workspace/crates/alpha/src/bin/tool.rs",
+        "alpha",
+        CargoTarget::Bin("tool".to_string()),
+        ""
+    )]
+    #[case("workspace/crates/beta/lib/lib.rs", "beta", CargoTarget::Lib, "")]
+    #[case(
+        "workspace/crates/beta/lib/core/types.rs",
+        "beta",
+        CargoTarget::Lib,
+        "core::types"
+    )]
+    fn resolves_package_target_and_module_path(
+        #[case] path: &str,
+        #[case] package: &str,
+        #[case] target: CargoTarget,
+        #[case] module_path: &str,
+    ) {
+        let res = op::execute(&fixture(path));
+
+        assert_that!(res.is_ok(), eq(true));
+        let res = res.ok().unwrap();
+        expect_that!(res.package, eq(package));
+        expect_that!(res.target, eq(&target));
+        expect_that!(res.module_path.join("::"), eq(module_path));
+        expect_that!(res.workspace_root.ends_with("rust/workspace"), eq(true));
+    }
+
+    #[gtest]
+    fn standalone_package_is_its_own_workspace_root() {
+        let res = op::execute(&fixture("standalone/src/lib.rs"));
+
+        assert_that!(res.is_ok(), eq(true));
+        let res = res.ok().unwrap();
+        expect_that!(res.package, eq("standalone"));
+        expect_that!(res.workspace_root, eq(&res.manifest_dir));
+    }
+
+    #[gtest]
+    fn file_outside_of_a_package() {
+        let res = op::execute("/no/such/crate/src/lib.rs");
+
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.err().unwrap(),
+            matches_pattern!(FrameworkError::NotFoundError(_))
+        );
+    }
+}
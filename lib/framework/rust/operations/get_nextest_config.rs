@@ -0,0 +1,137 @@
+pub(crate) mod op {
+    use std::{
+        collections::BTreeMap,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::core::errors::FrameworkError;
+
+    static CONFIG: &str = ".config/nextest.toml";
+    static DEFAULT_PROFILE: &str = "default";
+    static DEFAULT_STORE_DIR: &str = "target/nextest";
+
+    // NextestConfig
+    //
+    // The parts of `.config/nextest.toml` needed to pick a profile and to
+    // find the JUnit report it writes.
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct NextestConfig {
+        pub(crate) store_dir: PathBuf,
+        // profile name to the JUnit report path configured for it
+        pub(crate) profiles: BTreeMap<String, Option<String>>,
+    }
+
+    impl NextestConfig {
+        // junit_path
+        //
+        // nextest writes reports to `<store dir>/<profile>/<junit path>`.
+        // Profiles without their own `junit.path` inherit the one of the
+        // `default` profile.
+        pub(crate) fn junit_path(&self, profile: &str) -> Option<PathBuf> {
+            let path = self
+                .profiles
+                .get(profile)
+                .cloned()
+                .flatten()
+                .or_else(|| self.profiles.get(DEFAULT_PROFILE).cloned().flatten())?;
+            Some(self.store_dir.join(profile).join(path))
+        }
+    }
+
+    // execute
+    //
+    // Reads the nextest configuration of the workspace. A workspace without a
+    // configuration file only has the built-in `default` profile.
+    pub(crate) fn execute(workspace_root: &Path) -> Result<NextestConfig, FrameworkError> {
+        let mut profiles = BTreeMap::new();
+        profiles.insert(DEFAULT_PROFILE.to_string(), None);
+        let path = workspace_root.join(CONFIG);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Ok(NextestConfig {
+                store_dir: workspace_root.join(DEFAULT_STORE_DIR),
+                profiles,
+            });
+        };
+
+        let table = content.parse::<toml::Table>().map_err(|e| {
+            FrameworkError::ParsingError(format!("`{}`: {}", path.display(), e.message()))
+        })?;
+
+        let store_dir = table
+            .get("store")
+            .and_then(|s| s.get("dir"))
+            .and_then(|d| d.as_str())
+            .unwrap_or(DEFAULT_STORE_DIR);
+
+        if let Some(declared) = table.get("profile").and_then(|p| p.as_table()) {
+            for (name, profile) in declared.iter() {
+                let junit = profile
+                    .get("junit")
+                    .and_then(|j| j.get("path"))
+                    .and_then(|p| p.as_str())
+                    .map(|p| p.to_string());
+                profiles.insert(name.clone(), junit);
+            }
+        }
+
+        Ok(NextestConfig {
+            store_dir: workspace_root.join(store_dir),
+            profiles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use googletest::prelude::*;
+
+    use super::op;
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(format!(
+            "{}/lib/fixtures/rust/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        ))
+    }
+
+    #[gtest]
+    fn reads_profiles_and_junit_paths() {
+        let root = fixture("workspace");
+
+        let res = op::execute(&root);
+
+        assert_that!(res.is_ok(), eq(true));
+        let res = res.unwrap();
+        assert_that!(
+            res.profiles.keys().cloned().collect::<Vec<String>>(),
+            elements_are![eq("ci"), eq("default")]
+        );
+        assert_that!(
+            res.junit_path("ci"),
+            some(eq(&root.join("target/nextest/ci/junit.xml")))
+        );
+        assert_that!(
+            res.junit_path("default"),
+            some(eq(&root.join("target/nextest/default/junit.xml")))
+        );
+    }
+
+    #[gtest]
+    fn missing_configuration_has_default_profile() {
+        let root = fixture("standalone");
+
+        let res = op::execute(&root);
+
+        assert_that!(res.is_ok(), eq(true));
+        let res = res.unwrap();
+        assert_that!(
+            res.profiles.keys().cloned().collect::<Vec<String>>(),
+            elements_are![eq("default")]
+        );
+        assert_that!(res.junit_path("default"), none());
+    }
+}
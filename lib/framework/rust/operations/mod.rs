@@ -0,0 +1,7 @@
+pub(crate) mod cargotest_get_file_tests;
+pub(crate) mod cargotest_get_test_modules;
+pub(crate) mod get_cargo_context;
+pub(crate) mod get_nextest_config;
+pub(crate) mod nextest_parse_junit;
+pub(crate) mod parse_tree;
+pub(crate) mod rstest_get_cases;
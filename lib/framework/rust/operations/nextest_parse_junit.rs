@@ -0,0 +1,183 @@
+pub(crate) mod op {
+    use quick_xml::{Reader, events::BytesStart, events::Event};
+
+    use crate::core::{enums::TestStatus, errors::FrameworkError, types::TestResult};
+
+    // execute
+    //
+    // Parses the JUnit report written by cargo-nextest into one result per
+    // `<testcase>`. Test cases carrying a `<failure>` or `<error>` element
+    // failed, `<skipped>` ones were not run and all others passed.
+    //
+    // Example:
+    // <testsuites name="nextest-run" tests="2" failures="1">
+    //   <testsuite name="alpha" tests="2" failures="1">
+    //     <testcase name="test::adds" classname="alpha" time="0.001"/>
+    //     <testcase name="test::subtracts" classname="alpha" time="0.002">
+    //       <failure type="test failure">assertion failed</failure>
+    //     </testcase>
+    //   </testsuite>
+    // </testsuites>
+    pub(crate) fn execute(xml: &str) -> Result<Vec<TestResult>, FrameworkError> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut results = vec![];
+        let mut current: Option<TestResult> = None;
+        let mut in_message = false;
+        loop {
+            let event = reader.read_event().map_err(|e| {
+                FrameworkError::ParsingError(format!(
+                    "invalid junit report at position {}: {}",
+                    reader.error_position(),
+                    e
+                ))
+            })?;
+            match event {
+                Event::Start(e) if e.name().as_ref() == b"testcase" => {
+                    current = Some(test_case(&e)?);
+                }
+                Event::Empty(e) if e.name().as_ref() == b"testcase" => {
+                    results.push(test_case(&e)?);
+                }
+                Event::Start(e) if current.is_some() => {
+                    in_message = outcome(current.as_mut().unwrap(), &e)?;
+                }
+                Event::Empty(e) if current.is_some() => {
+                    outcome(current.as_mut().unwrap(), &e)?;
+                }
+                Event::Text(t) if in_message => {
+                    let text = t.unescape().map_err(|e| {
+                        FrameworkError::ParsingError(format!("invalid junit report: {}", e))
+                    })?;
+                    if let Some(result) = current.as_mut() {
+                        result.message = Some(text.to_string());
+                    }
+                }
+                Event::CData(c) if in_message => {
+                    if let Some(result) = current.as_mut() {
+                        result.message = Some(String::from_utf8_lossy(&c).to_string());
+                    }
+                }
+                Event::End(e) => match e.name().as_ref() {
+                    b"failure" | b"error" => in_message = false,
+                    b"testcase" => {
+                        if let Some(result) = current.take() {
+                            results.push(result);
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(results)
+    }
+
+    // outcome
+    //
+    // Applies a child element of `<testcase>` to its result, returning
+    // whether the element holds a failure message.
+    fn outcome(result: &mut TestResult, e: &BytesStart) -> Result<bool, FrameworkError> {
+        match e.name().as_ref() {
+            b"failure" | b"error" => {
+                result.status = TestStatus::Failed;
+                result.message = attribute(e, b"message")?;
+                Ok(true)
+            }
+            b"skipped" => {
+                result.status = TestStatus::Skipped;
+                result.message = attribute(e, b"message")?;
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn test_case(e: &BytesStart) -> Result<TestResult, FrameworkError> {
+        let name = attribute(e, b"name")?.ok_or_else(|| {
+            FrameworkError::ParsingError("junit testcase without a name".to_string())
+        })?;
+        Ok(TestResult {
+            name,
+            suite: attribute(e, b"classname")?.unwrap_or_default(),
+            status: TestStatus::Passed,
+            duration: attribute(e, b"time")?.and_then(|t| t.parse::<f64>().ok()),
+            message: None,
+        })
+    }
+
+    fn attribute(e: &BytesStart, key: &[u8]) -> Result<Option<String>, FrameworkError> {
+        let attribute = e
+            .try_get_attribute(key)
+            .map_err(|e| FrameworkError::ParsingError(format!("invalid junit report: {}", e)))?;
+        match attribute {
+            Some(a) => a
+                .unescape_value()
+                .map(|v| Some(v.to_string()))
+                .map_err(|e| FrameworkError::ParsingError(format!("invalid junit report: {}", e))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+
+    use super::op;
+    use crate::core::enums::TestStatus;
+    use crate::core::errors::FrameworkError;
+
+    #[gtest]
+    fn parses_test_results() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <testsuites name="nextest-run" tests="4" failures="1" errors="0">
+          <testsuite name="alpha" tests="4" disabled="1" errors="0" failures="1">
+            <testcase name="parser::lexer::test::splits_on_whitespace" classname="alpha" timestamp="2026-10-18T10:00:00Z" time="0.004"/>
+            <testcase name="test::adds" classname="alpha" time="0.012">
+              <failure type="test failure" message="assertion failed">thread 'test::adds' panicked at src/lib.rs:12:9:
+assertion `left == right` failed</failure>
+              <system-out>running 1 test</system-out>
+            </testcase>
+            <testcase name="test::ignored" classname="alpha" time="0">
+              <skipped/>
+            </testcase>
+            <testcase name="adds_from_outside" classname="alpha::integration" time="0.001">
+              <system-err><![CDATA[warning: noisy]]></system-err>
+            </testcase>
+          </testsuite>
+        </testsuites>"#;
+
+        let res = op::execute(xml);
+
+        assert_that!(res.is_ok(), eq(true));
+        let res = res.unwrap();
+        assert_that!(res.len(), eq(4));
+        expect_that!(res[0].name, eq("parser::lexer::test::splits_on_whitespace"));
+        expect_that!(res[0].status, eq(&TestStatus::Passed));
+        expect_that!(res[0].duration, some(eq(0.004)));
+        expect_that!(res[1].status, eq(&TestStatus::Failed));
+        expect_that!(
+            res[1].message.as_deref(),
+            some(starts_with("thread 'test::adds' panicked"))
+        );
+        expect_that!(res[2].status, eq(&TestStatus::Skipped));
+        expect_that!(res[3].suite, eq("alpha::integration"));
+        expect_that!(res[3].status, eq(&TestStatus::Passed));
+        expect_that!(res[3].message, none());
+    }
+
+    #[gtest]
+    fn invalid_report() {
+        let res = op::execute("<testsuites><testcase name=\"a\"></testsuite>");
+
+        assert_that!(res.is_err(), eq(true));
+        assert_that!(
+            res.unwrap_err(),
+            matches_pattern!(FrameworkError::ParsingError(_))
+        );
+    }
+}
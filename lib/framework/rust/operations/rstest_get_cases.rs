@@ -0,0 +1,201 @@
+pub(crate) mod op {
+    use std::ops::Range;
+
+    use tree_sitter::{Language, Node, Query, QueryCursor};
+
+    use crate::{
+        core::{
+            metadata::RunnableMeta,
+            types::{CursorPosition, Runnable},
+        },
+        framework::rust::treesitter::rstest_case_attribute,
+        treesitter::node::node_text,
+    };
+
+    // execute
+    //
+    // Expands the `#[case]` attributes of an rstest function into the tests
+    // rstest generates for them. rstest turns the function into a module and
+    // each case into `case_<n>` (or `case_<n>_<description>` for
+    // `#[case::description(...)]`), zero padding `n` to the width of the
+    // number of cases.
+    //
+    // Example:
+    // #[rstest]
+    // #[case(1, 2)]
+    // #[case::negative(-1, -2)]
+    // fn doubles(#[case] input: i32, #[case] expected: i32) {}
+    //
+    // yields `doubles::case_1` and `doubles::case_2_negative`.
+    pub(crate) fn execute(
+        root: Node,
+        content: &str,
+        parent: &Runnable,
+        cursor_position: Option<CursorPosition>,
+    ) -> Option<Vec<Runnable>> {
+        let query_pattern = rstest_case_attribute::query();
+        let query = Query::new(&Language::new(tree_sitter_rust::LANGUAGE), &query_pattern).ok()?;
+        let case_path_index = query.capture_index_for_name("test.case.path")?;
+        let case_index = query.capture_index_for_name("test.case")?;
+        let mut cursor = QueryCursor::new();
+        cursor.set_point_range(Range {
+            start: parent.range.start.to_point(),
+            end: parent.range.end.to_point(),
+        });
+        let query_matches = cursor.matches(&query, root, content.as_bytes());
+
+        let mut cases: Vec<(Option<String>, Node)> = vec![];
+        for node_matched in query_matches {
+            let case_node = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == case_index)
+                .map(|c| c.node);
+            let path = node_matched
+                .captures
+                .iter()
+                .find(|c| c.index == case_path_index)
+                .map(|c| node_text(c.node, content).replace(char::is_whitespace, ""));
+
+            let (Some(case_node), Some(path)) = (case_node, path) else {
+                continue;
+            };
+            // attributes on parameters such as `#[case] input: i32` never
+            // carry arguments, but nested items inside the body may.
+            if case_node
+                .parent()
+                .is_none_or(|p| p.kind() == "parameter" || p.kind() == "block")
+            {
+                continue;
+            }
+            if path == "case" {
+                cases.push((None, case_node));
+            } else if let Some(description) = path.strip_prefix("case::") {
+                cases.push((Some(description.to_string()), case_node));
+            }
+        }
+
+        let width = cases.len().to_string().len();
+        let mut runnables = vec![];
+        for (index, (description, case_node)) in cases.into_iter().enumerate() {
+            if let Some(position) = cursor_position {
+                let r = case_node.range();
+                if !position.in_range(Range {
+                    start: r.start_point,
+                    end: r.end_point,
+                }) {
+                    continue;
+                }
+            }
+            let mut name = format!("case_{:0width$}", index + 1, width = width);
+            if let Some(description) = description {
+                name.push('_');
+                name.push_str(&description);
+            }
+            runnables.push(Runnable {
+                name: format!("{}::{}", parent.name, name),
+                filepath: parent.filepath.clone(),
+                range: Range {
+                    start: CursorPosition::from_point(case_node.start_position()),
+                    end: CursorPosition::from_point(case_node.end_position()),
+                },
+                meta: RunnableMeta::default_rust(),
+            });
+        }
+
+        if runnables.is_empty() {
+            None
+        } else {
+            Some(runnables)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::op;
+    use crate::core::enums::Capability;
+    use crate::core::types::{Buffer, CursorPosition, Target};
+    use crate::framework::rust::operations::{cargotest_get_file_tests, parse_tree};
+
+    const CONTENT: &str = r#"
+        #[rstest]
+        #[case(1, 2)]
+        #[case::negative(-1, -2)]
+        #[case(3, 6)]
+        #[case(4, 8)]
+        #[case(5, 10)]
+        #[case(6, 12)]
+        #[case(7, 14)]
+        #[case(8, 16)]
+        #[case(9, 18)]
+        #[case::last(10, 20)]
+        fn doubles(#[case] input: i32, #[case] expected: i32) {
+            assert_eq!(expected, input * 2);
+        }
+        "#;
+
+    #[gtest]
+    fn expands_all_cases() {
+        let tree = parse_tree::op::execute(CONTENT).unwrap();
+        let buffer = Buffer::new(CONTENT, "lib.rs".to_string(), CursorPosition::new(0, 0));
+        let target = Target::new(Capability::TestRunner, buffer);
+        let parent = cargotest_get_file_tests::op::execute(tree.root_node(), &target)
+            .unwrap()
+            .remove(0);
+
+        let res = op::execute(tree.root_node(), CONTENT, &parent, None);
+
+        assert_that!(res, some(anything()));
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        assert_that!(names.len(), eq(10));
+        assert_that!(names[0], eq("doubles::case_01"));
+        assert_that!(names[1], eq("doubles::case_02_negative"));
+        assert_that!(names[2], eq("doubles::case_03"));
+        assert_that!(names[9], eq("doubles::case_10_last"));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(CursorPosition::new(3, 10), Some("doubles::case_02_negative"))]
+    #[case(CursorPosition::new(11, 10), Some("doubles::case_10_last"))]
+    #[case(CursorPosition::new(13, 10), None)]
+    fn case_under_cursor(#[case] cursor_position: CursorPosition, #[case] expected: Option<&str>) {
+        let tree = parse_tree::op::execute(CONTENT).unwrap();
+        let buffer = Buffer::new(CONTENT, "lib.rs".to_string(), cursor_position);
+        let target = Target::new(Capability::TestRunner, buffer);
+        let parent = cargotest_get_file_tests::op::execute(tree.root_node(), &target)
+            .unwrap()
+            .remove(0);
+
+        let res = op::execute(tree.root_node(), CONTENT, &parent, Some(cursor_position));
+
+        let name = res.and_then(|r| r.first().map(|r| r.name.clone()));
+        assert_that!(name.as_deref(), eq(expected));
+    }
+
+    #[gtest]
+    fn rstest_without_cases() {
+        let content = r#"
+        #[rstest]
+        fn uses_fixture(value: i32) {
+            #[allow(unused)]
+            let x = value;
+        }
+        "#;
+        let tree = parse_tree::op::execute(content).unwrap();
+        let buffer = Buffer::new(content, "lib.rs".to_string(), CursorPosition::new(0, 0));
+        let target = Target::new(Capability::TestRunner, buffer);
+        let parent = cargotest_get_file_tests::op::execute(tree.root_node(), &target)
+            .unwrap()
+            .remove(0);
+
+        assert_that!(
+            op::execute(tree.root_node(), content, &parent, None),
+            none()
+        );
+    }
+}
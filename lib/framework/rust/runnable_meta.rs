@@ -0,0 +1,44 @@
+use crate::core::metadata::{CargoTarget, RunnableMeta};
+
+impl RunnableMeta {
+    pub(in crate::framework::rust) fn set_exact(&mut self, value: bool) {
+        if let RunnableMeta::Rust { exact, .. } = self {
+            *exact = value;
+        }
+    }
+
+    pub(in crate::framework::rust) fn set_cargo_context(
+        &mut self,
+        package_name: String,
+        cargo_target: CargoTarget,
+    ) {
+        if let RunnableMeta::Rust {
+            package, target, ..
+        } = self
+        {
+            *package = package_name;
+            *target = cargo_target;
+        }
+    }
+
+    pub(in crate::framework::rust) fn get_cargo_meta(&self) -> Option<Meta> {
+        match self {
+            RunnableMeta::Rust {
+                package,
+                target,
+                exact,
+            } => Some(Meta {
+                package: package.clone(),
+                target: target.clone(),
+                exact: *exact,
+            }),
+            _ => None,
+        }
+    }
+}
+
+pub(in crate::framework::rust) struct Meta {
+    pub(in crate::framework::rust) package: String,
+    pub(in crate::framework::rust) target: CargoTarget,
+    pub(in crate::framework::rust) exact: bool,
+}
@@ -0,0 +1,27 @@
+// query
+//
+// Finds every function item in a rust file. Whether the function is a test
+// is decided by the attributes preceding it, which tree-sitter-rust emits
+// as siblings of the function rather than children, so they are inspected
+// after the match.
+//
+// Example:
+// #[cfg(test)]
+// mod test {
+//     #[test]
+//     fn adds() {
+//         assert_eq!(2, 1 + 1);
+//     }
+// }
+//
+// This will find the following functions:
+// - adds
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((function_item
+                    name: (identifier) @test_name
+                    body: (block)) @testfunc)]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,23 @@
+// query
+//
+// Finds inline module blocks, the unit run when the cursor sits inside a
+// test module but outside of any test function.
+//
+// Example:
+// #[cfg(test)]
+// mod test {
+//     #[test]
+//     fn adds() {}
+// }
+//
+// This will find the following modules:
+// - test
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((mod_item
+                    name: (identifier) @module_name
+                    body: (declaration_list)) @module)]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,3 @@
+pub(crate) mod cargotest_test_function;
+pub(crate) mod cargotest_test_module;
+pub(crate) mod rstest_case_attribute;
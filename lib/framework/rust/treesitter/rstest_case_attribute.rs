@@ -0,0 +1,26 @@
+// query
+//
+// Finds attributes that may declare an rstest case, both the positional
+// `#[case(...)]` form and the described `#[case::name(...)]` form.
+//
+// Example:
+// #[rstest]
+// #[case(1, 2)]
+// #[case::negative(-1, -2)]
+// fn doubles(#[case] input: i32, #[case] expected: i32) {
+//     assert_eq!(expected, input * 2);
+// }
+//
+// This will find the following cases:
+// - case(1, 2)
+// - case::negative(-1, -2)
+pub(crate) fn query() -> String {
+    let res = r#"
+            [[((attribute_item
+                    (attribute
+                        [(identifier) (scoped_identifier)] @test.case.path
+                        arguments: (token_tree))) @test.case)]]
+            "#;
+
+    res.to_string()
+}
@@ -0,0 +1,4 @@
+pub mod provider;
+#[cfg(test)]
+mod provider_test;
+pub(crate) mod runnable_meta;
@@ -0,0 +1,367 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use mlua::{Function, Lua, LuaSerdeExt, SerializeOptions, Table, Value, WeakLua};
+
+use crate::core::enums::Language as crate_language;
+use crate::core::enums::Search;
+use crate::core::errors::FrameworkError;
+use crate::core::metadata::RunnableMeta;
+use crate::core::types::Command;
+use crate::core::types::CursorPosition;
+use crate::core::types::Detection;
+use crate::core::types::Environment;
+use crate::core::types::Runnable;
+use crate::core::types::Target;
+use crate::core::{
+    enums::Capability,
+    traits::{Framework, FrameworkProvider},
+    types::CapabilityDetails,
+};
+
+// ScriptedProvider
+//
+// A framework implemented in lua, registered from a table such as
+//
+// {
+//   name = "Minitest",
+//   language = "ruby",            -- or a list, { "javascript", "typescript" }
+//   capability = "test",          -- "test" (default) or "debug"
+//   detect = function(target) return target.filepath:match("_test%.rb$") ~= nil end,
+//   runnables = function(target)
+//     return { { name = "test_adds", range = { start = { row = 3, col = 2 } }, meta = { line = 4 } } }
+//   end,
+//   generate_command = function(runnable)
+//     return { command = "ruby", args = { "-Itest", runnable.filepath, "-n", runnable.name },
+//              cwd = "test", env = { SEED = "1" } }
+//   end,
+// }
+//
+// Callbacks receive the target as `{ category, content, filepath,
+// position = { row, col }, search }`, `search` being one of "nearest",
+// "method" or "file". The `meta` of a runnable is kept as plain data and
+// handed back to `generate_command`. The state is held weakly, the
+// engine holding the provider being itself owned by that state.
+pub struct ScriptedProvider {
+    lua: WeakLua,
+    name: String,
+    languages: Vec<crate_language>,
+    capability: Capability,
+    detect: Function,
+    runnables: Function,
+    generate_command: Function,
+    search_capabilities: HashSet<CapabilityDetails>,
+}
+
+impl ScriptedProvider {
+    pub fn new(lua: &Lua, spec: Table) -> Result<Self, FrameworkError> {
+        let name: String = spec
+            .get::<Option<String>>("name")
+            .ok()
+            .flatten()
+            .filter(|n| !n.trim().is_empty())
+            .ok_or_else(|| {
+                FrameworkError::PreconditionError(
+                    "lua framework must define a non empty `name`".to_string(),
+                )
+            })?;
+        let error = |details: String| {
+            FrameworkError::PreconditionError(format!("lua framework `{}`: {}", name, details))
+        };
+
+        let languages = match spec.get::<Value>("language") {
+            Ok(Value::String(language)) => vec![language.to_string_lossy()],
+            Ok(Value::Table(languages)) => languages
+                .sequence_values::<String>()
+                .collect::<mlua::Result<Vec<String>>>()
+                .map_err(|e| error(format!("invalid `language`: {}", e)))?,
+            _ => vec![],
+        };
+        if languages.is_empty() {
+            return Err(error("`language` must name a language".to_string()));
+        }
+        let languages = languages
+            .iter()
+            .map(|l| {
+                crate_language::from_str(l)
+                    .map_err(|_| error(format!("unknown `language` `{}`", l)))
+            })
+            .collect::<Result<Vec<crate_language>, FrameworkError>>()?;
+
+        let capability = match spec.get::<Option<String>>("capability") {
+            Ok(None) => Capability::TestRunner,
+            Ok(Some(c)) if c == "test" => Capability::TestRunner,
+            Ok(Some(c)) if c == "debug" => Capability::Debugger,
+            Ok(Some(c)) => return Err(error(format!("unknown `capability` `{}`", c))),
+            Err(e) => return Err(error(format!("invalid `capability`: {}", e))),
+        };
+        let callback = |field: &str| {
+            spec.get::<Function>(field)
+                .map_err(|_| error(format!("`{}` must be a function", field)))
+        };
+        let detect = callback("detect")?;
+        let runnables = callback("runnables")?;
+        let generate_command = callback("generate_command")?;
+
+        let prefix = match capability {
+            Capability::TestRunner => "Test",
+            Capability::Debugger => "Debug",
+        };
+        let mut res = HashSet::with_capacity(3);
+        res.insert(CapabilityDetails {
+            capability: capability.clone(),
+            search: Search::Nearest,
+            description: format!("{} Nearest", prefix),
+        });
+        res.insert(CapabilityDetails {
+            capability: capability.clone(),
+            search: Search::Method,
+            description: format!("{} Function", prefix),
+        });
+        res.insert(CapabilityDetails {
+            capability: capability.clone(),
+            search: Search::File,
+            description: format!("{} File", prefix),
+        });
+
+        Ok(Self {
+            lua: lua.weak(),
+            name,
+            languages,
+            capability,
+            detect,
+            runnables,
+            generate_command,
+            search_capabilities: res,
+        })
+    }
+
+    fn error(&self, callback: &str, e: mlua::Error) -> FrameworkError {
+        FrameworkError::UnknownError(format!(
+            "lua framework `{}` failed in `{}`: {}",
+            self.name, callback, e
+        ))
+    }
+
+    fn lua(&self) -> mlua::Result<Lua> {
+        self.lua
+            .try_upgrade()
+            .ok_or_else(|| mlua::Error::runtime("the lua state is gone"))
+    }
+
+    fn target_table(&self, target: &Target) -> mlua::Result<Table> {
+        let res = self.lua()?.create_table()?;
+        let category = match target.category {
+            Capability::TestRunner => "test",
+            Capability::Debugger => "debug",
+        };
+        let search = match target.search_strategy {
+            Search::Nearest => "nearest",
+            Search::Method => "method",
+            Search::File => "file",
+        };
+        res.set("category", category)?;
+        res.set("content", target.buffer.content)?;
+        res.set("filepath", target.buffer.filepath.as_str())?;
+        res.set("position", self.position_table(target.buffer.position)?)?;
+        res.set("search", search)?;
+        Ok(res)
+    }
+
+    fn position_table(&self, position: CursorPosition) -> mlua::Result<Table> {
+        let res = self.lua()?.create_table()?;
+        res.set("row", position.row)?;
+        res.set("col", position.col)?;
+        Ok(res)
+    }
+
+    fn runnable_table(&self, runnable: &Runnable) -> mlua::Result<Table> {
+        let lua = self.lua()?;
+        let res = lua.create_table()?;
+        res.set("name", runnable.name.as_str())?;
+        res.set("filepath", runnable.filepath.as_str())?;
+        let range = lua.create_table()?;
+        range.set("start", self.position_table(runnable.range.start)?)?;
+        range.set("end", self.position_table(runnable.range.end)?)?;
+        res.set("range", range)?;
+        let data = runnable.meta.get_scripted_data().unwrap_or_default();
+        let options = SerializeOptions::new()
+            .serialize_none_to_null(false)
+            .serialize_unit_to_null(false);
+        res.set("meta", lua.to_value_with(&data, options)?)?;
+        Ok(res)
+    }
+
+    fn runnable(&self, value: Table, target: &Target) -> mlua::Result<Runnable> {
+        let name: String = value.get("name")?;
+        let filepath = value
+            .get::<Option<String>>("filepath")?
+            .unwrap_or_else(|| target.buffer.filepath.to_string());
+        let position = |table: Option<Table>| -> mlua::Result<Option<CursorPosition>> {
+            match table {
+                Some(t) => Ok(Some(CursorPosition::new(t.get("row")?, t.get("col")?))),
+                None => Ok(None),
+            }
+        };
+        let range: Option<Table> = value.get("range")?;
+        let (start, end) = match range {
+            Some(range) => (position(range.get("start")?)?, position(range.get("end")?)?),
+            None => (None, None),
+        };
+        let start = start.unwrap_or(target.buffer.position);
+        let end = end.unwrap_or(start);
+
+        let mut meta = RunnableMeta::default_scripted();
+        let data: Value = value.get("meta")?;
+        if !data.is_nil() {
+            meta.set_scripted_data(self.lua()?.from_value(data)?);
+        }
+        Ok(Runnable {
+            name,
+            filepath,
+            range: Range { start, end },
+            meta,
+        })
+    }
+}
+
+impl FrameworkProvider for ScriptedProvider {
+    fn create(&self) -> Box<dyn Framework> {
+        Box::new(ScriptedProvider {
+            lua: self.lua.clone(),
+            name: self.name.clone(),
+            languages: self.languages.clone(),
+            capability: self.capability.clone(),
+            detect: self.detect.clone(),
+            runnables: self.runnables.clone(),
+            generate_command: self.generate_command.clone(),
+            search_capabilities: self.search_capabilities.clone(),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn language(&self) -> crate_language {
+        self.languages[0].clone()
+    }
+
+    fn languages(&self) -> Vec<crate_language> {
+        self.languages.clone()
+    }
+
+    fn capability(&self) -> Capability {
+        self.capability.clone()
+    }
+
+    // priority
+    //
+    // Lua frameworks are registered for the project at hand and rank above
+    // the built-in frameworks detecting the same file.
+    fn priority(&self) -> u8 {
+        10
+    }
+}
+
+impl Framework for ScriptedProvider {
+    fn detect(&self, target: &Target) -> bool {
+        self.detection(target).matched
+    }
+
+    // detection
+    //
+    // A failing callback rejects the file, its error as the reason.
+    fn detection(&self, target: &Target) -> Detection {
+        if target.category != self.capability {
+            return Detection::rejected("capability not requested");
+        }
+        match self
+            .target_table(target)
+            .and_then(|t| self.detect.call::<Option<bool>>(t))
+        {
+            Ok(Some(true)) => Detection::matched(50, "`detect` matched the file"),
+            Ok(_) => Detection::rejected("`detect` rejected the file"),
+            Err(e) => Detection::rejected(format!("`detect` failed: {}", e)),
+        }
+    }
+
+    // runnables
+    //
+    // The callback returns a list of runnables, or `nil, message` when none
+    // can be found.
+    fn runnables(&self, target: &Target) -> Result<Vec<Runnable>, FrameworkError> {
+        let table = self
+            .target_table(target)
+            .map_err(|e| self.error("runnables", e))?;
+        let (runnables, message) = self
+            .runnables
+            .call::<(Option<Table>, Option<String>)>(table)
+            .map_err(|e| self.error("runnables", e))?;
+        let Some(runnables) = runnables else {
+            return Err(FrameworkError::NotFoundError(message.unwrap_or_else(
+                || format!("{} test not found at position", self.name),
+            )));
+        };
+
+        let res = runnables
+            .sequence_values::<Table>()
+            .map(|r| r.and_then(|r| self.runnable(r, target)))
+            .collect::<mlua::Result<Vec<Runnable>>>()
+            .map_err(|e| self.error("runnables", e))?;
+        if res.is_empty() {
+            return Err(FrameworkError::NotFoundError(message.unwrap_or_else(
+                || format!("{} test not found at position", self.name),
+            )));
+        }
+        Ok(res)
+    }
+
+    // generate_command
+    //
+    // A failing callback yields an empty command, see
+    // `try_generate_command` for its error.
+    fn generate_command(&self, runnable: Runnable) -> Command {
+        self.try_generate_command(runnable).unwrap_or_default()
+    }
+
+    // try_generate_command
+    //
+    // The callback returns `{ command, args, cwd, env }`, `cwd` and `env`
+    // being optional.
+    fn try_generate_command(&self, runnable: Runnable) -> Result<Command, FrameworkError> {
+        let res = self.runnable_table(&runnable).and_then(|t| {
+            let command: Table = self.generate_command.call(t)?;
+            let mut env = Environment::default();
+            if let Some(vars) = command.get::<Option<Table>>("env")? {
+                for pair in vars.pairs::<String, String>() {
+                    let (name, value) = pair?;
+                    env.set(&name, &value);
+                }
+            }
+            Ok(Command {
+                command: command.get("command")?,
+                args: command
+                    .get::<Option<Vec<String>>>("args")?
+                    .unwrap_or_default(),
+                cwd: command.get::<Option<String>>("cwd")?.map(PathBuf::from),
+                env,
+            })
+        });
+        res.map_err(|e| self.error("generate_command", e))
+    }
+
+    fn capabilities(&self) -> HashSet<CapabilityDetails> {
+        self.search_capabilities.clone()
+    }
+
+    fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+        let capabilities = self.capabilities();
+        capabilities
+            .iter()
+            .find(|&s| s.description == description)
+            .cloned()
+    }
+}
@@ -0,0 +1,342 @@
+#[cfg(test)]
+mod test {
+    use mlua::{Lua, Table};
+
+    use crate::core::enums;
+    use crate::core::errors::FrameworkError;
+    use crate::core::traits::{Framework, FrameworkProvider};
+    use crate::{
+        core::types::{self, Buffer, Target},
+        framework::scripted::provider,
+    };
+    use googletest::assert_that;
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    const CONTENT: &str = r#"require "minitest/autorun"
+
+class CalculatorTest < Minitest::Test
+  def test_adds
+    assert_equal 2, Calculator.new.add(1, 1)
+  end
+end
+"#;
+
+    const SPEC: &str = r#"
+return {
+  name = "Minitest",
+  language = "ruby",
+  detect = function(target)
+    return target.filepath:match("_test%.rb$") ~= nil
+  end,
+  runnables = function(target)
+    if target.search == "file" then
+      return { { name = target.filepath, meta = { file = true } } }
+    end
+    local row = 0
+    for line in target.content:gmatch("([^\n]*)\n") do
+      local name = line:match("def (test_[%w_]+)")
+      if name and row <= target.position.row then
+        return {
+          {
+            name = name,
+            range = { start = { row = row, col = 2 }, ["end"] = { row = row + 2, col = 5 } },
+            meta = { line = row + 1 },
+          },
+        }
+      end
+      row = row + 1
+    end
+    return nil, "no minitest method above the cursor"
+  end,
+  generate_command = function(runnable)
+    local args = { "-Itest", runnable.filepath }
+    if runnable.meta.line then
+      table.insert(args, "-n")
+      table.insert(args, runnable.name)
+    end
+    return { command = "ruby", args = args }
+  end,
+}
+"#;
+
+    fn provider(lua: &Lua) -> provider::ScriptedProvider {
+        let spec: Table = lua.load(SPEC).eval().unwrap();
+        provider::ScriptedProvider::new(lua, spec).unwrap()
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Search::Nearest, types::CursorPosition::new(4, 4), "test_adds")]
+    #[case(
+        enums::Search::File,
+        types::CursorPosition::new(4, 4),
+        "test/calculator_test.rb"
+    )]
+    fn get_tests(
+        #[case] search: enums::Search,
+        #[case] position: types::CursorPosition,
+        #[case] expected_test_name: &str,
+    ) {
+        // arrange
+        let lua = Lua::new();
+        let buffer = Buffer::new(CONTENT, "test/calculator_test.rb".to_string(), position);
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = provider(&lua);
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        let names: Vec<String> = res.unwrap().into_iter().map(|r| r.name).collect();
+        assert_that!(names, container_eq(vec![expected_test_name.to_string()]));
+    }
+
+    #[gtest]
+    fn test_not_found_at_position() {
+        // arrange
+        let lua = Lua::new();
+        let buffer = Buffer::new(
+            CONTENT,
+            "test/calculator_test.rb".to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let provider = provider(&lua);
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(
+            res,
+            err(eq(&FrameworkError::NotFoundError(
+                "no minitest method above the cursor".to_string()
+            )))
+        )
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        enums::Search::Nearest,
+        vec!["-Itest", "test/calculator_test.rb", "-n", "test_adds"]
+    )]
+    #[case(enums::Search::File, vec!["-Itest", "test/calculator_test.rb"])]
+    fn generate_command(#[case] search: enums::Search, #[case] expected_args: Vec<&str>) {
+        // arrange
+        let lua = Lua::new();
+        let buffer = Buffer::new(
+            CONTENT,
+            "test/calculator_test.rb".to_string(),
+            types::CursorPosition::new(4, 4),
+        );
+        let mut target = Target::new(enums::Capability::TestRunner, buffer);
+        target.override_search_strategy(search);
+        let provider = provider(&lua);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let cmd = provider.generate_command(runnable);
+
+        // assert
+        assert_that!(cmd.command, eq("ruby"));
+        let expected: Vec<String> = expected_args.iter().map(|a| a.to_string()).collect();
+        assert_that!(cmd.args, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(enums::Capability::TestRunner, "test/calculator_test.rb", true)]
+    #[case(enums::Capability::Debugger, "test/calculator_test.rb", false)]
+    #[case(enums::Capability::TestRunner, "lib/calculator.rb", false)]
+    fn detect(
+        #[case] capability: enums::Capability,
+        #[case] filepath: &str,
+        #[case] expected: bool,
+    ) {
+        let lua = Lua::new();
+        let buffer = Buffer::new(
+            CONTENT,
+            filepath.to_string(),
+            types::CursorPosition::new(4, 4),
+        );
+        let target = Target::new(capability, buffer);
+        let provider = provider(&lua);
+
+        assert_that!(provider.detect(&target), eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("return { language = 'ruby' }", "non empty `name`")]
+    #[case(
+        "return { name = 'Spec', language = 'cobol' }",
+        "unknown `language` `cobol`"
+    )]
+    #[case(
+        "return { name = 'Spec', language = 'ruby', capability = 'bench' }",
+        "unknown `capability` `bench`"
+    )]
+    #[case(
+        "return { name = 'Spec', language = { 'javascript', 'typescript' }, detect = function() end }",
+        "`runnables` must be a function"
+    )]
+    fn rejects_invalid_spec(#[case] spec: &str, #[case] expected: &str) {
+        // arrange
+        let lua = Lua::new();
+        let spec: Table = lua.load(spec).eval().unwrap();
+
+        // act
+        let res = provider::ScriptedProvider::new(&lua, spec);
+
+        // assert
+        assert_that!(
+            res.err(),
+            some(matches_pattern!(FrameworkError::PreconditionError(
+                contains_substring(expected)
+            )))
+        );
+    }
+
+    #[gtest]
+    fn reports_callback_errors() {
+        // arrange
+        let lua = Lua::new();
+        let spec: Table = lua
+            .load(
+                r#"return {
+                  name = "Broken",
+                  language = { "javascript", "typescript" },
+                  detect = function() return true end,
+                  runnables = function() error("boom") end,
+                  generate_command = function() return { command = "true" } end,
+                }"#,
+            )
+            .eval()
+            .unwrap();
+        let provider = provider::ScriptedProvider::new(&lua, spec).unwrap();
+        let buffer = Buffer::new(
+            "",
+            "app.test.ts".to_string(),
+            types::CursorPosition::new(0, 0),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+
+        // act
+        let res = provider.runnables(&target);
+
+        // assert
+        assert_that!(
+            provider.languages(),
+            elements_are![
+                eq(&enums::Language::JavaScript),
+                eq(&enums::Language::TypeScript)
+            ]
+        );
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::UnknownError(
+                contains_substring("lua framework `Broken` failed in `runnables`")
+            )))
+        );
+    }
+
+    #[gtest]
+    fn detection_reports_detect_errors() {
+        // arrange
+        let lua = Lua::new();
+        let spec: Table = lua
+            .load(
+                r#"return {
+                  name = "Broken",
+                  language = "ruby",
+                  detect = function() error("boom") end,
+                  runnables = function() return {} end,
+                  generate_command = function() return { command = "true" } end,
+                }"#,
+            )
+            .eval()
+            .unwrap();
+        let provider = provider::ScriptedProvider::new(&lua, spec).unwrap();
+        let buffer = Buffer::new(
+            CONTENT,
+            "test/calculator_test.rb".to_string(),
+            types::CursorPosition::new(4, 4),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+
+        // act
+        let res = provider.detection(&target);
+
+        // assert
+        assert_that!(res.matched, eq(false));
+        assert_that!(res.reason, starts_with("`detect` failed: "));
+        assert_that!(res.reason, contains_substring("boom"));
+        assert_that!(provider.detect(&target), eq(false));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(
+        r#"function(runnable)
+          return { command = "ruby", args = { runnable.filepath }, cwd = "test", env = { SEED = "1" } }
+        end"#,
+        Ok(("ruby", Some("test"), Some("1")))
+    )]
+    #[case(
+        r#"function() return { command = "ruby" } end"#,
+        Ok(("ruby", None, None))
+    )]
+    #[case(r#"function() error("no ruby") end"#, Err("no ruby"))]
+    fn try_generate_command(
+        #[case] callback: &str,
+        #[case] expected: std::result::Result<(&str, Option<&str>, Option<&str>), &str>,
+    ) {
+        // arrange
+        let lua = Lua::new();
+        let spec: Table = lua
+            .load(format!(
+                r#"return {{
+                  name = "Minitest",
+                  language = "ruby",
+                  detect = function() return true end,
+                  runnables = function() return {{ {{ name = "test_adds" }} }} end,
+                  generate_command = {},
+                }}"#,
+                callback
+            ))
+            .eval()
+            .unwrap();
+        let provider = provider::ScriptedProvider::new(&lua, spec).unwrap();
+        let buffer = Buffer::new(
+            CONTENT,
+            "test/calculator_test.rb".to_string(),
+            types::CursorPosition::new(4, 4),
+        );
+        let target = Target::new(enums::Capability::TestRunner, buffer);
+        let runnable = provider.runnables(&target).unwrap().remove(0);
+
+        // act
+        let res = provider.try_generate_command(runnable);
+
+        // assert
+        match expected {
+            Ok((command, cwd, seed)) => {
+                let cmd = res.unwrap();
+                assert_that!(cmd.command, eq(command));
+                assert_that!(cmd.cwd, eq(&cwd.map(std::path::PathBuf::from)));
+                assert_that!(cmd.env.set.get("SEED").map(String::as_str), eq(seed));
+            }
+            Err(message) => assert_that!(
+                res,
+                err(matches_pattern!(FrameworkError::UnknownError(all!(
+                    contains_substring("failed in `generate_command`"),
+                    contains_substring(message)
+                ))))
+            ),
+        }
+    }
+}
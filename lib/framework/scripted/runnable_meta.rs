@@ -0,0 +1,16 @@
+use crate::core::metadata::RunnableMeta;
+
+impl RunnableMeta {
+    pub(in crate::framework::scripted) fn set_scripted_data(&mut self, value: serde_json::Value) {
+        if let RunnableMeta::Scripted { data } = self {
+            *data = value;
+        }
+    }
+
+    pub(in crate::framework::scripted) fn get_scripted_data(&self) -> Option<serde_json::Value> {
+        if let RunnableMeta::Scripted { data } = self {
+            return Some(data.clone());
+        }
+        None
+    }
+}
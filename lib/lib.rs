@@ -1,5 +1,19 @@
+use mlua::{Lua, Table};
+
 mod core;
 mod engine;
 mod framework;
+mod lua;
+mod overseer;
 mod terminal;
 mod treesitter;
+mod watch;
+
+// examen
+//
+// Entry point of `require("examen")`, see `lua::module`.
+#[cfg_attr(feature = "module", mlua::lua_module)]
+pub fn examen(lua: &Lua) -> mlua::Result<Table> {
+    let engine = engine::Engine::initialize(lua::runtime_paths(lua)?);
+    lua::module(lua, engine)
+}
@@ -0,0 +1,857 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+use mlua::{Function, Lua, Table};
+
+use crate::core::enums::TestStatus;
+use crate::core::errors::ExecutionError;
+use crate::core::trust::TrustEntry;
+use crate::core::types::{CursorPosition, Runnable, TestResult};
+use crate::engine::{Engine, subject};
+use crate::terminal::executor::{ExecutorOptions, OutputLine, Stream};
+use crate::terminal::jobs::{JobId, JobStatus};
+use crate::watch;
+
+enum JobEvent {
+    Output(OutputLine),
+    Finished(Result<ExitStatus, ExecutionError>),
+}
+
+// JobCallback
+//
+// The callback of a run started from lua, along with what it runs, see
+// `subject`, so the results of the framework can be read once it finished.
+#[derive(Clone)]
+struct JobCallback {
+    id: JobId,
+    callback: Function,
+    framework: String,
+    runnable: Runnable,
+}
+
+// Jobs
+//
+// The callbacks of the runs started from lua, until they finish. Runs are
+// keyed by the order they were started in, as a job may finish before
+// `submit` returns its id.
+struct Jobs {
+    next_key: u64,
+    callbacks: BTreeMap<u64, JobCallback>,
+    sender: Sender<(u64, JobEvent)>,
+    events: Receiver<(u64, JobEvent)>,
+}
+
+// module
+//
+// The lua api of examen:
+//
+// local examen = require("examen")
+// examen.register_framework(spec)      -- see `ScriptedProvider`
+// examen.overseer_templates(filepath)  -- see `TemplateProvider`
+// examen.explain(filepath)             -- { { framework = "GoTest", matched = true, reason = ... } },
+//                                      -- ranked as `Engine::explain` ranks them
+// local task = examen.overseer_task({
+//   framework = "GoTest",
+//   capability = "Test Nearest",
+//   filepath = "pkg/calc/calc_test.go",
+//   cursor = { 12, 4 },                -- as `nvim_win_get_cursor` returns it
+// })                                   -- see `TaskDefinition`, nil without a test, running
+//                                      -- every test the search finds, e.g. for "Test File"
+// examen.debug_configuration({         -- as `overseer_task` with `framework = "Delve"`, the
+//   ...,                               -- nvim-dap launch configuration, see `LaunchConfiguration`
+// })
+// local id = examen.run({               -- as `overseer_task`, nil without a test
+//   ...,
+//   timeout_ms = 60000,
+// }, function(event) end)              -- { job = id, kind = "output", stream = "stdout", line = ... }
+//                                      -- { job = id, kind = "finished", code = 0, results = { ... } },
+//                                      -- `results` as the framework reports them, see `Framework::results`
+// examen.jobs()                        -- { { id = id, status = "running", runnable_id = ..., command = ... } }
+// examen.cancel(id)
+// examen.cancel_all()
+// examen.poll()
+// examen.watch                         -- see `watch::lua::module`
+// examen.touched_tests(filepath, "main") -- see `TouchedTests`
+// examen.affected_tests({              -- see `AffectedTests`
+//   filepath = "pkg/calc/calc.go",
+//   base = "main",                     -- the working tree against HEAD when nil
+// })
+// examen.trust                         -- see `trust_module`
+//
+// Frameworks registered from lua flow through the same capabilities and
+// commands as the built in ones. Jobs run in threads of their own while lua
+// may only be called from the editor's, so their events wait until `poll`,
+// driven by a timer, hands them to the callbacks.
+pub(crate) fn module(lua: &Lua, engine: Engine) -> mlua::Result<Table> {
+    let engine = Rc::new(RefCell::new(engine));
+    let res = lua.create_table()?;
+
+    let state = engine.clone();
+    res.set(
+        "register_framework",
+        lua.create_function(move |lua, spec: Table| {
+            state
+                .borrow_mut()
+                .register_lua_framework(lua, spec)
+                .map_err(mlua::Error::external)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "overseer_templates",
+        lua.create_function(move |lua, filepath: String| {
+            state.borrow().overseer_templates(&filepath).to_lua(lua)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "explain",
+        lua.create_function(move |lua, filepath: String| {
+            let res = lua.create_table()?;
+            for m in state.borrow().explain(&filepath) {
+                let table = lua.create_table()?;
+                table.set("framework", m.framework)?;
+                table.set("priority", m.priority)?;
+                table.set("matched", m.detection.matched)?;
+                table.set("confidence", m.detection.confidence)?;
+                table.set("reason", m.detection.reason)?;
+                res.push(table)?;
+            }
+            Ok(res)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "overseer_task",
+        lua.create_function(move |lua, table: Table| {
+            let engine = state.borrow();
+            let Some((framework, runnables)) = runnables(&engine, &table)? else {
+                return Ok(None);
+            };
+            engine
+                .overseer_task(&framework, runnables)
+                .map_err(mlua::Error::external)?
+                .to_lua(lua)
+                .map(Some)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "debug_configuration",
+        lua.create_function(move |lua, table: Table| {
+            let engine = state.borrow();
+            let Some((framework, runnables)) = runnables(&engine, &table)? else {
+                return Ok(None);
+            };
+            engine
+                .debug_configuration(&framework, runnables)
+                .map_err(mlua::Error::external)?
+                .to_lua_table(lua)
+                .map(Some)
+        })?,
+    )?;
+
+    let (sender, events) = mpsc::channel();
+    let jobs = Rc::new(RefCell::new(Jobs {
+        next_key: 0,
+        callbacks: BTreeMap::new(),
+        sender,
+        events,
+    }));
+
+    let state = engine.clone();
+    let callbacks = jobs.clone();
+    res.set(
+        "run",
+        lua.create_function(move |_, (table, callback): (Table, Function)| {
+            let engine = state.borrow();
+            let Some((framework, runnables)) = runnables(&engine, &table)? else {
+                return Ok(None);
+            };
+            let runnable = subject(&runnables).map_err(mlua::Error::external)?;
+            let options = ExecutorOptions {
+                timeout: table
+                    .get::<Option<u64>>("timeout_ms")?
+                    .map(Duration::from_millis),
+            };
+            let mut jobs = callbacks.borrow_mut();
+            jobs.next_key += 1;
+            let key = jobs.next_key;
+            let (lines, exits) = (jobs.sender.clone(), jobs.sender.clone());
+            let request = engine
+                .job(&framework, runnables)
+                .map_err(mlua::Error::external)?
+                .with_options(options)
+                .with_on_line(Box::new(move |line| {
+                    let _ = lines.send((key, JobEvent::Output(line)));
+                }))
+                .with_on_exit(Box::new(move |result| {
+                    let _ = exits.send((key, JobEvent::Finished(result)));
+                }));
+            let res = engine
+                .jobs()
+                .submit(request)
+                .map_err(mlua::Error::external)?;
+            jobs.callbacks.insert(
+                key,
+                JobCallback {
+                    id: res,
+                    callback,
+                    framework,
+                    runnable,
+                },
+            );
+            Ok(Some(res))
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "jobs",
+        lua.create_function(move |lua, ()| {
+            let res = lua.create_table()?;
+            for job in state.borrow().jobs().jobs() {
+                let table = lua.create_table()?;
+                table.set("id", job.id)?;
+                let status = match job.status {
+                    JobStatus::Queued => "queued",
+                    JobStatus::Running => "running",
+                };
+                table.set("status", status)?;
+                table.set("runnable_id", job.runnable_id)?;
+                table.set("package", job.package)?;
+                table.set("command", job.command)?;
+                table.set("pid", job.pid)?;
+                res.push(table)?;
+            }
+            Ok(res)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "cancel",
+        lua.create_function(move |_, id: JobId| Ok(state.borrow().jobs().cancel(id)))?,
+    )?;
+
+    res.set(
+        "watch",
+        watch::lua::module(lua, engine.borrow().jobs().clone())?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "touched_tests",
+        lua.create_function(move |lua, (filepath, base): (String, String)| {
+            state
+                .borrow()
+                .touched_tests(&filepath, &base)
+                .map_err(mlua::Error::external)?
+                .to_lua(lua)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "affected_tests",
+        lua.create_function(move |lua, table: Table| {
+            let filepath: String = table.get("filepath")?;
+            let base: Option<String> = table.get("base")?;
+            state
+                .borrow()
+                .affected_tests(&filepath, base.as_deref())
+                .map_err(mlua::Error::external)?
+                .to_lua(lua)
+        })?,
+    )?;
+
+    res.set("trust", trust_module(lua, engine.clone())?)?;
+
+    let state = engine.clone();
+    res.set(
+        "cancel_all",
+        lua.create_function(move |_, ()| Ok(state.borrow().jobs().cancel_all()))?,
+    )?;
+
+    let state = jobs;
+    res.set(
+        "poll",
+        lua.create_function(move |lua, ()| {
+            // callbacks may start jobs, they run once the state is released
+            let mut pending = vec![];
+            {
+                let mut jobs = state.borrow_mut();
+                let events: Vec<(u64, JobEvent)> = jobs.events.try_iter().collect();
+                for (key, event) in events {
+                    let finished = matches!(event, JobEvent::Finished(_));
+                    let callback = match finished {
+                        true => jobs.callbacks.remove(&key),
+                        false => jobs.callbacks.get(&key).cloned(),
+                    };
+                    let Some(job) = callback else {
+                        continue;
+                    };
+                    let table = event_table(lua, job.id, event)?;
+                    // a runner without a report has no results to add
+                    if finished
+                        && let Ok(results) = engine.borrow().results(&job.framework, &job.runnable)
+                        && !results.is_empty()
+                    {
+                        table.set("results", results_table(lua, &results)?)?;
+                    }
+                    pending.push((job.callback, table));
+                }
+            }
+            let res = pending.len();
+            for (callback, event) in pending {
+                callback.call::<()>(event)?;
+            }
+            Ok(res)
+        })?,
+    )?;
+    Ok(res)
+}
+
+// trust_module
+//
+// The lua api of the trust database, see `TrustDatabase`:
+//
+// examen.trust.list()              -- { { path = ..., hash = ... } }
+// examen.trust.allow(filepath)     -- { path = ..., hash = ... }
+// examen.trust.revoke(filepath)    -- whether the file was trusted
+fn trust_module(lua: &Lua, engine: Rc<RefCell<Engine>>) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+
+    let state = engine.clone();
+    res.set(
+        "list",
+        lua.create_function(move |lua, ()| {
+            let res = lua.create_table()?;
+            for entry in state.borrow().trusted() {
+                res.push(trust_entry_table(lua, &entry)?)?;
+            }
+            Ok(res)
+        })?,
+    )?;
+
+    let state = engine.clone();
+    res.set(
+        "allow",
+        lua.create_function(move |lua, filepath: String| {
+            let entry = state
+                .borrow_mut()
+                .allow(&filepath)
+                .map_err(mlua::Error::external)?;
+            trust_entry_table(lua, &entry)
+        })?,
+    )?;
+
+    let state = engine;
+    res.set(
+        "revoke",
+        lua.create_function(move |_, filepath: String| {
+            state
+                .borrow_mut()
+                .revoke(&filepath)
+                .map_err(mlua::Error::external)
+        })?,
+    )?;
+    Ok(res)
+}
+
+fn trust_entry_table(lua: &Lua, entry: &TrustEntry) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+    res.set("path", entry.path.display().to_string())?;
+    res.set("hash", entry.hash.as_str())?;
+    Ok(res)
+}
+
+// runnables
+//
+// The runnables the capability of the framework finds in the file at the
+// cursor, as `overseer_task` and `run` describe it, run all at once. None
+// when the search finds nothing.
+fn runnables(engine: &Engine, table: &Table) -> mlua::Result<Option<(String, Vec<Runnable>)>> {
+    let framework: String = table.get("framework")?;
+    let capability: String = table.get("capability")?;
+    let filepath: String = table.get("filepath")?;
+    let cursor = match table.get::<Option<Table>>("cursor")? {
+        Some(cursor) => {
+            CursorPosition::new(cursor.get::<usize>(1)?.saturating_sub(1), cursor.get(2)?)
+        }
+        None => CursorPosition::default(),
+    };
+    let runnables = engine
+        .find_runnables(&filepath, &capability, &framework, cursor)
+        .map_err(mlua::Error::external)?;
+    Ok((!runnables.is_empty()).then_some((framework, runnables)))
+}
+
+fn event_table(lua: &Lua, id: JobId, event: JobEvent) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+    res.set("job", id)?;
+    match event {
+        JobEvent::Output(line) => {
+            res.set("kind", "output")?;
+            let stream = match line.stream {
+                Stream::Stdout => "stdout",
+                Stream::Stderr => "stderr",
+            };
+            res.set("stream", stream)?;
+            res.set("line", line.line)?;
+        }
+        JobEvent::Finished(result) => {
+            res.set("kind", "finished")?;
+            match result {
+                Ok(status) => res.set("code", status.code())?,
+                Err(e) => res.set("error", e.to_string())?,
+            }
+        }
+    }
+    Ok(res)
+}
+
+fn results_table(lua: &Lua, results: &[TestResult]) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+    for result in results {
+        let table = lua.create_table()?;
+        table.set("name", result.name.as_str())?;
+        table.set("suite", result.suite.as_str())?;
+        let status = match result.status {
+            TestStatus::Passed => "passed",
+            TestStatus::Failed => "failed",
+            TestStatus::Skipped => "skipped",
+        };
+        table.set("status", status)?;
+        table.set("duration", result.duration)?;
+        table.set("message", result.message.as_deref())?;
+        res.push(table)?;
+    }
+    Ok(res)
+}
+
+// runtime_paths
+//
+// The runtime path of neovim, empty when lua runs elsewhere.
+pub(crate) fn runtime_paths(lua: &Lua) -> mlua::Result<Vec<PathBuf>> {
+    let Some(vim) = lua.globals().get::<Option<Table>>("vim")? else {
+        return Ok(vec![]);
+    };
+    let list: Function = vim.get::<Table>("api")?.get("nvim_list_runtime_paths")?;
+    Ok(list
+        .call::<Vec<String>>(())?
+        .into_iter()
+        .map(PathBuf::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::time::{Duration, Instant};
+
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use super::module;
+    use crate::core::config::ConfigSource;
+    use crate::core::git::test::{repository, write};
+    use crate::core::registry::FrameworkRegistry;
+    use crate::core::trust::TrustDatabase;
+    use crate::engine::Engine;
+    use crate::framework::golang::delve::DelveProvider;
+    use crate::framework::rust::cargotest::CargotestProvider;
+
+    const SPEC: &str = r#"
+        examen.register_framework({
+          name = "Scripted",
+          language = "go",
+          detect = function(target) return target.filepath:match("_test%.go$") ~= nil end,
+          runnables = function(target)
+            return { { name = "TestCalculator", range = { start = { row = 9, col = 0 } } } }
+          end,
+          generate_command = function(runnable)
+            return { command = "go", args = { "test", "-run", runnable.name } }
+          end,
+        })
+        "#;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/declarative/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    fn lua() -> Lua {
+        let lua = Lua::new();
+        let engine = Engine::new(FrameworkRegistry::new(), vec![], TrustDatabase::default());
+        lua.globals()
+            .set("examen", module(&lua, engine).unwrap())
+            .unwrap();
+        lua.globals()
+            .set("filepath", fixture("suite/pkg/calculator/suite_test.go"))
+            .unwrap();
+        lua
+    }
+
+    #[gtest]
+    fn runs_lua_frameworks_through_overseer() {
+        // arrange
+        let lua = lua();
+        lua.load(SPEC).exec().unwrap();
+
+        // act
+        let templates: Table = lua
+            .load("examen.overseer_templates(filepath)")
+            .eval()
+            .unwrap();
+        let task: Table = lua
+            .load(
+                r#"examen.overseer_task({
+                  framework = "Scripted",
+                  capability = "Test Nearest",
+                  filepath = filepath,
+                  cursor = { 10, 0 },
+                })"#,
+            )
+            .eval()
+            .unwrap();
+
+        // assert
+        let names: Vec<String> = templates
+            .get::<Vec<Table>>("templates")
+            .unwrap()
+            .iter()
+            .map(|t| t.get("name").unwrap())
+            .collect();
+        assert_that!(
+            names,
+            container_eq(vec![
+                "Scripted: Test File".to_string(),
+                "Scripted: Test Function".to_string(),
+                "Scripted: Test Nearest".to_string(),
+            ])
+        );
+        assert_that!(task.get::<String>("cmd").unwrap(), eq("go"));
+        assert_that!(
+            task.get::<Vec<String>>("args").unwrap(),
+            container_eq(vec![
+                "test".to_string(),
+                "-run".to_string(),
+                "TestCalculator".to_string(),
+            ])
+        );
+        assert_that!(
+            task.get::<Table>("metadata")
+                .unwrap()
+                .get::<String>("runnable_id")
+                .unwrap(),
+            ends_with("suite_test.go::TestCalculator")
+        );
+    }
+
+    #[gtest]
+    fn explains_detection_from_lua() {
+        // arrange
+        let lua = lua();
+        lua.load(SPEC).exec().unwrap();
+
+        // act
+        let res: Vec<Table> = lua.load("examen.explain(filepath)").eval().unwrap();
+
+        // assert
+        assert_that!(res.len(), eq(1));
+        assert_that!(res[0].get::<String>("framework").unwrap(), eq("Scripted"));
+        assert_that!(res[0].get::<bool>("matched").unwrap(), eq(true));
+        assert_that!(res[0].get::<u8>("priority").unwrap(), eq(10));
+        assert_that!(res[0].get::<String>("reason").unwrap(), not(eq("")));
+    }
+
+    #[gtest]
+    fn runs_jobs_from_lua() {
+        // arrange
+        let lua = lua();
+        lua.load(SPEC.replace(
+            r#"{ command = "go", args = { "test", "-run", runnable.name } }"#,
+            r#"{ command = "echo", args = { runnable.name } }"#,
+        ))
+        .exec()
+        .unwrap();
+
+        // act
+        lua.load(
+            r#"
+            events = {}
+            id = examen.run({
+              framework = "Scripted",
+              capability = "Test Nearest",
+              filepath = filepath,
+              cursor = { 10, 0 },
+            }, function(event) table.insert(events, event) end)
+            "#,
+        )
+        .exec()
+        .unwrap();
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_secs(10) {
+            lua.load("examen.poll()").exec().unwrap();
+            let events: Vec<Table> = lua.globals().get("events").unwrap();
+            if events
+                .last()
+                .is_some_and(|e| e.get::<String>("kind").unwrap() == "finished")
+            {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        // assert
+        let events: Vec<Table> = lua.globals().get("events").unwrap();
+        let kinds: Vec<String> = events.iter().map(|e| e.get("kind").unwrap()).collect();
+        assert_that!(
+            kinds,
+            container_eq(vec!["output".to_string(), "finished".to_string()])
+        );
+        assert_that!(
+            events[0].get::<String>("line").unwrap(),
+            eq("TestCalculator")
+        );
+        assert_that!(events[1].get::<i32>("code").unwrap(), eq(0));
+        assert_that!(
+            events[1].get::<u64>("job").unwrap(),
+            eq(lua.globals().get::<u64>("id").unwrap())
+        );
+        assert_that!(
+            lua.load("examen.jobs()").eval::<Vec<Table>>().unwrap(),
+            is_empty()
+        );
+        assert_that!(
+            lua.load("examen.cancel(id)").eval::<bool>().unwrap(),
+            eq(false)
+        );
+        assert_that!(
+            lua.load("examen.cancel_all()").eval::<usize>().unwrap(),
+            eq(0)
+        );
+    }
+
+    #[gtest]
+    fn trusts_project_files_from_lua() {
+        // arrange
+        let lua = lua();
+        lua.globals()
+            .set(
+                "definition",
+                fixture("suite/.examen/frameworks/ginkgo.toml"),
+            )
+            .unwrap();
+        let task = r#"examen.overseer_task({
+          framework = "Ginkgo",
+          capability = "Test Nearest",
+          filepath = filepath,
+          cursor = { 16, 3 },
+        }).cmd"#;
+        let untrusted = lua.load(task).eval::<String>();
+
+        // act
+        let entry: Table = lua.load("examen.trust.allow(definition)").eval().unwrap();
+        let trusted: String = lua.load(task).eval().unwrap();
+        let listed: Vec<Table> = lua.load("examen.trust.list()").eval().unwrap();
+        let revoked: bool = lua.load("examen.trust.revoke(definition)").eval().unwrap();
+
+        // assert
+        assert_that!(
+            untrusted.unwrap_err().to_string(),
+            contains_substring("unknown framework `Ginkgo`")
+        );
+        assert_that!(trusted, eq("./bin/ginkgo"));
+        assert_that!(
+            entry.get::<String>("path").unwrap(),
+            ends_with("suite/.examen/frameworks/ginkgo.toml")
+        );
+        assert_that!(entry.get::<String>("hash").unwrap().len(), eq(64));
+        assert_that!(listed.len(), eq(1));
+        assert_that!(revoked, eq(true));
+        assert_that!(
+            lua.load("examen.trust.list()")
+                .eval::<Vec<Table>>()
+                .unwrap(),
+            is_empty()
+        );
+        assert_that!(lua.load(task).eval::<String>(), err(anything()));
+    }
+
+    #[gtest]
+    fn lists_affected_tests_from_lua() {
+        // arrange
+        let lua = lua();
+        let repository = repository(
+            "affected-lua",
+            &[
+                ("go.mod", "module example.com/app\n"),
+                ("lib/lib.go", "package lib\n"),
+                (
+                    "web/web_test.go",
+                    "package web\n\nimport \"example.com/app/lib\"\n",
+                ),
+            ],
+        );
+        write(
+            &repository,
+            "lib/lib.go",
+            "package lib\n\nvar Changed = true\n",
+        );
+        lua.globals()
+            .set(
+                "changed",
+                repository.join("lib/lib.go").display().to_string(),
+            )
+            .unwrap();
+
+        // act
+        let res: Table = lua
+            .load("examen.affected_tests({ filepath = changed })")
+            .eval()
+            .unwrap();
+
+        // assert
+        assert_that!(
+            res.get::<Vec<String>>("packages").unwrap(),
+            container_eq(vec!["example.com/app/web".to_string()])
+        );
+        let command: Table = res.get("command").unwrap();
+        assert_that!(command.get::<String>("cmd").unwrap(), eq("go"));
+        assert_that!(
+            command.get::<Vec<String>>("args").unwrap(),
+            container_eq(vec!["test".to_string(), "example.com/app/web".to_string()])
+        );
+        let _ = fs::remove_dir_all(&repository);
+    }
+
+    #[gtest]
+    fn builds_debug_configurations_from_lua() {
+        // arrange
+        let lua = Lua::new();
+        let mut registry = FrameworkRegistry::new();
+        registry.register(Box::new(DelveProvider::with_config_source(
+            ConfigSource::default(),
+        )));
+        let engine = Engine::new(registry, vec![], TrustDatabase::default());
+        lua.globals()
+            .set("examen", module(&lua, engine).unwrap())
+            .unwrap();
+        lua.globals()
+            .set("filepath", fixture("suite/pkg/calculator/suite_test.go"))
+            .unwrap();
+
+        // act
+        let res: Table = lua
+            .load(
+                r#"examen.debug_configuration({
+                  framework = "Delve",
+                  capability = "Debug Nearest",
+                  filepath = filepath,
+                  cursor = { 11, 1 },
+                })"#,
+            )
+            .eval()
+            .unwrap();
+
+        // assert
+        assert_that!(res.get::<String>("type").unwrap(), eq("go"));
+        assert_that!(res.get::<String>("mode").unwrap(), eq("test"));
+        assert_that!(
+            res.get::<Vec<String>>("args").unwrap(),
+            container_eq(vec![
+                "-test.run".to_string(),
+                "^TestCalculator$".to_string()
+            ])
+        );
+    }
+
+    #[gtest]
+    fn runs_every_test_of_the_file_from_lua() {
+        // arrange
+        let lua = Lua::new();
+        let mut registry = FrameworkRegistry::new();
+        registry.register(Box::new(CargotestProvider::new()));
+        let engine = Engine::new(registry, vec![], TrustDatabase::default());
+        lua.globals()
+            .set("examen", module(&lua, engine).unwrap())
+            .unwrap();
+        let filepath = format!(
+            "{}/lib/fixtures/rust/workspace/crates/alpha/tests/arithmetic.rs",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        lua.globals().set("filepath", filepath.as_str()).unwrap();
+
+        // act
+        let task: Table = lua
+            .load(
+                r#"examen.overseer_task({
+                  framework = "CargoTest",
+                  capability = "Test File",
+                  filepath = filepath,
+                })"#,
+            )
+            .eval()
+            .unwrap();
+
+        // assert
+        assert_that!(task.get::<String>("cmd").unwrap(), eq("cargo"));
+        assert_that!(
+            task.get::<Vec<String>>("args").unwrap(),
+            container_eq(vec![
+                "test".to_string(),
+                "-p".to_string(),
+                "alpha".to_string(),
+                "--test".to_string(),
+                "arithmetic".to_string(),
+                "--".to_string(),
+                "adds".to_string(),
+                "adds_negatives".to_string(),
+                "adds_zero::case_1".to_string(),
+                "adds_zero::case_2".to_string(),
+                "--exact".to_string(),
+            ])
+        );
+        assert_that!(
+            task.get::<String>("name").unwrap(),
+            eq(&format!("CargoTest: {}", filepath))
+        );
+    }
+
+    #[gtest]
+    fn rejects_unknown_framework() {
+        // arrange
+        let lua = lua();
+
+        // act
+        let res = lua
+            .load(
+                r#"examen.overseer_task({
+                  framework = "Unknown",
+                  capability = "Test Nearest",
+                  filepath = filepath,
+                })"#,
+            )
+            .exec();
+
+        // assert
+        assert_that!(
+            res.unwrap_err().to_string(),
+            contains_substring("unknown framework `Unknown`")
+        );
+    }
+}
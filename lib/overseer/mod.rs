@@ -0,0 +1,2 @@
+pub(crate) mod task;
+pub(crate) mod template;
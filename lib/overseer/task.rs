@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use mlua::{Lua, Table};
+
+use crate::core::enums::Language;
+use crate::core::types::{Command, EnvPolicy, Runnable};
+
+// errorformat
+//
+// How failures of the language's test runners point at source lines, fed
+// to overseer's `on_output_quickfix` component.
+pub(crate) fn errorformat(language: &Language) -> &'static str {
+    match language {
+        // "    calculator_test.go:12: want 2, got 3"
+        Language::Golang => "%*[ \t]%f:%l: %m,%f:%l:%c: %m,%f:%l: %m",
+        // "thread 'tests::adds' panicked at src/lib.rs:10:5:"
+        Language::Rust => "%.%#panicked at %f:%l:%c:,%Eerror%m,%Z%*[ ]--> %f:%l:%c",
+        // "tests/test_calc.py:12: AssertionError"
+        Language::Python => "%f:%l: %m",
+        // "    at Object.<anonymous> (src/calc.test.js:4:17)"
+        Language::JavaScript | Language::TypeScript => "%.%#at %.%#(%f:%l:%c),%.%#at %f:%l:%c",
+        // "spec/calc_spec.rb:4:in `block (2 levels)'"
+        Language::Ruby => "%f:%l:in %m,rspec %f:%l %m",
+        // "calc_test.cpp:12: Failure"
+        Language::Cpp => "%f:%l: %m,%f(%l): %m",
+        // "    at com.acme.CalcTest.adds(CalcTest.java:12)"
+        Language::Java => "%.%#at %.%#(%f:%l)",
+        _ => "%f:%l:%c: %m,%f:%l: %m",
+    }
+}
+
+// Component
+//
+// An overseer component, its name followed by its options, e.g.
+// `{ "on_output_quickfix", open_on_exit = "failure" }`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Component {
+    pub name: String,
+    pub options: BTreeMap<String, serde_json::Value>,
+}
+
+impl Component {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            options: BTreeMap::new(),
+        }
+    }
+
+    pub fn option(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.options.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+// TaskDefinition
+//
+// The definition `require("overseer").new_task` expects for a runnable.
+// Variables a command unsets, or an environment it clears, have no overseer
+// counterpart and go through `env -u`/`env -i` instead, the variables of a
+// cleared environment following `-i`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskDefinition {
+    pub name: String,
+    pub cmd: String,
+    pub args: Vec<String>,
+    pub cwd: Option<String>,
+    pub env: BTreeMap<String, String>,
+    pub components: Vec<Component>,
+    pub metadata: TaskMetadata,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskMetadata {
+    pub runnable_id: String,
+    pub framework: String,
+    pub filepath: String,
+    pub test: String,
+    // 1 based, as editors number lines
+    pub line: usize,
+}
+
+impl TaskDefinition {
+    pub fn new(
+        framework: &str,
+        language: &Language,
+        runnable: &Runnable,
+        command: Command,
+    ) -> Self {
+        let mut cmd = command.command;
+        let mut args = command.args;
+        let mut env = command.env.set;
+        if command.env.policy == EnvPolicy::Clear || !command.env.unset.is_empty() {
+            let mut env_args = vec![];
+            if command.env.policy == EnvPolicy::Clear {
+                // `-i` would clear the variables handed to `env` itself
+                env_args.push("-i".to_string());
+                for (name, value) in std::mem::take(&mut env) {
+                    env_args.push(format!("{}={}", name, value));
+                }
+            } else {
+                for name in command.env.unset.iter() {
+                    env_args.extend(["-u".to_string(), name.to_string()]);
+                }
+            }
+            env_args.push(cmd);
+            env_args.append(&mut args);
+            cmd = "env".to_string();
+            args = env_args;
+        }
+
+        // an unnamed runnable stands for every test of its file
+        let subject = match runnable.name.is_empty() {
+            true => &runnable.filepath,
+            false => &runnable.name,
+        };
+        Self {
+            name: format!("{}: {}", framework, subject),
+            cmd,
+            args,
+            cwd: command.cwd.map(|cwd| cwd.display().to_string()),
+            env,
+            components: vec![
+                Component::new("on_output_quickfix")
+                    .option("errorformat", errorformat(language))
+                    .option("open_on_exit", "failure")
+                    .option("set_diagnostics", true),
+                Component::new("default"),
+            ],
+            metadata: TaskMetadata {
+                runnable_id: runnable.id(),
+                framework: framework.to_string(),
+                filepath: runnable.filepath.to_string(),
+                test: runnable.name.to_string(),
+                line: runnable.range.start.row + 1,
+            },
+        }
+    }
+
+    pub fn to_lua(&self, lua: &Lua) -> mlua::Result<Table> {
+        let res = lua.create_table()?;
+        res.set("name", self.name.as_str())?;
+        res.set("cmd", self.cmd.as_str())?;
+        res.set("args", self.args.clone())?;
+        if let Some(cwd) = &self.cwd {
+            res.set("cwd", cwd.as_str())?;
+        }
+        if !self.env.is_empty() {
+            res.set("env", self.env.clone())?;
+        }
+
+        let components = lua.create_table()?;
+        for component in self.components.iter() {
+            let table = lua.create_table()?;
+            table.push(component.name.as_str())?;
+            for (name, value) in component.options.iter() {
+                table.set(name.as_str(), mlua::LuaSerdeExt::to_value(lua, value)?)?;
+            }
+            components.push(table)?;
+        }
+        res.set("components", components)?;
+
+        let metadata = lua.create_table()?;
+        metadata.set("runnable_id", self.metadata.runnable_id.as_str())?;
+        metadata.set("framework", self.metadata.framework.as_str())?;
+        metadata.set("filepath", self.metadata.filepath.as_str())?;
+        metadata.set("test", self.metadata.test.as_str())?;
+        metadata.set("line", self.metadata.line)?;
+        res.set("metadata", metadata)?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Range;
+    use std::path::PathBuf;
+
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use super::TaskDefinition;
+    use crate::core::enums::Language;
+    use crate::core::metadata::RunnableMeta;
+    use crate::core::types::{Command, CursorPosition, EnvPolicy, Runnable};
+
+    fn runnable() -> Runnable {
+        Runnable {
+            name: "^TestAdd$".to_string(),
+            filepath: "pkg/calc/calc_test.go".to_string(),
+            range: Range {
+                start: CursorPosition::new(11, 0),
+                end: CursorPosition::new(14, 1),
+            },
+            meta: RunnableMeta::default_golang(),
+        }
+    }
+
+    fn command() -> Command {
+        let mut res = Command {
+            command: "go".to_string(),
+            args: vec![
+                "test".to_string(),
+                "-run".to_string(),
+                "^TestAdd$".to_string(),
+            ],
+            cwd: Some(PathBuf::from("/src/calc")),
+            ..Default::default()
+        };
+        res.env.set("DATABASE_URL", "postgres://localhost/calc");
+        res
+    }
+
+    #[gtest]
+    fn builds_overseer_task() {
+        // arrange
+        let lua = Lua::new();
+
+        // act
+        let task = TaskDefinition::new("GoTest", &Language::Golang, &runnable(), command())
+            .to_lua(&lua)
+            .unwrap();
+
+        // assert
+        assert_that!(task.get::<String>("name").unwrap(), eq("GoTest: ^TestAdd$"));
+        assert_that!(task.get::<String>("cmd").unwrap(), eq("go"));
+        assert_that!(
+            task.get::<Vec<String>>("args").unwrap(),
+            container_eq(vec![
+                "test".to_string(),
+                "-run".to_string(),
+                "^TestAdd$".to_string()
+            ])
+        );
+        assert_that!(task.get::<String>("cwd").unwrap(), eq("/src/calc"));
+        let env: Table = task.get("env").unwrap();
+        assert_that!(
+            env.get::<String>("DATABASE_URL").unwrap(),
+            eq("postgres://localhost/calc")
+        );
+        let components: Vec<Table> = task.get("components").unwrap();
+        assert_that!(
+            components[0].get::<String>(1).unwrap(),
+            eq("on_output_quickfix")
+        );
+        assert_that!(
+            components[0].get::<String>("errorformat").unwrap(),
+            contains_substring("%f:%l: %m")
+        );
+        assert_that!(components[1].get::<String>(1).unwrap(), eq("default"));
+        let metadata: Table = task.get("metadata").unwrap();
+        assert_that!(
+            metadata.get::<String>("runnable_id").unwrap(),
+            eq("pkg/calc/calc_test.go::^TestAdd$")
+        );
+        assert_that!(metadata.get::<usize>("line").unwrap(), eq(12));
+    }
+
+    #[gtest]
+    fn unsets_variables_through_env() {
+        // arrange
+        let mut command = command();
+        command.env.unset("GOFLAGS");
+
+        // act
+        let task = TaskDefinition::new("GoTest", &Language::Golang, &runnable(), command);
+
+        // assert
+        assert_that!(task.cmd, eq("env"));
+        assert_that!(
+            task.args,
+            elements_are![
+                eq("-u"),
+                eq("GOFLAGS"),
+                eq("go"),
+                eq("test"),
+                eq("-run"),
+                eq("^TestAdd$")
+            ]
+        );
+    }
+
+    #[gtest]
+    fn sets_variables_of_a_cleared_environment_through_env() {
+        // arrange
+        let mut command = command();
+        command.env.policy = EnvPolicy::Clear;
+
+        // act
+        let task = TaskDefinition::new("GoTest", &Language::Golang, &runnable(), command);
+
+        // assert
+        assert_that!(task.cmd, eq("env"));
+        assert_that!(
+            task.args,
+            elements_are![
+                eq("-i"),
+                eq("DATABASE_URL=postgres://localhost/calc"),
+                eq("go"),
+                eq("test"),
+                eq("-run"),
+                eq("^TestAdd$")
+            ]
+        );
+        assert_that!(task.env, is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use mlua::{Lua, Table};
+
+use crate::core::enums::{Capability, Search};
+use crate::core::types::FrameworkCapability;
+
+// PROVIDER_NAME
+//
+// Name of the overseer template provider.
+pub(crate) const PROVIDER_NAME: &str = "examen";
+
+// TemplateProvider
+//
+// Describes the overseer templates available for a file, one per
+// capability of the frameworks detected, best ranked first. The lua side
+// registers it as
+//
+// {
+//   name = provider.name,
+//   generator = function(_, cb) cb(vim.tbl_map(to_template, provider.templates)) end,
+// }
+//
+// each template building its task from `Engine::overseer_task`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateProvider {
+    pub name: String,
+    pub filepath: String,
+    pub templates: Vec<Template>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    // e.g. "GoTest: Test Nearest"
+    pub name: String,
+    pub desc: String,
+    // overseer tags, "TEST" for test runners
+    pub tags: Vec<String>,
+    pub framework: String,
+    // the capability description, as `Engine::find_runnables` expects it
+    pub capability: String,
+}
+
+impl TemplateProvider {
+    pub fn new(filepath: &str, capabilities: &[FrameworkCapability]) -> Self {
+        let templates = capabilities
+            .iter()
+            .map(|c| {
+                let action = match c.details.capability {
+                    Capability::TestRunner => "Run",
+                    Capability::Debugger => "Debug",
+                };
+                let scope = match c.details.search {
+                    Search::Nearest => "the test nearest to the cursor",
+                    Search::Method => "the test function under the cursor",
+                    Search::File => "every test of the file",
+                };
+                Template {
+                    name: format!("{}: {}", c.framework, c.details.description),
+                    desc: format!("{} {} with {}", action, scope, c.framework),
+                    tags: match c.details.capability {
+                        Capability::TestRunner => vec!["TEST".to_string()],
+                        Capability::Debugger => vec![],
+                    },
+                    framework: c.framework.to_string(),
+                    capability: c.details.description.to_string(),
+                }
+            })
+            .collect();
+        Self {
+            name: PROVIDER_NAME.to_string(),
+            filepath: filepath.to_string(),
+            templates,
+        }
+    }
+
+    pub fn to_lua(&self, lua: &Lua) -> mlua::Result<Table> {
+        let res = lua.create_table()?;
+        res.set("name", self.name.as_str())?;
+        res.set("filepath", self.filepath.as_str())?;
+        let templates = lua.create_table()?;
+        for (i, template) in self.templates.iter().enumerate() {
+            let table = lua.create_table()?;
+            table.set("name", template.name.as_str())?;
+            table.set("desc", template.desc.as_str())?;
+            table.set("tags", template.tags.clone())?;
+            // overseer lists lower priorities first
+            table.set("priority", 50 + i)?;
+            let metadata = lua.create_table()?;
+            metadata.set("framework", template.framework.as_str())?;
+            metadata.set("capability", template.capability.as_str())?;
+            metadata.set("filepath", self.filepath.as_str())?;
+            table.set("metadata", metadata)?;
+            templates.push(table)?;
+        }
+        res.set("templates", templates)?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use super::TemplateProvider;
+    use crate::core::enums::{Capability, Search};
+    use crate::core::types::{CapabilityDetails, FrameworkCapability};
+
+    fn capability(framework: &str, capability: Capability, search: Search) -> FrameworkCapability {
+        let description = match (&capability, &search) {
+            (Capability::TestRunner, Search::Nearest) => "Test Nearest",
+            (Capability::TestRunner, _) => "Test File",
+            (Capability::Debugger, _) => "Debug Nearest",
+        };
+        FrameworkCapability {
+            framework: framework.to_string(),
+            details: CapabilityDetails {
+                capability,
+                search,
+                description: description.to_string(),
+            },
+        }
+    }
+
+    #[gtest]
+    fn describes_file_capabilities() {
+        // arrange
+        let lua = Lua::new();
+        let capabilities = vec![
+            capability("GoTest", Capability::TestRunner, Search::Nearest),
+            capability("Delve", Capability::Debugger, Search::Nearest),
+        ];
+
+        // act
+        let res = TemplateProvider::new("pkg/calc/calc_test.go", &capabilities)
+            .to_lua(&lua)
+            .unwrap();
+
+        // assert
+        assert_that!(res.get::<String>("name").unwrap(), eq("examen"));
+        let templates: Vec<Table> = res.get("templates").unwrap();
+        assert_that!(templates.len(), eq(2));
+        assert_that!(
+            templates[0].get::<String>("name").unwrap(),
+            eq("GoTest: Test Nearest")
+        );
+        assert_that!(
+            templates[0].get::<String>("desc").unwrap(),
+            eq("Run the test nearest to the cursor with GoTest")
+        );
+        assert_that!(
+            templates[0].get::<Vec<String>>("tags").unwrap(),
+            container_eq(vec!["TEST".to_string()])
+        );
+        assert_that!(templates[1].get::<Vec<String>>("tags").unwrap(), is_empty());
+        let metadata: Table = templates[1].get("metadata").unwrap();
+        assert_that!(metadata.get::<String>("framework").unwrap(), eq("Delve"));
+        assert_that!(
+            metadata.get::<String>("capability").unwrap(),
+            eq("Debug Nearest")
+        );
+    }
+}
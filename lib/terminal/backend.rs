@@ -0,0 +1,88 @@
+use crate::core::config::{Backend, ExecutorConfig};
+use crate::core::errors::ExecutionError;
+use crate::terminal::core::TerminalExecution;
+use crate::terminal::executor::{ExecutorOptions, OutputLine, RunningExecution};
+
+// DEFAULT_TMUX_TARGET
+//
+// Window commands are sent to when the project names none.
+pub(crate) const DEFAULT_TMUX_TARGET: &str = "examen";
+
+pub type LineCallback = Box<dyn FnMut(OutputLine) + Send>;
+
+// Executor
+//
+// Runs commands somewhere, the editor's own processes or a terminal the
+// user watches, without blocking the caller.
+pub trait Executor: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn execute(
+        &self,
+        execution: &TerminalExecution,
+        options: ExecutorOptions,
+        on_line: LineCallback,
+    ) -> Result<RunningExecution, ExecutionError>;
+}
+
+// ProcessExecutor
+//
+// Runs commands as children of the editor, see `TerminalExecution::spawn`.
+#[derive(Default)]
+pub struct ProcessExecutor;
+
+impl Executor for ProcessExecutor {
+    fn name(&self) -> &str {
+        "process"
+    }
+
+    fn execute(
+        &self,
+        execution: &TerminalExecution,
+        options: ExecutorOptions,
+        on_line: LineCallback,
+    ) -> Result<RunningExecution, ExecutionError> {
+        execution.spawn(options, on_line)
+    }
+}
+
+// executor
+//
+// The executor a project selected in its configuration.
+pub(crate) fn executor(config: &ExecutorConfig) -> Box<dyn Executor> {
+    match config.backend {
+        #[cfg(unix)]
+        Some(Backend::Tmux) => Box::new(crate::terminal::tmux::TmuxExecutor::new(
+            config.target.as_deref().unwrap_or(DEFAULT_TMUX_TARGET),
+        )),
+        _ => Box::new(ProcessExecutor),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::executor;
+    use crate::core::config::{Backend, ExecutorConfig};
+
+    #[gtest]
+    #[rstest]
+    #[case(None, "process")]
+    #[case(Some(Backend::Process), "process")]
+    #[case(Some(Backend::Tmux), "tmux")]
+    fn selects_configured_backend(#[case] backend: Option<Backend>, #[case] expected: &str) {
+        // arrange
+        let config = ExecutorConfig {
+            backend,
+            ..Default::default()
+        };
+
+        // act
+        let res = executor(&config);
+
+        // assert
+        assert_that!(res.name(), eq(expected));
+    }
+}
@@ -217,7 +217,7 @@ mod test {
     #[gtest]
     #[rstest]
     #[case(TerminalCommand::Which {program: "go".to_string()}, "which go")]
-    #[case(TerminalCommand::Which {program: "".to_string()}, "which")]
+    #[case(TerminalCommand::Which {program: "".to_string()}, "which ''")]
     fn which_command(#[case] command: TerminalCommand, #[case] expected: &str) {
         assert_that!(command.to_terminal_execution().to_string(), eq(expected))
     }
@@ -228,31 +228,31 @@ mod test {
         .pattern("\"needle\"".to_string())
         .files(vec!["haystack.txt".to_string()])
         .call(), 
-        "grep \"needle\" haystack.txt")]
+        "grep '\"needle\"' haystack.txt")]
     #[case(TerminalCommand::grep()
         .pattern("\"needle\"".to_string())
         .files(vec!["haystack.txt".to_string()])
         .case_insensitive(true)
         .call(), 
-        "grep -i \"needle\" haystack.txt")]
+        "grep -i '\"needle\"' haystack.txt")]
     #[case(TerminalCommand::grep()
         .pattern("\"needle\"".to_string())
         .files(vec!["haystack.txt".to_string()])
         .case_insensitive(true)
         .call(), 
-        "grep -i \"needle\" haystack.txt")]
+        "grep -i '\"needle\"' haystack.txt")]
     #[case(TerminalCommand::grep()
         .pattern("\"needle\"".to_string())
         .files(vec!["haystack.txt".to_string()])
         .show_count(true)
         .call(), 
-        "grep -c \"needle\" haystack.txt")]
+        "grep -c '\"needle\"' haystack.txt")]
     #[case(TerminalCommand::grep()
         .pattern("\"needle\"".to_string())
         .files(vec!["haystack.txt".to_string()])
         .invert_match(true)
         .call(), 
-        "grep -v \"needle\" haystack.txt")]
+        "grep -v '\"needle\"' haystack.txt")]
     fn grep_command(#[case] command: TerminalCommand, #[case] expected: &str) {
         assert_that!(command.to_terminal_execution().to_string(), eq(expected))
     }
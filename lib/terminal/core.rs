@@ -1,10 +1,18 @@
 use std::{
     fmt,
-    io::BufReader,
+    io::Cursor,
+    path::PathBuf,
     process::{Command, ExitStatus, Stdio},
 };
 
-pub type TerminalOutput = BufReader<std::process::ChildStdout>;
+use crate::core::errors::ExecutionError;
+use crate::core::types::{self, EnvPolicy, Environment};
+
+// TerminalOutput
+//
+// Stdout of a finished command, read in full while it ran so a chatty
+// command cannot fill the pipe and block.
+pub type TerminalOutput = Cursor<Vec<u8>>;
 
 pub enum TerminalExecutionResult {
     // ReadOutput - capture stdout and return the result
@@ -22,53 +30,233 @@ pub enum TerminalExecutionResult {
 pub struct TerminalExecution {
     pub command: String,
     pub args: Vec<String>,
+    // working directory, the current one when unset
+    pub cwd: Option<PathBuf>,
+    pub env: Environment,
 }
 
 impl TerminalExecution {
     pub fn new(command: String, args: Vec<String>) -> Self {
-        Self { command, args }
+        Self {
+            command,
+            args,
+            cwd: None,
+            env: Environment::default(),
+        }
     }
 
-    pub fn run(&self, return_stdout: bool) -> Result<TerminalExecutionResult, ()> {
+    pub(crate) fn process(&self) -> Command {
+        let mut res = Command::new(&self.command);
+        res.args(self.args.iter());
+        if self.env.policy == EnvPolicy::Clear {
+            res.env_clear();
+        }
+        for name in self.env.unset.iter() {
+            res.env_remove(name);
+        }
+        res.envs(self.env.set.iter());
+        if let Some(cwd) = &self.cwd {
+            res.current_dir(cwd);
+        }
+        res
+    }
+
+    // run
+    //
+    // Runs the command to completion, capturing stdout when `return_stdout`
+    // is set and leaving it to the editor otherwise. See `spawn` to run
+    // without blocking.
+    pub fn run(&self, return_stdout: bool) -> Result<TerminalExecutionResult, ExecutionError> {
         if return_stdout {
             return self.run_return_output();
         }
         self.run_return_status()
     }
 
-    fn run_return_output(&self) -> Result<TerminalExecutionResult, ()> {
-        let child = std::process::Command::new(&self.command)
-            .args(self.args.iter())
+    fn run_return_output(&self) -> Result<TerminalExecutionResult, ExecutionError> {
+        let child = self
+            .process()
+            .stdout(Stdio::piped())
             .spawn()
-            .expect("failed to run command");
-
-        let output = child.wait_with_output().expect("command execution failed");
-        Ok(TerminalExecutionResult::TerminationStatus {
+            .map_err(|e| self.spawn_error(e))?;
+        let output = child
+            .wait_with_output()
+            .map_err(|e| ExecutionError::IoError(format!("{}: {}", self.command, e)))?;
+        Ok(TerminalExecutionResult::ReadOutput {
             status: output.status,
+            output: Cursor::new(output.stdout),
         })
     }
 
-    fn run_return_status(&self) -> Result<TerminalExecutionResult, ()> {
-        let mut child = Command::new(&self.command)
-            .args(self.args.iter())
-            .stdout(Stdio::piped())
-            .spawn()
-            .expect("failed to run command");
-        let stdout = child.stdout.take().expect("command did not have output");
-        let output = BufReader::new(stdout);
-        let status = child.wait().expect("command execution failed");
-        Ok(TerminalExecutionResult::ReadOutput { status, output })
+    fn run_return_status(&self) -> Result<TerminalExecutionResult, ExecutionError> {
+        let mut child = self.process().spawn().map_err(|e| self.spawn_error(e))?;
+        let status = child
+            .wait()
+            .map_err(|e| ExecutionError::IoError(format!("{}: {}", self.command, e)))?;
+        Ok(TerminalExecutionResult::TerminationStatus { status })
+    }
+
+    pub(crate) fn spawn_error(&self, e: std::io::Error) -> ExecutionError {
+        ExecutionError::SpawnError(format!("failed to run `{}`: {}", self.command, e))
+    }
+}
+
+impl From<types::Command> for TerminalExecution {
+    fn from(command: types::Command) -> Self {
+        Self {
+            command: command.command,
+            args: command.args,
+            cwd: command.cwd,
+            env: command.env,
+        }
     }
 }
 
+// Display
+//
+// A shell line reproducing the execution, e.g.
+// `cd /repo && env -u GOFLAGS DATABASE_URL='postgres://localhost/db' go test ./...`,
+// every word quoted as needed.
 impl fmt::Display for TerminalExecution {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut res = self.command.clone();
-        let args = self.args.clone().join(" ");
-        if args.len().ge(&0) && !args.is_empty() {
-            res.push(' ');
-            res.push_str(&args);
+        let mut words: Vec<String> = vec![];
+        if self.env.policy == EnvPolicy::Clear {
+            words.extend(["env".to_string(), "-i".to_string()]);
+        } else if !self.env.unset.is_empty() {
+            words.push("env".to_string());
+            for name in self.env.unset.iter() {
+                words.extend(["-u".to_string(), shell_words::quote(name).to_string()]);
+            }
         }
-        write!(f, "{}", res)
+        for (name, value) in self.env.set.iter() {
+            words.push(format!("{}={}", name, shell_words::quote(value)));
+        }
+        words.push(shell_words::quote(&self.command).to_string());
+        words.extend(self.args.iter().map(|a| shell_words::quote(a).to_string()));
+
+        if let Some(cwd) = &self.cwd {
+            write!(
+                f,
+                "cd {} && ",
+                shell_words::quote(&cwd.display().to_string())
+            )?;
+        }
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use std::io::BufRead;
+
+    use super::{TerminalExecution, TerminalExecutionResult};
+    use crate::core::errors::ExecutionError;
+    use crate::core::types::{Command, EnvPolicy};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn run_captures_stdout_when_asked() {
+        // arrange
+        let mut execution = TerminalExecution::new(
+            "sh".to_string(),
+            vec!["-c".to_string(), "echo \"$NAME\"; pwd".to_string()],
+        );
+        execution.env.set("NAME", "examen");
+        execution.cwd = Some(PathBuf::from("/"));
+
+        // act
+        let res = execution.run(true);
+
+        // assert
+        let Ok(TerminalExecutionResult::ReadOutput { status, output }) = res else {
+            panic!("expected the output to be read");
+        };
+        let lines: Vec<String> = output.lines().map(|l| l.unwrap()).collect();
+        assert_that!(status.success(), eq(true));
+        assert_that!(
+            lines,
+            container_eq(vec!["examen".to_string(), "/".to_string()])
+        );
+    }
+
+    #[gtest]
+    fn run_returns_exit_status() {
+        // arrange
+        let execution = TerminalExecution::new(
+            "sh".to_string(),
+            vec!["-c".to_string(), "exit 3".to_string()],
+        );
+
+        // act
+        let res = execution.run(false);
+
+        // assert
+        let Ok(TerminalExecutionResult::TerminationStatus { status }) = res else {
+            panic!("expected the command to terminate");
+        };
+        assert_that!(status.code(), some(eq(3)));
+    }
+
+    #[gtest]
+    fn run_reports_spawn_failure() {
+        // arrange
+        let execution = TerminalExecution::new("examen-missing-program".to_string(), vec![]);
+
+        // act
+        let res = execution.run(false);
+
+        // assert
+        assert_that!(
+            res.err(),
+            some(matches_pattern!(ExecutionError::SpawnError(starts_with(
+                "failed to run `examen-missing-program`"
+            ))))
+        );
+    }
+
+    #[gtest]
+    fn display_reproduces_environment_and_cwd() {
+        // arrange
+        let mut command = Command {
+            command: "go".to_string(),
+            args: vec![
+                "test".to_string(),
+                "-run".to_string(),
+                "^TestA$/^case a$".to_string(),
+            ],
+            cwd: Some(PathBuf::from("/src/my repo")),
+            ..Default::default()
+        };
+        command
+            .env
+            .set("DATABASE_URL", "postgres://localhost/app?user=me");
+        command.env.unset("GOFLAGS");
+
+        // act
+        let res = TerminalExecution::from(command).to_string();
+
+        // assert
+        assert_that!(
+            res,
+            eq(
+                "cd '/src/my repo' && env -u GOFLAGS DATABASE_URL='postgres://localhost/app?user=me' go test -run '^TestA$/^case a$'"
+            )
+        );
+    }
+
+    #[gtest]
+    fn display_clears_environment() {
+        // arrange
+        let mut execution = TerminalExecution::new("env".to_string(), vec![]);
+        execution.env.policy = EnvPolicy::Clear;
+        execution.env.set("HOME", "/tmp");
+
+        // act
+        let res = execution.to_string();
+
+        // assert
+        assert_that!(res, eq("env -i HOME=/tmp env"));
     }
 }
@@ -0,0 +1,515 @@
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, ExitStatus, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::core::errors::ExecutionError;
+use crate::terminal::core::TerminalExecution;
+
+// POLL_INTERVAL
+//
+// How often the supervisor checks for cancellation, the timeout and the
+// exit of the child while no output arrives.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+// DRAIN_TIMEOUT
+//
+// How long output is still read once the command exited, a process that
+// left its group may keep the streams open forever.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputLine {
+    pub stream: Stream,
+    pub line: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ExecutorOptions {
+    // the command is killed once running longer
+    pub timeout: Option<Duration>,
+}
+
+// RunningExecution
+//
+// A command running in the background. Its output is handed to the line
+// callback from the supervising thread, in the order it was read.
+pub struct RunningExecution {
+    // unknown when the command runs outside of examen, e.g. in tmux
+    pid: Option<u32>,
+    cancelled: Arc<AtomicBool>,
+    handle: JoinHandle<Result<ExitStatus, ExecutionError>>,
+}
+
+impl RunningExecution {
+    pub(crate) fn new(
+        pid: Option<u32>,
+        cancelled: Arc<AtomicBool>,
+        handle: JoinHandle<Result<ExitStatus, ExecutionError>>,
+    ) -> Self {
+        Self {
+            pid,
+            cancelled,
+            handle,
+        }
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.pid
+    }
+
+    // cancel
+    //
+    // Kills the command along with every process it started, `wait` then
+    // returns a `CancelledError`.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn canceller(&self) -> Canceller {
+        Canceller(self.cancelled.clone())
+    }
+
+    pub fn wait(self) -> Result<ExitStatus, ExecutionError> {
+        self.handle.join().unwrap_or_else(|_| {
+            Err(ExecutionError::IoError(
+                "the execution supervisor panicked".to_string(),
+            ))
+        })
+    }
+}
+
+// Canceller
+//
+// Cancels a `RunningExecution` from another thread.
+#[derive(Clone, Debug)]
+pub struct Canceller(Arc<AtomicBool>);
+
+impl Canceller {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+impl TerminalExecution {
+    // spawn
+    //
+    // Starts the command in its own process group and returns without
+    // waiting for it, streaming stdout and stderr line by line to `on_line`.
+    pub fn spawn<F>(
+        &self,
+        options: ExecutorOptions,
+        on_line: F,
+    ) -> Result<RunningExecution, ExecutionError>
+    where
+        F: FnMut(OutputLine) + Send + 'static,
+    {
+        let mut process = self.process();
+        process
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut process, 0);
+        let mut child = process.spawn().map_err(|e| self.spawn_error(e))?;
+
+        let (sender, receiver) = mpsc::channel();
+        if let Some(stdout) = child.stdout.take() {
+            read_lines(stdout, Stream::Stdout, sender.clone());
+        }
+        if let Some(stderr) = child.stderr.take() {
+            read_lines(stderr, Stream::Stderr, sender);
+        }
+
+        let pid = child.id();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let supervisor = Supervisor {
+            command: self.command.clone(),
+            child,
+            cancelled: cancelled.clone(),
+            deadline: options.timeout.map(|t| (Instant::now() + t, t)),
+        };
+        let handle = thread::Builder::new()
+            .name(format!("examen: {}", self.command))
+            .spawn(move || supervisor.run(receiver, on_line))
+            .map_err(|e| self.spawn_error(e))?;
+        Ok(RunningExecution::new(Some(pid), cancelled, handle))
+    }
+}
+
+// read_lines
+//
+// Sends every line of the stream until it closes. Test binaries may print
+// raw bytes, invalid UTF-8 is replaced rather than ending the stream.
+fn read_lines(source: impl Read + Send + 'static, stream: Stream, sender: Sender<OutputLine>) {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(source);
+        let mut bytes = vec![];
+        loop {
+            bytes.clear();
+            match reader.read_until(b'\n', &mut bytes) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            let line = String::from_utf8_lossy(&bytes)
+                .trim_end_matches(['\n', '\r'])
+                .to_string();
+            if sender.send(OutputLine { stream, line }).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+struct Supervisor {
+    command: String,
+    child: Child,
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<(Instant, Duration)>,
+}
+
+impl Supervisor {
+    // run
+    //
+    // Forwards output until the child exited and both streams are closed,
+    // or `DRAIN_TIMEOUT` passed, killing the process group on cancellation
+    // or timeout. Processes of the group outliving the child are killed as
+    // well. The child is reaped last, so its pid, which is also the id of
+    // the group, cannot be reused while the group may be signalled.
+    fn run<F>(
+        mut self,
+        receiver: mpsc::Receiver<OutputLine>,
+        mut on_line: F,
+    ) -> Result<ExitStatus, ExecutionError>
+    where
+        F: FnMut(OutputLine),
+    {
+        let mut streams_closed = false;
+        let mut drained_by: Option<Instant> = None;
+        loop {
+            if streams_closed {
+                thread::sleep(POLL_INTERVAL);
+            } else {
+                match receiver.recv_timeout(POLL_INTERVAL) {
+                    Ok(line) => on_line(line),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => streams_closed = true,
+                }
+            }
+
+            if drained_by.is_none() && self.exited()? {
+                self.signal_group();
+                drained_by = Some(Instant::now() + DRAIN_TIMEOUT);
+            }
+            if let Some(drained_by) = drained_by
+                && (streams_closed || Instant::now() >= drained_by)
+            {
+                return self.child.wait().map_err(|e| self.io_error(e));
+            }
+            if self.cancelled.load(Ordering::SeqCst) {
+                self.kill()?;
+                return Err(ExecutionError::CancelledError(format!(
+                    "`{}` was cancelled",
+                    self.command
+                )));
+            }
+            if let Some((deadline, timeout)) = self.deadline
+                && Instant::now() >= deadline
+            {
+                self.kill()?;
+                return Err(ExecutionError::TimeoutError(format!(
+                    "`{}` did not finish within {:?}",
+                    self.command, timeout
+                )));
+            }
+        }
+    }
+
+    // exited
+    //
+    // Whether the child exited, leaving it to be reaped by `wait`.
+    #[cfg(unix)]
+    fn exited(&mut self) -> Result<bool, ExecutionError> {
+        // SAFETY: siginfo_t is plain data, zeroed it reports no child
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        // SAFETY: waitid only writes to `info`, WNOWAIT keeps the child waitable
+        let res = unsafe {
+            libc::waitid(
+                libc::P_PID,
+                self.child.id() as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOHANG | libc::WNOWAIT,
+            )
+        };
+        if res == -1 {
+            return Err(self.io_error(std::io::Error::last_os_error()));
+        }
+        // SAFETY: waitid filled in a SIGCHLD siginfo_t, or left it zeroed
+        Ok(unsafe { info.si_pid() } != 0)
+    }
+
+    #[cfg(not(unix))]
+    fn exited(&mut self) -> Result<bool, ExecutionError> {
+        let status = self.child.try_wait().map_err(|e| self.io_error(e))?;
+        Ok(status.is_some())
+    }
+
+    // signal_group
+    //
+    // Kills the whole process group, so children of the command, e.g. the
+    // test binary `go test` builds, do not outlive it. Only called before
+    // the child is reaped.
+    fn signal_group(&mut self) {
+        #[cfg(unix)]
+        {
+            let pgid = self.child.id() as libc::pid_t;
+            // SAFETY: kill only sends a signal, a negative pid targets the
+            // group the child leads since it was spawned with process_group(0)
+            unsafe {
+                libc::kill(-pgid, libc::SIGKILL);
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = self.child.kill();
+        }
+    }
+
+    fn kill(&mut self) -> Result<(), ExecutionError> {
+        self.signal_group();
+        self.child.wait().map_err(|e| self.io_error(e))?;
+        Ok(())
+    }
+
+    fn io_error(&self, e: std::io::Error) -> ExecutionError {
+        ExecutionError::IoError(format!("{}: {}", self.command, e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use googletest::prelude::*;
+
+    use super::{ExecutorOptions, OutputLine, Stream};
+    use crate::core::errors::ExecutionError;
+    use crate::terminal::core::TerminalExecution;
+
+    fn sh(script: &str) -> TerminalExecution {
+        TerminalExecution::new("sh".to_string(), vec!["-c".to_string(), script.to_string()])
+    }
+
+    #[gtest]
+    fn streams_stdout_and_stderr_lines() {
+        // arrange
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+        let mut execution = sh("echo one; echo two >&2; echo \"$GREETING\"; exit 3");
+        execution.env.set("GREETING", "hello world");
+
+        // act
+        let res = execution
+            .spawn(ExecutorOptions::default(), move |line| {
+                sink.lock().unwrap().push(line)
+            })
+            .unwrap()
+            .wait();
+
+        // assert
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(3))));
+        let lines = lines.lock().unwrap();
+        assert_that!(
+            lines
+                .iter()
+                .filter(|l| l.stream == Stream::Stdout)
+                .collect::<Vec<&OutputLine>>(),
+            elements_are![
+                field!(&OutputLine.line, eq("one")),
+                field!(&OutputLine.line, eq("hello world"))
+            ]
+        );
+        assert_that!(
+            *lines,
+            contains(eq(&OutputLine {
+                stream: Stream::Stderr,
+                line: "two".to_string()
+            }))
+        );
+    }
+
+    #[gtest]
+    fn keeps_streaming_after_invalid_utf8() {
+        // arrange
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+        let execution = sh("printf 'one\\n\\377\\376\\ntwo\\n'");
+
+        // act
+        let res = execution
+            .spawn(ExecutorOptions::default(), move |line| {
+                sink.lock().unwrap().push(line.line)
+            })
+            .unwrap()
+            .wait();
+
+        // assert
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(0))));
+        assert_that!(
+            *lines.lock().unwrap(),
+            elements_are![eq("one"), eq("\u{fffd}\u{fffd}"), eq("two")]
+        );
+    }
+
+    // running
+    //
+    // Whether the process runs, a killed process nobody reaped yet being a
+    // zombie.
+    #[cfg(target_os = "linux")]
+    fn running(pid: u32) -> bool {
+        std::fs::read_to_string(format!("/proc/{}/stat", pid))
+            .ok()
+            .and_then(|stat| stat.rsplit_once(") ").map(|(_, rest)| rest.to_string()))
+            .is_some_and(|rest| !rest.starts_with('Z'))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[gtest]
+    fn times_out_and_kills_process_group() {
+        // arrange
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+        let execution = sh("sleep 5 & echo $!; wait");
+        let options = ExecutorOptions {
+            timeout: Some(Duration::from_millis(200)),
+        };
+        let started = Instant::now();
+
+        // act
+        let res = execution
+            .spawn(options, move |line| sink.lock().unwrap().push(line.line))
+            .unwrap()
+            .wait();
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::TimeoutError(
+                contains_substring("did not finish within 200ms")
+            )))
+        );
+        assert_that!(started.elapsed() < Duration::from_secs(4), eq(true));
+        let background: u32 = lines.lock().unwrap()[0].parse().unwrap();
+        let killed = (0..50).any(|_| {
+            std::thread::sleep(Duration::from_millis(20));
+            !running(background)
+        });
+        assert_that!(killed, eq(true));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[gtest]
+    fn kills_process_group_once_command_exits() {
+        // arrange
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+        // the background sleep keeps stdout open after sh exits
+        let execution = sh("sleep 5 & echo $!");
+        let started = Instant::now();
+
+        // act
+        let res = execution
+            .spawn(ExecutorOptions::default(), move |line| {
+                sink.lock().unwrap().push(line.line)
+            })
+            .unwrap()
+            .wait();
+
+        // assert
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(0))));
+        assert_that!(started.elapsed() < Duration::from_secs(2), eq(true));
+        let background: u32 = lines.lock().unwrap()[0].parse().unwrap();
+        let killed = (0..50).any(|_| {
+            std::thread::sleep(Duration::from_millis(20));
+            !running(background)
+        });
+        assert_that!(killed, eq(true));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[gtest]
+    fn stops_reading_output_held_open_outside_the_group() {
+        // arrange
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+        let execution = sh("setsid sleep 5 & echo $!");
+        let started = Instant::now();
+
+        // act
+        let res = execution
+            .spawn(ExecutorOptions::default(), move |line| {
+                sink.lock().unwrap().push(line.line)
+            })
+            .unwrap()
+            .wait();
+
+        // assert
+        let elapsed = started.elapsed();
+        let escaped: i32 = lines.lock().unwrap()[0].parse().unwrap();
+        // SAFETY: only signals the sleep started above
+        unsafe {
+            libc::kill(escaped, libc::SIGKILL);
+        }
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(0))));
+        assert_that!(elapsed < Duration::from_secs(3), eq(true));
+    }
+
+    #[gtest]
+    fn cancels_running_command() {
+        // arrange
+        let running = sh("echo started; sleep 5")
+            .spawn(ExecutorOptions::default(), |_| {})
+            .unwrap();
+        let canceller = running.canceller();
+
+        // act
+        std::thread::sleep(Duration::from_millis(50));
+        canceller.cancel();
+        let res = running.wait();
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::CancelledError(
+                contains_substring("`sh` was cancelled")
+            )))
+        );
+    }
+
+    #[gtest]
+    fn reports_spawn_failure() {
+        // arrange
+        let execution = TerminalExecution::new("examen-missing-program".to_string(), vec![]);
+
+        // act
+        let res = execution.spawn(ExecutorOptions::default(), |_| {});
+
+        // assert
+        assert_that!(
+            res.err(),
+            some(matches_pattern!(ExecutionError::SpawnError(starts_with(
+                "failed to run `examen-missing-program`"
+            ))))
+        );
+    }
+}
@@ -0,0 +1,714 @@
+use std::collections::VecDeque;
+use std::path::Path;
+use std::process::ExitStatus;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use crate::core::config::{ConcurrencyPolicy, ExecutorConfig};
+use crate::core::errors::ExecutionError;
+use crate::core::types::Runnable;
+use crate::terminal::backend::{Executor, LineCallback, executor};
+use crate::terminal::core::TerminalExecution;
+use crate::terminal::executor::{Canceller, ExecutorOptions};
+
+// DEFAULT_MAX_JOBS
+//
+// Runs allowed at once when the configuration sets no `max_jobs`.
+pub(crate) const DEFAULT_MAX_JOBS: usize = 4;
+
+pub type ExitCallback = Box<dyn FnOnce(Result<ExitStatus, ExecutionError>) + Send>;
+
+pub type JobId = u64;
+
+// JobRequest
+//
+// A command to run for a runnable. Its package is the directory of the
+// runnable's file, the unit `go test` builds and caches.
+pub struct JobRequest {
+    runnable_id: String,
+    package: String,
+    execution: TerminalExecution,
+    options: ExecutorOptions,
+    // the executor of the registry when unset
+    executor: Option<Box<dyn Executor>>,
+    on_line: LineCallback,
+    on_exit: ExitCallback,
+}
+
+impl JobRequest {
+    pub fn new(runnable: &Runnable, execution: TerminalExecution) -> Self {
+        Self {
+            runnable_id: runnable.id(),
+            package: Path::new(&runnable.filepath)
+                .parent()
+                .map(|p| p.display().to_string())
+                .unwrap_or_default(),
+            execution,
+            options: ExecutorOptions::default(),
+            executor: None,
+            on_line: Box::new(|_| {}),
+            on_exit: Box::new(|_| {}),
+        }
+    }
+
+    pub fn with_options(mut self, options: ExecutorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    // with_executor
+    //
+    // Runs the job with the executor its project configured rather than the
+    // one of the registry.
+    pub fn with_executor(mut self, executor: Box<dyn Executor>) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
+    pub fn with_on_line(mut self, on_line: LineCallback) -> Self {
+        self.on_line = on_line;
+        self
+    }
+
+    // with_on_exit
+    //
+    // Called once the job is over, including when it was cancelled, replaced
+    // or failed to start.
+    pub fn with_on_exit(mut self, on_exit: ExitCallback) -> Self {
+        self.on_exit = on_exit;
+        self
+    }
+
+    fn conflicts(&self, runnable_id: &str, package: &str) -> bool {
+        self.runnable_id == runnable_id || self.package == package
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JobStatus {
+    Queued,
+    Running,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobInfo {
+    pub id: JobId,
+    pub runnable_id: String,
+    pub package: String,
+    pub command: String,
+    pub status: JobStatus,
+    pub pid: Option<u32>,
+}
+
+struct RunningJob {
+    id: JobId,
+    runnable_id: String,
+    package: String,
+    command: String,
+    pid: Option<u32>,
+    // unset while the executor starts the job
+    canceller: Option<Canceller>,
+    cancelled: bool,
+}
+
+impl RunningJob {
+    // cancel
+    //
+    // Cancels the job, or once started when the executor is still starting
+    // it.
+    fn cancel(&mut self) {
+        self.cancelled = true;
+        if let Some(canceller) = &self.canceller {
+            canceller.cancel();
+        }
+    }
+}
+
+struct QueuedJob {
+    id: JobId,
+    request: JobRequest,
+}
+
+// Start
+//
+// A job taken off the queue, for the executor to start once the state is
+// unlocked.
+struct Start {
+    id: JobId,
+    execution: TerminalExecution,
+    options: ExecutorOptions,
+    executor: Option<Box<dyn Executor>>,
+    on_line: LineCallback,
+    on_exit: ExitCallback,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: JobId,
+    running: Vec<RunningJob>,
+    queued: VecDeque<QueuedJob>,
+}
+
+struct Inner {
+    executor: Box<dyn Executor>,
+    policy: ConcurrencyPolicy,
+    max_jobs: usize,
+    state: Mutex<State>,
+}
+
+// callbacks left to call once the state is unlocked
+type Exits = Vec<(ExitCallback, ExecutionError)>;
+
+// ExecutionRegistry
+//
+// Tracks the runs in flight and decides, following the configured
+// `ConcurrencyPolicy`, what happens to a run of a runnable or package
+// already being tested. At most `max_jobs` runs execute at once, the others
+// wait their turn in submission order. Executors start jobs while the
+// registry is unlocked, as some take a while, e.g. tmux.
+#[derive(Clone)]
+pub struct ExecutionRegistry {
+    inner: Arc<Inner>,
+}
+
+impl ExecutionRegistry {
+    pub fn new(executor: Box<dyn Executor>, config: &ExecutorConfig) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                executor,
+                policy: config.policy.unwrap_or_default(),
+                max_jobs: config.max_jobs.unwrap_or(DEFAULT_MAX_JOBS).max(1),
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+
+    // from_config
+    //
+    // A registry running jobs with the configured executor, see `executor`.
+    pub fn from_config(config: &ExecutorConfig) -> Self {
+        Self::new(executor(config), config)
+    }
+
+    // submit
+    //
+    // Starts the job, or queues it until the runs in its way are over.
+    pub fn submit(&self, request: JobRequest) -> Result<JobId, ExecutionError> {
+        let mut exits = vec![];
+        let mut state = self.inner.lock();
+        match self.inner.policy {
+            ConcurrencyPolicy::Reject => {
+                let busy = state
+                    .running
+                    .iter()
+                    .map(|j| (&j.runnable_id, &j.package))
+                    .chain(
+                        state
+                            .queued
+                            .iter()
+                            .map(|j| (&j.request.runnable_id, &j.request.package)),
+                    )
+                    .any(|(runnable_id, package)| request.conflicts(runnable_id, package));
+                if busy {
+                    return Err(ExecutionError::RejectedError(format!(
+                        "`{}` is already being tested",
+                        request.package
+                    )));
+                }
+            }
+            ConcurrencyPolicy::Replace => {
+                for job in state.running.iter_mut() {
+                    if request.conflicts(&job.runnable_id, &job.package) {
+                        job.cancel();
+                    }
+                }
+                let (replaced, kept) = std::mem::take(&mut state.queued)
+                    .into_iter()
+                    .partition(|j| request.conflicts(&j.request.runnable_id, &j.request.package));
+                state.queued = kept;
+                exits.extend(replaced.into_iter().map(|j: QueuedJob| {
+                    let error = ExecutionError::CancelledError(format!(
+                        "`{}` was replaced before it started",
+                        j.request.execution.command
+                    ));
+                    (j.request.on_exit, error)
+                }));
+            }
+            ConcurrencyPolicy::Queue | ConcurrencyPolicy::Parallel => {}
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.queued.push_back(QueuedJob { id, request });
+        self.inner.dispatch(state, exits);
+        Ok(id)
+    }
+
+    // jobs
+    //
+    // The running jobs, then the queued ones in the order they will start.
+    pub fn jobs(&self) -> Vec<JobInfo> {
+        let state = self.inner.lock();
+        let running = state.running.iter().map(|j| JobInfo {
+            id: j.id,
+            runnable_id: j.runnable_id.to_string(),
+            package: j.package.to_string(),
+            command: j.command.to_string(),
+            status: JobStatus::Running,
+            pid: j.pid,
+        });
+        let queued = state.queued.iter().map(|j| JobInfo {
+            id: j.id,
+            runnable_id: j.request.runnable_id.to_string(),
+            package: j.request.package.to_string(),
+            command: j.request.execution.command.to_string(),
+            status: JobStatus::Queued,
+            pid: None,
+        });
+        running.chain(queued).collect()
+    }
+
+    // cancel
+    //
+    // Cancels the job, whether running or queued. False when no such job is
+    // known, e.g. it already finished.
+    pub fn cancel(&self, id: JobId) -> bool {
+        let mut state = self.inner.lock();
+        if let Some(job) = state.running.iter_mut().find(|j| j.id == id) {
+            job.cancel();
+            return true;
+        }
+        let Some(position) = state.queued.iter().position(|j| j.id == id) else {
+            return false;
+        };
+        let job = state.queued.remove(position).unwrap();
+        drop(state);
+        let error = cancelled(&job.request);
+        run_exits(vec![(job.request.on_exit, error)]);
+        true
+    }
+
+    // cancel_all
+    //
+    // Cancels every job, returning how many there were.
+    pub fn cancel_all(&self) -> usize {
+        let mut state = self.inner.lock();
+        for job in state.running.iter_mut() {
+            job.cancel();
+        }
+        let queued: Vec<QueuedJob> = state.queued.drain(..).collect();
+        let res = state.running.len() + queued.len();
+        drop(state);
+        run_exits(
+            queued
+                .into_iter()
+                .map(|j| {
+                    let error = cancelled(&j.request);
+                    (j.request.on_exit, error)
+                })
+                .collect(),
+        );
+        res
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // dispatch
+    //
+    // Starts the queued jobs nothing holds back anymore, once the state is
+    // unlocked, then calls the exit callbacks.
+    fn dispatch(self: &Arc<Self>, mut state: MutexGuard<'_, State>, exits: Exits) {
+        let starts = self.schedule(&mut state);
+        drop(state);
+        run_exits(exits);
+        for start in starts {
+            self.start(start);
+        }
+    }
+
+    // schedule
+    //
+    // Moves the queued jobs nothing holds back anymore to the running ones.
+    fn schedule(&self, state: &mut State) -> Vec<Start> {
+        let mut res = vec![];
+        let mut i = 0;
+        while i < state.queued.len() && state.running.len() < self.max_jobs {
+            let request = &state.queued[i].request;
+            let blocked = self.policy != ConcurrencyPolicy::Parallel
+                && state
+                    .running
+                    .iter()
+                    .any(|j| request.conflicts(&j.runnable_id, &j.package));
+            if blocked {
+                i += 1;
+                continue;
+            }
+
+            let QueuedJob { id, request } = state.queued.remove(i).unwrap();
+            state.running.push(RunningJob {
+                id,
+                runnable_id: request.runnable_id,
+                package: request.package,
+                command: request.execution.command.to_string(),
+                pid: None,
+                canceller: None,
+                cancelled: false,
+            });
+            res.push(Start {
+                id,
+                execution: request.execution,
+                options: request.options,
+                executor: request.executor,
+                on_line: request.on_line,
+                on_exit: request.on_exit,
+            });
+        }
+        res
+    }
+
+    // start
+    //
+    // Starts a job `schedule` moved to the running ones, the state being
+    // unlocked.
+    fn start(self: &Arc<Self>, start: Start) {
+        let Start {
+            id,
+            execution,
+            options,
+            executor,
+            on_line,
+            on_exit,
+        } = start;
+        let executor = executor.as_deref().unwrap_or(self.executor.as_ref());
+        let running = match executor.execute(&execution, options, on_line) {
+            Ok(running) => running,
+            Err(e) => {
+                self.finish(id);
+                on_exit(Err(e));
+                return;
+            }
+        };
+
+        let mut state = self.lock();
+        if let Some(job) = state.running.iter_mut().find(|j| j.id == id) {
+            job.pid = running.pid();
+            job.canceller = Some(running.canceller());
+            if job.cancelled {
+                running.cancel();
+            }
+        }
+        drop(state);
+        let inner = self.clone();
+        thread::spawn(move || {
+            let res = running.wait();
+            inner.finish(id);
+            on_exit(res);
+        });
+    }
+
+    fn finish(self: &Arc<Self>, id: JobId) {
+        let mut state = self.lock();
+        state.running.retain(|j| j.id != id);
+        self.dispatch(state, vec![]);
+    }
+}
+
+fn cancelled(request: &JobRequest) -> ExecutionError {
+    ExecutionError::CancelledError(format!(
+        "`{}` was cancelled before it started",
+        request.execution.command
+    ))
+}
+
+fn run_exits(exits: Exits) {
+    for (on_exit, error) in exits {
+        on_exit(Err(error));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Range;
+    use std::process::ExitStatus;
+    use std::sync::Mutex;
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::thread;
+    use std::time::Duration;
+
+    use googletest::prelude::*;
+
+    use super::{ExecutionRegistry, JobInfo, JobRequest, JobStatus};
+    use crate::core::config::{ConcurrencyPolicy, ExecutorConfig};
+    use crate::core::errors::ExecutionError;
+    use crate::core::metadata::RunnableMeta;
+    use crate::core::types::{CursorPosition, Runnable};
+    use crate::terminal::backend::{Executor, LineCallback, ProcessExecutor};
+    use crate::terminal::core::TerminalExecution;
+    use crate::terminal::executor::{ExecutorOptions, RunningExecution};
+
+    type Exit = (
+        &'static str,
+        std::result::Result<ExitStatus, ExecutionError>,
+    );
+
+    // GatedExecutor
+    //
+    // Starts processes once released, telling when it was asked to.
+    struct GatedExecutor {
+        entered: Sender<()>,
+        release: Mutex<Receiver<()>>,
+    }
+
+    impl Executor for GatedExecutor {
+        fn name(&self) -> &str {
+            "gated"
+        }
+
+        fn execute(
+            &self,
+            execution: &TerminalExecution,
+            options: ExecutorOptions,
+            on_line: LineCallback,
+        ) -> std::result::Result<RunningExecution, ExecutionError> {
+            self.entered.send(()).unwrap();
+            self.release.lock().unwrap().recv().unwrap();
+            ProcessExecutor.execute(execution, options, on_line)
+        }
+    }
+
+    fn registry(policy: ConcurrencyPolicy, max_jobs: usize) -> ExecutionRegistry {
+        let config = ExecutorConfig {
+            policy: Some(policy),
+            max_jobs: Some(max_jobs),
+            ..Default::default()
+        };
+        ExecutionRegistry::new(Box::new(ProcessExecutor), &config)
+    }
+
+    fn runnable(filepath: &str, name: &str) -> Runnable {
+        Runnable {
+            name: name.to_string(),
+            filepath: filepath.to_string(),
+            range: Range {
+                start: CursorPosition::new(0, 0),
+                end: CursorPosition::new(1, 0),
+            },
+            meta: RunnableMeta::default_golang(),
+        }
+    }
+
+    // request
+    //
+    // A job running the script, its exit sent labelled on `exits`.
+    fn request(
+        runnable: &Runnable,
+        script: &str,
+        label: &'static str,
+        exits: &mpsc::Sender<Exit>,
+    ) -> JobRequest {
+        let exits = exits.clone();
+        JobRequest::new(
+            runnable,
+            TerminalExecution::new("sh".to_string(), vec!["-c".to_string(), script.to_string()]),
+        )
+        .with_on_exit(Box::new(move |res| {
+            let _ = exits.send((label, res));
+        }))
+    }
+
+    fn next(exits: &Receiver<Exit>) -> Exit {
+        exits.recv_timeout(Duration::from_secs(10)).unwrap()
+    }
+
+    fn statuses(registry: &ExecutionRegistry) -> Vec<JobStatus> {
+        registry.jobs().iter().map(|j: &JobInfo| j.status).collect()
+    }
+
+    #[gtest]
+    fn replace_cancels_run_of_same_package() {
+        // arrange
+        let registry = registry(ConcurrencyPolicy::Replace, 4);
+        let (sender, exits) = mpsc::channel();
+        registry
+            .submit(request(
+                &runnable("pkg/calc/calc_test.go", "^TestAdd$"),
+                "sleep 5",
+                "first",
+                &sender,
+            ))
+            .unwrap();
+
+        // act
+        registry
+            .submit(request(
+                &runnable("pkg/calc/sub_test.go", "^TestSub$"),
+                "exit 0",
+                "second",
+                &sender,
+            ))
+            .unwrap();
+
+        // assert
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("first"));
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::CancelledError(anything())))
+        );
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("second"));
+        assert_that!(res.map(|s| s.success()), ok(eq(&true)));
+    }
+
+    #[gtest]
+    fn queue_waits_for_run_of_same_runnable() {
+        // arrange
+        let registry = registry(ConcurrencyPolicy::Queue, 4);
+        let (sender, exits) = mpsc::channel();
+        let runnable = runnable("pkg/calc/calc_test.go", "^TestAdd$");
+
+        // act
+        registry
+            .submit(request(&runnable, "sleep 0.2", "first", &sender))
+            .unwrap();
+        registry
+            .submit(request(&runnable, "exit 3", "second", &sender))
+            .unwrap();
+
+        // assert
+        assert_that!(
+            statuses(&registry),
+            elements_are![eq(&JobStatus::Running), eq(&JobStatus::Queued)]
+        );
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("first"));
+        assert_that!(res.map(|s| s.success()), ok(eq(&true)));
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("second"));
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(3))));
+        assert_that!(registry.jobs(), is_empty());
+    }
+
+    #[gtest]
+    fn reject_refuses_run_of_busy_package() {
+        // arrange
+        let registry = registry(ConcurrencyPolicy::Reject, 4);
+        let (sender, _exits) = mpsc::channel();
+        registry
+            .submit(request(
+                &runnable("pkg/calc/calc_test.go", "^TestAdd$"),
+                "sleep 5",
+                "first",
+                &sender,
+            ))
+            .unwrap();
+
+        // act
+        let res = registry.submit(request(
+            &runnable("pkg/calc/calc_test.go", "^TestSub$"),
+            "exit 0",
+            "second",
+            &sender,
+        ));
+
+        // assert
+        assert_that!(
+            res,
+            err(eq(&ExecutionError::RejectedError(
+                "`pkg/calc` is already being tested".to_string()
+            )))
+        );
+        assert_that!(registry.cancel_all(), eq(1));
+    }
+
+    #[gtest]
+    fn max_jobs_limits_parallel_runs() {
+        // arrange
+        let registry = registry(ConcurrencyPolicy::Parallel, 1);
+        let (sender, exits) = mpsc::channel();
+        let first = registry
+            .submit(request(
+                &runnable("pkg/calc/calc_test.go", "^TestAdd$"),
+                "sleep 5",
+                "first",
+                &sender,
+            ))
+            .unwrap();
+        let second = registry
+            .submit(request(
+                &runnable("pkg/fmt/fmt_test.go", "^TestFormat$"),
+                "exit 0",
+                "second",
+                &sender,
+            ))
+            .unwrap();
+        assert_that!(
+            statuses(&registry),
+            elements_are![eq(&JobStatus::Running), eq(&JobStatus::Queued)]
+        );
+
+        // act
+        let cancelled = registry.cancel(first);
+
+        // assert
+        assert_that!(cancelled, eq(true));
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("first"));
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::CancelledError(anything())))
+        );
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("second"));
+        assert_that!(res.map(|s| s.success()), ok(eq(&true)));
+        assert_that!(registry.cancel(second), eq(false));
+    }
+
+    #[gtest]
+    fn cancels_job_while_executor_starts_it() {
+        // arrange
+        let (entered, entering) = mpsc::channel();
+        let (releasing, release) = mpsc::channel();
+        let executor = GatedExecutor {
+            entered,
+            release: Mutex::new(release),
+        };
+        let registry = ExecutionRegistry::new(Box::new(executor), &ExecutorConfig::default());
+        let (sender, exits) = mpsc::channel();
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                registry
+                    .submit(request(
+                        &runnable("pkg/calc/calc_test.go", "^TestAdd$"),
+                        "sleep 5",
+                        "first",
+                        &sender,
+                    ))
+                    .unwrap()
+            });
+            entering.recv_timeout(Duration::from_secs(10)).unwrap();
+
+            // act
+            let jobs = registry.jobs();
+            let cancelled = registry.cancel(jobs[0].id);
+            releasing.send(()).unwrap();
+
+            // assert
+            assert_that!(cancelled, eq(true));
+            assert_that!(jobs[0].status, eq(JobStatus::Running));
+            assert_that!(jobs[0].pid, none());
+        });
+        let (label, res) = next(&exits);
+        assert_that!(label, eq("first"));
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::CancelledError(anything())))
+        );
+        assert_that!(registry.jobs(), is_empty());
+    }
+}
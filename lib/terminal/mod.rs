@@ -1,2 +1,7 @@
+pub(crate) mod backend;
 pub(crate) mod commands;
-mod core;
+pub(crate) mod core;
+pub(crate) mod executor;
+pub(crate) mod jobs;
+#[cfg(unix)]
+pub(crate) mod tmux;
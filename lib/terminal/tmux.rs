@@ -0,0 +1,550 @@
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::errors::ExecutionError;
+use crate::terminal::backend::{Executor, LineCallback};
+use crate::terminal::core::TerminalExecution;
+use crate::terminal::executor::{
+    ExecutorOptions, OutputLine, POLL_INTERVAL, RunningExecution, Stream,
+};
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+// IDLE_TIMEOUT
+//
+// How long a pane may stay busy before a command is typed into it, e.g.
+// while the shell of the previous run exits.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(1);
+
+// STOP_TIMEOUT
+//
+// How long a cancelled command may take to stop after `C-c`.
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+// TmuxExecutor
+//
+// Types commands into a tmux window, created when missing, so long runs can
+// be watched and scrolled in a pane. The command is wrapped to copy its
+// output to a log file examen tails, and to record its exit code in a
+// status file once done:
+//
+// sh -c '{ <command>; echo $? > status.tmp; } 2>&1 | tee output.log; mv status.tmp status'
+//
+// Commands are only typed into an idle pane, one whose shell runs nothing.
+// Cancelling sends `C-c` to the pane, as a user would, and waits for the
+// command to stop.
+#[derive(Clone)]
+pub struct TmuxExecutor {
+    // `[session:]window`
+    target: String,
+    // `-L` socket name, the default server when unset
+    socket: Option<String>,
+}
+
+impl TmuxExecutor {
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+            socket: None,
+        }
+    }
+
+    pub fn with_socket(mut self, socket: &str) -> Self {
+        self.socket = Some(socket.to_string());
+        self
+    }
+
+    fn tmux(&self, args: &[&str]) -> Result<String, ExecutionError> {
+        let mut command = Command::new("tmux");
+        if let Some(socket) = &self.socket {
+            command.args(["-L", socket]);
+        }
+        let output = command
+            .args(args)
+            .output()
+            .map_err(|e| ExecutionError::SpawnError(format!("failed to run `tmux`: {}", e)))?;
+        if !output.status.success() {
+            return Err(ExecutionError::SpawnError(format!(
+                "`tmux {}` failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    // pane
+    //
+    // The id of the pane of the target window, creating the window in the
+    // background when it does not exist.
+    fn pane(&self) -> Result<String, ExecutionError> {
+        let (session, window) = match self.target.split_once(':') {
+            Some((session, window)) => (Some(session), window),
+            None => (None, self.target.as_str()),
+        };
+        // targets given to tmux match loosely, windows are looked up by name
+        let panes = self.tmux(&[
+            "list-panes",
+            "-a",
+            "-F",
+            "#{session_name}\t#{window_name}\t#{pane_id}",
+        ])?;
+        let pane = panes.lines().find_map(|line| {
+            let mut fields = line.split('\t');
+            let (s, w, pane) = (fields.next()?, fields.next()?, fields.next()?);
+            (w == window && session.is_none_or(|session| session == s)).then(|| pane.to_string())
+        });
+        if let Some(pane) = pane {
+            return Ok(pane);
+        }
+
+        let mut args = vec!["new-window", "-d", "-P", "-F", "#{pane_id}", "-n", window];
+        let session_target = session.map(|s| format!("{}:", s));
+        if let Some(session_target) = session_target.as_deref() {
+            args.extend(["-t", session_target]);
+        }
+        self.tmux(&args)
+    }
+
+    // busy
+    //
+    // The program the pane runs besides its shell, none when idle. Keys
+    // sent to a busy pane would be typed into that program.
+    fn busy(&self, pane: &str) -> Result<Option<String>, ExecutionError> {
+        let info = self.tmux(&[
+            "display-message",
+            "-p",
+            "-t",
+            pane,
+            "#{pane_pid}\t#{pane_current_command}",
+        ])?;
+        let (pid, command) = info.split_once('\t').unwrap_or((info.as_str(), ""));
+        let children = Command::new("pgrep")
+            .args(["-P", pid])
+            .output()
+            .map_err(|e| ExecutionError::SpawnError(format!("failed to run `pgrep`: {}", e)))?;
+        Ok(children.status.success().then(|| command.to_string()))
+    }
+
+    // wait_idle
+    //
+    // Waits up to `timeout` for the pane to become idle, returning what it
+    // still runs otherwise.
+    fn wait_idle(&self, pane: &str, timeout: Duration) -> Result<Option<String>, ExecutionError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let busy = self.busy(pane)?;
+            if busy.is_none() || Instant::now() >= deadline {
+                return Ok(busy);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+// wrapper
+//
+// The shell line typed in the pane.
+pub(crate) fn wrapper(execution: &TerminalExecution, directory: &Path) -> String {
+    let path =
+        |name: &str| shell_words::quote(&directory.join(name).display().to_string()).to_string();
+    let script = format!(
+        "{{ {}; echo $? > {}; }} 2>&1 | tee {}; mv {} {}",
+        execution,
+        path("status.tmp"),
+        path("output.log"),
+        path("status.tmp"),
+        path("status")
+    );
+    format!("sh -c {}", shell_words::quote(&script))
+}
+
+impl Executor for TmuxExecutor {
+    fn name(&self) -> &str {
+        "tmux"
+    }
+
+    fn execute(
+        &self,
+        execution: &TerminalExecution,
+        options: ExecutorOptions,
+        on_line: LineCallback,
+    ) -> Result<RunningExecution, ExecutionError> {
+        let pane = self.pane()?;
+        let directory = std::env::temp_dir().join(format!(
+            "examen-tmux-{}-{}",
+            std::process::id(),
+            RUNS.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&directory)
+            .map_err(|e| ExecutionError::IoError(format!("{}: {}", directory.display(), e)))?;
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let poller = Poller {
+            command: execution.command.clone(),
+            line: wrapper(execution, &directory),
+            directory,
+            tmux: self.clone(),
+            pane,
+            cancelled: cancelled.clone(),
+            deadline: options.timeout.map(|t| (Instant::now() + t, t)),
+        };
+        let handle = thread::Builder::new()
+            .name(format!("examen tmux: {}", execution.command))
+            .spawn(move || poller.run(on_line))
+            .map_err(|e| ExecutionError::SpawnError(format!("failed to poll tmux: {}", e)))?;
+        Ok(RunningExecution::new(None, cancelled, handle))
+    }
+}
+
+struct Poller {
+    command: String,
+    // shell line typed in the pane, see `wrapper`
+    line: String,
+    directory: PathBuf,
+    tmux: TmuxExecutor,
+    pane: String,
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<(Instant, Duration)>,
+}
+
+impl Poller {
+    // run
+    //
+    // Types the command once the pane is idle, tails the log until the
+    // status file appears, then reports the exit code it records. The files
+    // of a command interrupted by `C-c` are removed once it stopped, and
+    // left behind when it does not.
+    fn run(self, mut on_line: LineCallback) -> Result<ExitStatus, ExecutionError> {
+        if let Err(e) = self.start() {
+            let _ = fs::remove_dir_all(&self.directory);
+            return Err(e);
+        }
+        let res = self.poll(&mut on_line);
+        let stopped = res.is_ok()
+            || self
+                .tmux
+                .wait_idle(&self.pane, STOP_TIMEOUT)
+                .is_ok_and(|busy| busy.is_none());
+        if stopped {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+        res
+    }
+
+    // start
+    //
+    // Waits for the pane to become idle, away from the thread the run was
+    // started from, then types the command.
+    fn start(&self) -> Result<(), ExecutionError> {
+        if let Some(command) = self.tmux.wait_idle(&self.pane, IDLE_TIMEOUT)? {
+            return Err(ExecutionError::SpawnError(format!(
+                "tmux pane {} of `{}` is busy running `{}`",
+                self.pane, self.tmux.target, command
+            )));
+        }
+        if self.cancelled.load(Ordering::SeqCst) {
+            return Err(ExecutionError::CancelledError(format!(
+                "`{}` was cancelled",
+                self.command
+            )));
+        }
+        self.tmux
+            .tmux(&["send-keys", "-t", &self.pane, "-l", &self.line])?;
+        self.tmux.tmux(&["send-keys", "-t", &self.pane, "Enter"])?;
+        Ok(())
+    }
+
+    fn interrupt(&self) {
+        let _ = self.tmux.tmux(&["send-keys", "-t", &self.pane, "C-c"]);
+    }
+
+    fn poll(&self, on_line: &mut LineCallback) -> Result<ExitStatus, ExecutionError> {
+        let log = self.directory.join("output.log");
+        let status = self.directory.join("status");
+        let mut offset = 0;
+        let mut pending = String::new();
+        loop {
+            let done = status.is_file();
+            offset = self.tail(&log, offset, &mut pending, on_line)?;
+            if done {
+                if !pending.is_empty() {
+                    on_line(OutputLine {
+                        stream: Stream::Stdout,
+                        line: std::mem::take(&mut pending),
+                    });
+                }
+                let code = fs::read_to_string(&status).map_err(|e| self.io_error(&status, e))?;
+                let code: i32 = code.trim().parse().map_err(|_| {
+                    ExecutionError::IoError(format!(
+                        "{}: `{}` is not an exit code",
+                        status.display(),
+                        code.trim()
+                    ))
+                })?;
+                return Ok(ExitStatus::from_raw((code & 0xff) << 8));
+            }
+            if self.cancelled.load(Ordering::SeqCst) {
+                self.interrupt();
+                return Err(ExecutionError::CancelledError(format!(
+                    "`{}` was cancelled",
+                    self.command
+                )));
+            }
+            if let Some((deadline, timeout)) = self.deadline
+                && Instant::now() >= deadline
+            {
+                self.interrupt();
+                return Err(ExecutionError::TimeoutError(format!(
+                    "`{}` did not finish within {:?}",
+                    self.command, timeout
+                )));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    // tail
+    //
+    // Hands the complete lines written to the log since `offset` to the
+    // callback, keeping a trailing partial line for later.
+    fn tail(
+        &self,
+        log: &Path,
+        offset: u64,
+        pending: &mut String,
+        on_line: &mut LineCallback,
+    ) -> Result<u64, ExecutionError> {
+        let Ok(mut file) = fs::File::open(log) else {
+            return Ok(offset);
+        };
+        let mut bytes = vec![];
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_to_end(&mut bytes))
+            .map_err(|e| self.io_error(log, e))?;
+        pending.push_str(&String::from_utf8_lossy(&bytes));
+        while let Some(end) = pending.find('\n') {
+            let line: String = pending.drain(..=end).collect();
+            on_line(OutputLine {
+                stream: Stream::Stdout,
+                line: line.trim_end_matches(['\n', '\r']).to_string(),
+            });
+        }
+        Ok(offset + bytes.len() as u64)
+    }
+
+    fn io_error(&self, path: &Path, e: std::io::Error) -> ExecutionError {
+        ExecutionError::IoError(format!("{}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+    use std::process::Command;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use googletest::prelude::*;
+
+    use super::{TmuxExecutor, wrapper};
+    use crate::core::errors::ExecutionError;
+    use crate::terminal::backend::Executor;
+    use crate::terminal::core::TerminalExecution;
+    use crate::terminal::executor::ExecutorOptions;
+
+    // Server
+    //
+    // A tmux server of its own per test, killed once done.
+    struct Server(String);
+
+    impl Server {
+        // start
+        //
+        // None when tmux is not installed, the test is then skipped. A tmux
+        // failing to start a server fails the test.
+        fn start(name: &str) -> Option<Self> {
+            if Command::new("tmux").arg("-V").output().is_err() {
+                eprintln!("skipped: tmux is not installed");
+                return None;
+            }
+            let socket = format!("examen-{}-{}", name, std::process::id());
+            let status = Command::new("tmux")
+                .args([
+                    "-L",
+                    &socket,
+                    "-f",
+                    "/dev/null",
+                    "new-session",
+                    "-d",
+                    "-s",
+                    "work",
+                ])
+                .env("SHELL", "/bin/sh")
+                .status()
+                .unwrap();
+            assert!(status.success(), "tmux failed to start a server");
+            Some(Self(socket))
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            let _ = Command::new("tmux")
+                .args(["-L", &self.0, "kill-server"])
+                .status();
+        }
+    }
+
+    fn sh(script: &str) -> TerminalExecution {
+        TerminalExecution::new("sh".to_string(), vec!["-c".to_string(), script.to_string()])
+    }
+
+    #[gtest]
+    fn wrapper_records_exit_code() {
+        let res = wrapper(&sh("exit 3"), Path::new("/tmp/run"));
+
+        assert_that!(
+            res,
+            eq(
+                "sh -c '{ sh -c '\\''exit 3'\\''; echo $? > /tmp/run/status.tmp; } 2>&1 | tee /tmp/run/output.log; mv /tmp/run/status.tmp /tmp/run/status'"
+            )
+        );
+    }
+
+    #[gtest]
+    fn runs_command_in_named_window() {
+        // arrange
+        let Some(server) = Server::start("run") else {
+            return;
+        };
+        let executor = TmuxExecutor::new("work:tests").with_socket(&server.0);
+        let lines = Arc::new(Mutex::new(vec![]));
+        let sink = lines.clone();
+
+        // act
+        let res = executor
+            .execute(
+                &sh("echo one; echo two >&2; exit 4"),
+                ExecutorOptions {
+                    timeout: Some(Duration::from_secs(10)),
+                },
+                Box::new(move |line| sink.lock().unwrap().push(line.line)),
+            )
+            .unwrap()
+            .wait();
+
+        // assert
+        assert_that!(res.map(|s| s.code()), ok(eq(&Some(4))));
+        assert_that!(
+            *lines.lock().unwrap(),
+            container_eq(vec!["one".to_string(), "two".to_string()])
+        );
+        let windows = Command::new("tmux")
+            .args([
+                "-L",
+                &server.0,
+                "list-windows",
+                "-t",
+                "work",
+                "-F",
+                "#{window_name}",
+            ])
+            .output()
+            .unwrap();
+        assert_that!(
+            String::from_utf8_lossy(&windows.stdout).to_string(),
+            contains_substring("tests")
+        );
+    }
+
+    #[gtest]
+    fn cancels_command_in_window() {
+        // arrange
+        let Some(server) = Server::start("cancel") else {
+            return;
+        };
+        let executor = TmuxExecutor::new("work:tests").with_socket(&server.0);
+        let running = executor
+            .execute(
+                &sh("sleep 30"),
+                ExecutorOptions::default(),
+                Box::new(|_| {}),
+            )
+            .unwrap();
+
+        // act
+        std::thread::sleep(Duration::from_millis(100));
+        running.cancel();
+        let res = running.wait();
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::CancelledError(
+                contains_substring("`sh` was cancelled")
+            )))
+        );
+        let pane = executor.pane().unwrap();
+        assert_that!(executor.busy(&pane), ok(none()));
+    }
+
+    #[gtest]
+    fn refuses_busy_pane() {
+        // arrange
+        let Some(server) = Server::start("busy") else {
+            return;
+        };
+        let executor = TmuxExecutor::new("work:tests").with_socket(&server.0);
+        // the window runs a shell waiting on `sleep` rather than a prompt,
+        // so the pane is busy once the shell forked it, with no typing
+        let pane = executor
+            .tmux(&[
+                "new-window",
+                "-d",
+                "-P",
+                "-F",
+                "#{pane_id}",
+                "-n",
+                "tests",
+                "-t",
+                "work:",
+                "sleep 30; exit",
+            ])
+            .unwrap();
+        let started = Instant::now();
+        while executor.busy(&pane).unwrap().is_none() {
+            assert!(
+                started.elapsed() < Duration::from_secs(10),
+                "the pane never ran `sleep`"
+            );
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        // act
+        let running = executor
+            .execute(
+                &sh("echo one"),
+                ExecutorOptions::default(),
+                Box::new(|_| {}),
+            )
+            .unwrap();
+        let res = running.wait();
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(ExecutionError::SpawnError(all!(
+                contains_substring("is busy running `sh`"),
+                contains_substring("work:tests")
+            ))))
+        );
+    }
+}
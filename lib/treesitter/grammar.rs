@@ -0,0 +1,26 @@
+use tree_sitter::Language as Grammar;
+
+use crate::core::enums::Language;
+
+// grammar
+//
+// The tree-sitter grammar parsing files of a language. Typescript and tsx
+// are separate grammars, picked from the file extension.
+pub(crate) fn grammar(language: &Language, filepath: &str) -> Option<Grammar> {
+    let res = match language {
+        Language::Golang => tree_sitter_go::LANGUAGE.into(),
+        Language::Rust => tree_sitter_rust::LANGUAGE.into(),
+        Language::Python => tree_sitter_python::LANGUAGE.into(),
+        Language::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+        Language::TypeScript if filepath.ends_with(".tsx") => {
+            tree_sitter_typescript::LANGUAGE_TSX.into()
+        }
+        Language::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        Language::Lua => tree_sitter_lua::LANGUAGE.into(),
+        Language::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+        Language::Java => tree_sitter_java::LANGUAGE.into(),
+        Language::Ruby => tree_sitter_ruby::LANGUAGE.into(),
+        Language::Unsupported => return None,
+    };
+    Some(res)
+}
@@ -1,2 +1,3 @@
+pub(crate) mod grammar;
 pub(crate) mod node;
-
+pub(crate) mod query_file;
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, RwLock};
+use std::time::SystemTime;
+
+use tree_sitter::{Language, Query};
+
+use crate::core::errors::FrameworkError;
+use crate::core::paths::config_dir;
+
+// RUNTIME_PATHS
+//
+// Directories searched for `queries/<language>/examen_<name>.scm` files, highest
+// priority first, the user configuration directory by default. Editors set
+// them to their own runtime path, e.g. `nvim_list_runtime_paths()`, when
+// initializing the engine.
+static RUNTIME_PATHS: LazyLock<RwLock<Vec<PathBuf>>> =
+    LazyLock::new(|| RwLock::new(config_dir().into_iter().collect()));
+
+pub fn set_runtime_paths(paths: Vec<PathBuf>) {
+    if let Ok(mut runtime_paths) = RUNTIME_PATHS.write() {
+        *runtime_paths = paths;
+    }
+}
+
+pub(crate) fn runtime_paths() -> Vec<PathBuf> {
+    RUNTIME_PATHS
+        .read()
+        .map(|paths| paths.clone())
+        .unwrap_or_default()
+}
+
+// Fingerprint
+//
+// The candidate files of a query with their modification time, None for
+// the missing ones.
+type Fingerprint = Vec<(PathBuf, Option<SystemTime>)>;
+
+// Loaded
+//
+// A resolution of a query along with the files it was resolved from.
+type Loaded = (Fingerprint, Result<String, FrameworkError>);
+
+// LOADED
+//
+// The last resolution of every query, reused as long as its candidate files
+// are unchanged, sparing the reads and compilations on each search.
+static LOADED: LazyLock<Mutex<HashMap<(&str, &str), Loaded>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// QueryFile
+//
+// A tree-sitter query shipped as an embedded `.scm` asset that users can
+// replace or extend, nvim-treesitter style: the first file on the runtime
+// path replaces the embedded query, files starting with a `; extends`
+// modeline are appended to it instead.
+pub(crate) struct QueryFile {
+    pub(crate) language: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) embedded: &'static str,
+    // captures the operations running the query rely on
+    pub(crate) captures: &'static [&'static str],
+    pub(crate) grammar: fn() -> Language,
+}
+
+impl QueryFile {
+    // source
+    //
+    // The query to run. A user query failing validation falls back to the
+    // embedded one, providers report the failure through `load`.
+    pub(crate) fn source(&self) -> String {
+        self.load(&runtime_paths())
+            .unwrap_or_else(|_| self.embedded.to_string())
+    }
+
+    // load
+    //
+    // Resolves the query from the runtime paths, failing with the file at
+    // fault when a user query does not compile or misses a capture.
+    pub(crate) fn load(&self, runtime_paths: &[PathBuf]) -> Result<String, FrameworkError> {
+        let files: Vec<PathBuf> = runtime_paths
+            .iter()
+            .map(|directory| {
+                directory
+                    .join("queries")
+                    .join(self.language)
+                    .join(format!("examen_{}.scm", self.name))
+            })
+            .collect();
+        let fingerprint: Fingerprint = files
+            .iter()
+            .map(|file| {
+                let modified = fs::metadata(file).and_then(|m| m.modified()).ok();
+                (file.clone(), modified)
+            })
+            .collect();
+        let key = (self.language, self.name);
+        if let Ok(loaded) = LOADED.lock()
+            && let Some((cached, res)) = loaded.get(&key)
+            && *cached == fingerprint
+        {
+            return res.clone();
+        }
+
+        let res = self.resolve(&files);
+        if let Ok(mut loaded) = LOADED.lock() {
+            loaded.insert(key, (fingerprint, res.clone()));
+        }
+        res
+    }
+
+    fn resolve(&self, files: &[PathBuf]) -> Result<String, FrameworkError> {
+        let mut base: Option<String> = None;
+        let mut extensions: Vec<String> = vec![];
+        for file in files {
+            let Ok(content) = fs::read_to_string(file) else {
+                continue;
+            };
+            self.compile(&content, &file.display().to_string())?;
+            if extends(&content) {
+                extensions.push(content);
+            } else if base.is_none() {
+                base = Some(content);
+            }
+        }
+        if base.is_none() && extensions.is_empty() {
+            return Ok(self.embedded.to_string());
+        }
+
+        let mut res = base.unwrap_or_else(|| self.embedded.to_string());
+        for extension in extensions {
+            res.push('\n');
+            res.push_str(&extension);
+        }
+        let query = self.compile(&res, "runtime path")?;
+        for capture in self.captures {
+            if !query.capture_names().contains(capture) {
+                return Err(FrameworkError::QueryError(format!(
+                    "{} query `{}` must capture `@{}`",
+                    self.language, self.name, capture
+                )));
+            }
+        }
+        Ok(res)
+    }
+
+    fn compile(&self, content: &str, origin: &str) -> Result<Query, FrameworkError> {
+        Query::new(&(self.grammar)(), content).map_err(|e| {
+            FrameworkError::QueryError(format!(
+                "{} query `{}` from {} failed to compile: {}",
+                self.language, self.name, origin, e
+            ))
+        })
+    }
+}
+
+// extends
+//
+// Whether the leading comments of a query file hold the `; extends` modeline.
+fn extends(content: &str) -> bool {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with(';'))
+        .any(|line| line.trim_start_matches(';').trim() == "extends")
+}
+
+// validate
+//
+// Loads every query, returning the first failure.
+pub(crate) fn validate(queries: &[&QueryFile]) -> Result<(), FrameworkError> {
+    let paths = runtime_paths();
+    for query in queries {
+        query.load(&paths)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use googletest::prelude::*;
+    use rstest::rstest;
+    use tree_sitter::{Parser, Query, QueryCursor};
+
+    use super::QueryFile;
+    use crate::core::errors::FrameworkError;
+    use crate::treesitter::node::node_text;
+
+    static SUBTEST: QueryFile = QueryFile {
+        language: "go",
+        name: "subtest",
+        embedded: r#"
+(call_expression
+  function: (selector_expression
+    field: (field_identifier) @method (#eq? @method "Run"))
+  arguments: (argument_list
+    (interpreted_string_literal) @test.case.field.value)) @test.case
+"#,
+        captures: &["test.case", "test.case.field.value"],
+        grammar: || tree_sitter_go::LANGUAGE.into(),
+    };
+
+    const CONTENT: &str = r#"package calculator
+
+func TestAdd(t *testing.T) {
+	for name, tt := range map[string]struct{ a, b int }{
+		"base case": {a: 0, b: 1},
+	} {
+		t.Run(name, func(t *testing.T) {})
+	}
+	t.Run("literal", func(t *testing.T) {})
+}
+"#;
+
+    fn runtime_path(name: &str) -> PathBuf {
+        PathBuf::from(format!(
+            "{}/lib/fixtures/queries/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+    }
+
+    fn subtests(source: &str) -> Vec<String> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_go::LANGUAGE.into())
+            .unwrap();
+        let tree = parser.parse(CONTENT, None).unwrap();
+        let query = Query::new(&tree_sitter_go::LANGUAGE.into(), source).unwrap();
+        let index = query
+            .capture_index_for_name("test.case.field.value")
+            .unwrap();
+        let mut cursor = QueryCursor::new();
+        cursor
+            .matches(&query, tree.root_node(), CONTENT.as_bytes())
+            .flat_map(|m| m.captures.to_vec())
+            .filter(|c| c.index == index)
+            .map(|c| node_text(c.node, CONTENT))
+            .collect()
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(vec![], vec!["\"literal\""])]
+    #[case(vec!["missing"], vec!["\"literal\""])]
+    #[case(vec!["override"], vec!["name"])]
+    #[case(vec!["extends"], vec!["\"base case\"", "\"literal\""])]
+    #[case(vec!["override", "extends"], vec!["\"base case\"", "name"])]
+    fn resolves_runtime_queries(#[case] paths: Vec<&str>, #[case] expected: Vec<&str>) {
+        // arrange
+        let paths: Vec<PathBuf> = paths.into_iter().map(runtime_path).collect();
+
+        // act
+        let res = SUBTEST.load(&paths);
+
+        // assert
+        let mut names = subtests(&res.unwrap());
+        names.sort();
+        let expected: Vec<String> = expected.iter().map(|e| e.to_string()).collect();
+        assert_that!(names, container_eq(expected));
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("broken", "broken/queries/go/examen_subtest.scm failed to compile")]
+    #[case(
+        "missing_capture",
+        "go query `subtest` must capture `@test.case.field.value`"
+    )]
+    fn reports_invalid_user_queries(#[case] path: &str, #[case] expected: &str) {
+        // act
+        let res = SUBTEST.load(&[runtime_path(path)]);
+
+        // assert
+        assert_that!(
+            res,
+            err(matches_pattern!(FrameworkError::QueryError(
+                contains_substring(expected)
+            )))
+        );
+    }
+
+    #[gtest]
+    fn reloads_changed_queries_only() {
+        // arrange
+        static CACHED: QueryFile = QueryFile {
+            name: "cached_subtest",
+            ..SUBTEST
+        };
+        let root = std::env::temp_dir().join(format!("examen-queries-{}", std::process::id()));
+        let directory = root.join("queries").join("go");
+        fs::create_dir_all(&directory).unwrap();
+        let file = directory.join("examen_cached_subtest.scm");
+        let write = |content: &str, modified: SystemTime| {
+            fs::write(&file, content).unwrap();
+            fs::File::options()
+                .write(true)
+                .open(&file)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        };
+        let override_query =
+            fs::read_to_string(runtime_path("override").join("queries/go/examen_subtest.scm"))
+                .unwrap();
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        write(&override_query, epoch);
+        let first = CACHED.load(std::slice::from_ref(&root)).unwrap();
+
+        // act
+        write("(broken", epoch);
+        let unchanged = CACHED.load(std::slice::from_ref(&root));
+        write("(broken", epoch + Duration::from_secs(1));
+        let changed = CACHED.load(std::slice::from_ref(&root));
+
+        // assert
+        assert_that!(first, eq(&override_query));
+        assert_that!(unchanged, ok(eq(&override_query)));
+        assert_that!(
+            changed,
+            err(matches_pattern!(FrameworkError::QueryError(
+                contains_substring("examen_cached_subtest.scm failed to compile")
+            )))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
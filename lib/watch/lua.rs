@@ -0,0 +1,363 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use mlua::{Function, Lua, Table};
+
+use crate::core::config::Config;
+use crate::core::enums::Language;
+use crate::core::trust::TrustDatabase;
+use crate::engine::builtin_providers;
+use crate::terminal::executor::Stream;
+use crate::terminal::jobs::ExecutionRegistry;
+use crate::watch::session::{
+    WatchEvent, WatchScope, WatchSession, WatchSpec, WatchState, WatchStatus, discover,
+};
+
+struct Watch {
+    session: WatchSession,
+    events: Receiver<WatchEvent>,
+    callback: Function,
+}
+
+#[derive(Default)]
+struct Watches {
+    next_id: u64,
+    sessions: BTreeMap<u64, Watch>,
+}
+
+// module
+//
+// The lua api of watch sessions:
+//
+// local id = watch.start({
+//   filepath = "pkg/calc/calc_test.go",
+//   framework = "GoTest",
+//   name = "TestAdd",         -- every test of the file when nil
+//   scope = "runnable",       -- or "file", "package"
+//   debounce_ms = 200,
+//   timeout_ms = 60000,
+// }, function(event) end)
+// watch.status(id) -- { state = "running", runnable_id = ..., runs = 2, last_code = 1 }
+// watch.stop(id)
+// watch.poll()
+//
+// Sessions run in threads of their own while lua may only be called from
+// the editor's, so their events wait until `poll`, driven by a timer, hands
+// them to the callbacks. Only the go frameworks built into examen can be
+// watched, lua and declarative ones living on the editor's thread. Runs go
+// through `jobs`, alongside those started with `examen.run`.
+pub(crate) fn module(lua: &Lua, jobs: ExecutionRegistry) -> mlua::Result<Table> {
+    let watches = Rc::new(RefCell::new(Watches::default()));
+    let res = lua.create_table()?;
+
+    let state = watches.clone();
+    res.set(
+        "start",
+        lua.create_function(move |_, (table, callback): (Table, Function)| {
+            let watch = start(table, callback, jobs.clone())?;
+            let mut watches = state.borrow_mut();
+            watches.next_id += 1;
+            let id = watches.next_id;
+            watches.sessions.insert(id, watch);
+            Ok(id)
+        })?,
+    )?;
+
+    let state = watches.clone();
+    res.set(
+        "stop",
+        lua.create_function(move |_, id: u64| {
+            let watch = state.borrow_mut().sessions.remove(&id);
+            Ok(watch.map(|mut w| w.session.stop()).is_some())
+        })?,
+    )?;
+
+    let state = watches.clone();
+    res.set(
+        "status",
+        lua.create_function(move |lua, id: u64| match state.borrow().sessions.get(&id) {
+            Some(watch) => status_table(lua, &watch.session.status()).map(Some),
+            None => Ok(None),
+        })?,
+    )?;
+
+    let state = watches;
+    res.set(
+        "poll",
+        lua.create_function(move |lua, ()| {
+            // callbacks may stop sessions, they run once the state is released
+            let mut pending = vec![];
+            for (id, watch) in state.borrow().sessions.iter() {
+                for event in watch.events.try_iter() {
+                    pending.push((watch.callback.clone(), event_table(lua, *id, event)?));
+                }
+            }
+            let res = pending.len();
+            for (callback, event) in pending {
+                callback.call::<()>(event)?;
+            }
+            Ok(res)
+        })?,
+    )?;
+    Ok(res)
+}
+
+fn start(table: Table, callback: Function, jobs: ExecutionRegistry) -> mlua::Result<Watch> {
+    let filepath: String = table.get("filepath")?;
+    let framework: String = table.get("framework")?;
+    let name: Option<String> = table.get("name")?;
+    let scope = match table.get::<Option<String>>("scope")?.as_deref() {
+        None | Some("runnable") if name.is_some() => WatchScope::Runnable,
+        None | Some("file") => WatchScope::File,
+        Some("package") => WatchScope::Package,
+        Some("runnable") => return Err(error("scope `runnable` requires a `name`")),
+        Some(scope) => {
+            return Err(error(format!(
+                "unknown scope `{}`, expected `runnable`, `file` or `package`",
+                scope
+            )));
+        }
+    };
+
+    let mut providers = builtin_providers();
+    let Some(index) = providers.iter().position(|p| p.name() == framework) else {
+        return Err(error(format!(
+            "`{}` is not a framework built into examen",
+            framework
+        )));
+    };
+    // only the go files of a package are watched, see `snapshot`
+    if providers[index].language() != Language::Golang {
+        return Err(error(format!(
+            "`{}` cannot be watched, only go frameworks can",
+            framework
+        )));
+    }
+    let runnables = discover(providers.swap_remove(index).create().as_ref(), &filepath)
+        .map_err(mlua::Error::external)?;
+    let runnable = match &name {
+        Some(name) => runnables.into_iter().find(|r| &r.name == name),
+        None => runnables.into_iter().next(),
+    }
+    .ok_or_else(|| match &name {
+        Some(name) => error(format!("no test `{}` in {}", name, filepath)),
+        None => error(format!("no tests in {}", filepath)),
+    })?;
+
+    let mut spec = WatchSpec::new(runnable, scope);
+    if let Some(debounce) = table.get::<Option<u64>>("debounce_ms")? {
+        spec.debounce = Duration::from_millis(debounce);
+    }
+    spec.options.timeout = table
+        .get::<Option<u64>>("timeout_ms")?
+        .map(Duration::from_millis);
+    let config = Config::discover(Path::new(&filepath), &TrustDatabase::open())
+        .map_err(mlua::Error::external)?;
+    spec.config = Some(config);
+
+    let (sender, events) = mpsc::channel();
+    // providers are rebuilt on the thread of the session
+    let session = WatchSession::start(
+        spec,
+        Box::new(move || builtin_providers().swap_remove(index).create()),
+        jobs,
+        Box::new(move |event| {
+            let _ = sender.send(event);
+        }),
+    )
+    .map_err(mlua::Error::external)?;
+    Ok(Watch {
+        session,
+        events,
+        callback,
+    })
+}
+
+fn error(message: impl Into<String>) -> mlua::Error {
+    mlua::Error::RuntimeError(message.into())
+}
+
+fn status_table(lua: &Lua, status: &WatchStatus) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+    let state = match status.state {
+        WatchState::Idle => "idle",
+        WatchState::Running => "running",
+        WatchState::Stopped => "stopped",
+    };
+    res.set("state", state)?;
+    res.set("runnable_id", status.runnable_id.as_str())?;
+    res.set("runs", status.runs)?;
+    res.set("last_code", status.last_code)?;
+    Ok(res)
+}
+
+fn event_table(lua: &Lua, id: u64, event: WatchEvent) -> mlua::Result<Table> {
+    let res = lua.create_table()?;
+    res.set("watch", id)?;
+    match event {
+        WatchEvent::Started {
+            run,
+            runnable_id,
+            command,
+        } => {
+            res.set("kind", "started")?;
+            res.set("run", run)?;
+            res.set("runnable_id", runnable_id)?;
+            res.set("command", command)?;
+        }
+        WatchEvent::Output(line) => {
+            res.set("kind", "output")?;
+            let stream = match line.stream {
+                Stream::Stdout => "stdout",
+                Stream::Stderr => "stderr",
+            };
+            res.set("stream", stream)?;
+            res.set("line", line.line)?;
+        }
+        WatchEvent::Finished { run, result } => {
+            res.set("kind", "finished")?;
+            res.set("run", run)?;
+            match result {
+                Ok(status) => res.set("code", status.code())?,
+                Err(e) => res.set("error", e.to_string())?,
+            }
+        }
+        WatchEvent::Renamed { from, to } => {
+            res.set("kind", "renamed")?;
+            res.set("from", from)?;
+            res.set("to", to)?;
+        }
+        WatchEvent::Error(message) => {
+            res.set("kind", "error")?;
+            res.set("message", message)?;
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use googletest::prelude::*;
+    use mlua::{Lua, Table};
+
+    use super::module;
+    use crate::core::config::ExecutorConfig;
+    use crate::terminal::backend::ProcessExecutor;
+    use crate::terminal::jobs::ExecutionRegistry;
+
+    fn fixture(path: &str) -> String {
+        format!(
+            "{}/lib/fixtures/declarative/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            path
+        )
+    }
+
+    fn jobs() -> ExecutionRegistry {
+        ExecutionRegistry::new(Box::new(ProcessExecutor), &ExecutorConfig::default())
+    }
+
+    #[gtest]
+    fn controls_sessions_from_lua() {
+        // arrange
+        let lua = Lua::new();
+        lua.globals()
+            .set("watch", module(&lua, jobs()).unwrap())
+            .unwrap();
+        lua.globals()
+            .set("filepath", fixture("suite/pkg/calculator/suite_test.go"))
+            .unwrap();
+        lua.load(
+            r#"
+            events = {}
+            id = watch.start({
+              filepath = filepath,
+              framework = "GoTest",
+              name = "TestCalculator",
+              timeout_ms = 10000,
+            }, function(event) table.insert(events, event.kind) end)
+            "#,
+        )
+        .exec()
+        .unwrap();
+
+        // act
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_secs(15) {
+            lua.load("watch.poll()").exec().unwrap();
+            let events: Vec<String> = lua.globals().get("events").unwrap();
+            if events.contains(&"finished".to_string()) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        // assert
+        let events: Vec<String> = lua.globals().get("events").unwrap();
+        assert_that!(events.first(), some(eq("started")));
+        assert_that!(events.last(), some(eq("finished")));
+        let status: Table = lua.load("watch.status(id)").eval().unwrap();
+        assert_that!(status.get::<usize>("runs").unwrap(), eq(1));
+        assert_that!(
+            status.get::<String>("runnable_id").unwrap(),
+            ends_with("suite_test.go::TestCalculator")
+        );
+        assert_that!(lua.load("watch.stop(id)").eval::<bool>().unwrap(), eq(true));
+        assert_that!(
+            lua.load("watch.stop(id)").eval::<bool>().unwrap(),
+            eq(false)
+        );
+        assert_that!(
+            lua.load("watch.status(id)")
+                .eval::<Option<Table>>()
+                .unwrap(),
+            none()
+        );
+    }
+
+    #[gtest]
+    fn rejects_unknown_framework() {
+        // arrange
+        let lua = Lua::new();
+        lua.globals()
+            .set("watch", module(&lua, jobs()).unwrap())
+            .unwrap();
+
+        // act
+        let res = lua
+            .load(r#"watch.start({ filepath = "a_test.go", framework = "Scripted" }, print)"#)
+            .exec();
+
+        // assert
+        assert_that!(
+            res.unwrap_err().to_string(),
+            contains_substring("`Scripted` is not a framework built into examen")
+        );
+    }
+
+    #[gtest]
+    fn rejects_frameworks_not_testing_go() {
+        // arrange
+        let lua = Lua::new();
+        lua.globals()
+            .set("watch", module(&lua, jobs()).unwrap())
+            .unwrap();
+
+        // act
+        let res = lua
+            .load(r#"watch.start({ filepath = "test_app.py", framework = "Pytest" }, print)"#)
+            .exec();
+
+        // assert
+        assert_that!(
+            res.unwrap_err().to_string(),
+            contains_substring("`Pytest` cannot be watched, only go frameworks can")
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod lua;
+pub(crate) mod session;
@@ -0,0 +1,741 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::core::config::Config;
+use crate::core::errors::{ExecutionError, FrameworkError};
+use crate::core::traits::Framework;
+use crate::core::types::Runnable;
+use crate::framework::golang::operations::get_package_directory;
+use crate::terminal::backend::executor;
+use crate::terminal::core::TerminalExecution;
+use crate::terminal::executor::{ExecutorOptions, OutputLine};
+use crate::terminal::jobs::{ExecutionRegistry, JobId, JobRequest};
+
+// DEFAULT_DEBOUNCE
+//
+// How long the sources must stay untouched before a rerun, editors and
+// formatters writing a file several times in a row.
+pub(crate) const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+// SCAN_INTERVAL
+//
+// How often the watched directory is scanned for changes.
+pub(crate) const SCAN_INTERVAL: Duration = Duration::from_millis(50);
+
+pub type FrameworkFactory = Box<dyn FnOnce() -> Box<dyn Framework> + Send>;
+
+pub type EventCallback = Box<dyn FnMut(WatchEvent) + Send>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WatchScope {
+    // the test alone, followed across renames
+    #[default]
+    Runnable,
+    // every test of its file
+    File,
+    // every test of its directory, go's package
+    Package,
+}
+
+impl WatchScope {
+    // target
+    //
+    // The runnable a run of the scope executes, the file or the package
+    // directory of `runnable` standing for every test it holds. The package
+    // is in the form `go test` takes, see `get_package_directory`.
+    fn target(&self, runnable: &Runnable) -> Runnable {
+        let mut res = runnable.clone();
+        match self {
+            WatchScope::Runnable => {}
+            WatchScope::File => res.name = String::new(),
+            WatchScope::Package => {
+                res.name = String::new();
+                res.filepath = get_package_directory::op::execute(&runnable.filepath);
+            }
+        }
+        res
+    }
+}
+
+fn parent(filepath: &str) -> PathBuf {
+    match Path::new(filepath).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WatchSpec {
+    pub runnable: Runnable,
+    pub scope: WatchScope,
+    pub debounce: Duration,
+    pub options: ExecutorOptions,
+    // the configuration of the watched file, giving runs their working
+    // directory, environment and executor, those of the registry when unset
+    pub config: Option<Config>,
+}
+
+impl WatchSpec {
+    pub fn new(runnable: Runnable, scope: WatchScope) -> Self {
+        Self {
+            runnable,
+            scope,
+            debounce: DEFAULT_DEBOUNCE,
+            options: ExecutorOptions::default(),
+            config: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WatchEvent {
+    Started {
+        run: usize,
+        runnable_id: String,
+        command: String,
+    },
+    Output(OutputLine),
+    Finished {
+        run: usize,
+        result: Result<ExitStatus, ExecutionError>,
+    },
+    // the watched test was renamed in its file
+    Renamed {
+        from: String,
+        to: String,
+    },
+    Error(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WatchState {
+    Idle,
+    Running,
+    Stopped,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchStatus {
+    pub state: WatchState,
+    pub runnable_id: String,
+    pub runs: usize,
+    // exit code of the last run that completed
+    pub last_code: Option<i32>,
+}
+
+// WatchSession
+//
+// Reruns a runnable whenever the files of its directory change, from a
+// thread of its own. Hidden files and editor backups are ignored, and
+// changes are debounced so a burst of writes triggers a single run; a run
+// still going when the next one is due is cancelled. Runs are submitted to
+// the registry, following its policy towards the other runs of the editor. When the test file
+// itself changes its runnables are discovered again, a renamed test being
+// found back by its position.
+pub struct WatchSession {
+    stopped: Arc<AtomicBool>,
+    status: Arc<Mutex<WatchStatus>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl WatchSession {
+    pub fn start(
+        spec: WatchSpec,
+        framework: FrameworkFactory,
+        jobs: ExecutionRegistry,
+        on_event: EventCallback,
+    ) -> Result<Self, ExecutionError> {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let status = Arc::new(Mutex::new(WatchStatus {
+            state: WatchState::Idle,
+            runnable_id: spec.scope.target(&spec.runnable).id(),
+            runs: 0,
+            last_code: None,
+        }));
+        let watcher = Watcher {
+            directory: parent(&spec.runnable.filepath),
+            scope: spec.scope,
+            runnable: spec.runnable,
+            debounce: spec.debounce,
+            options: spec.options,
+            config: spec.config,
+            jobs,
+            on_event,
+            stopped: stopped.clone(),
+            status: status.clone(),
+        };
+        let handle = thread::Builder::new()
+            .name(format!("examen watch: {}", watcher.runnable.id()))
+            .spawn(move || watcher.run(framework()))
+            .map_err(|e| ExecutionError::SpawnError(format!("failed to start watching: {}", e)))?;
+        Ok(Self {
+            stopped,
+            status,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn status(&self) -> WatchStatus {
+        self.status
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    // stop
+    //
+    // Stops watching, cancelling the run in progress, and waits for the
+    // thread to finish.
+    pub fn stop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for WatchSession {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// follow
+//
+// The runnable among `runnables` standing for `runnable`: the one of the
+// same name, or when renamed the innermost one around its former position.
+pub(crate) fn follow(runnable: &Runnable, runnables: Vec<Runnable>) -> Option<Runnable> {
+    if let Some(res) = runnables.iter().find(|r| r.name == runnable.name) {
+        return Some(res.clone());
+    }
+    let row = runnable.range.start.row;
+    runnables
+        .into_iter()
+        .filter(|r| r.range.start.row <= row && row <= r.range.end.row)
+        .max_by_key(|r| r.range.start.row)
+}
+
+// discover
+//
+// Every runnable of the file.
+pub(crate) fn discover(
+    framework: &dyn Framework,
+    filepath: &str,
+) -> Result<Vec<Runnable>, FrameworkError> {
+    let content = fs::read_to_string(filepath)
+        .map_err(|e| FrameworkError::NotFoundError(format!("{}: {}", filepath, e)))?;
+    let res = framework.all_runnables(filepath, &content);
+    if res.is_empty() {
+        return Err(FrameworkError::NotFoundError(format!(
+            "no tests in {}",
+            filepath
+        )));
+    }
+    Ok(res)
+}
+
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+// snapshot
+//
+// Modification time and size of the files the tests of the package depend
+// on: its go files, the module files next to them and its testdata.
+fn snapshot(directory: &Path) -> Snapshot {
+    let mut res = Snapshot::new();
+    let Ok(entries) = fs::read_dir(directory) else {
+        return res;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() && name == "testdata" {
+            res.extend(testdata(&entry.path()));
+        } else if metadata.is_file()
+            && !name.starts_with('.')
+            && (name.ends_with(".go") || name == "go.mod" || name == "go.sum")
+            && let Ok(modified) = metadata.modified()
+        {
+            res.insert(entry.path(), (modified, metadata.len()));
+        }
+    }
+    res
+}
+
+// testdata
+//
+// Modification time and size of every file below the testdata directory.
+fn testdata(directory: &Path) -> Snapshot {
+    let mut res = Snapshot::new();
+    let Ok(entries) = fs::read_dir(directory) else {
+        return res;
+    };
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            res.extend(testdata(&entry.path()));
+        } else if let Ok(modified) = metadata.modified() {
+            res.insert(entry.path(), (modified, metadata.len()));
+        }
+    }
+    res
+}
+
+// Channels
+//
+// The output and the exit of the runs of a watcher.
+struct Channels {
+    sender: mpsc::Sender<OutputLine>,
+    lines: Receiver<OutputLine>,
+    exit: mpsc::Sender<Result<ExitStatus, ExecutionError>>,
+    exits: Receiver<Result<ExitStatus, ExecutionError>>,
+}
+
+struct Watcher {
+    directory: PathBuf,
+    scope: WatchScope,
+    // the test file, or one of its tests when followed
+    runnable: Runnable,
+    debounce: Duration,
+    options: ExecutorOptions,
+    config: Option<Config>,
+    jobs: ExecutionRegistry,
+    on_event: EventCallback,
+    stopped: Arc<AtomicBool>,
+    status: Arc<Mutex<WatchStatus>>,
+}
+
+impl Watcher {
+    fn run(mut self, framework: Box<dyn Framework>) {
+        let (sender, lines) = mpsc::channel();
+        let (exit, exits) = mpsc::channel();
+        let channels = Channels {
+            sender,
+            lines,
+            exit,
+            exits,
+        };
+        let mut files = snapshot(&self.directory);
+        // the first run starts right away
+        let mut due: Option<(Instant, bool)> = Some((Instant::now() - self.debounce, false));
+        let mut current: Option<(usize, JobId)> = None;
+        loop {
+            self.forward(&channels.lines);
+            if current.is_some()
+                && let Ok(result) = channels.exits.try_recv()
+            {
+                let (run, _) = current.take().unwrap();
+                self.finish(run, result, &channels.lines);
+            }
+            if self.stopped.load(Ordering::SeqCst) {
+                if let Some(current) = current.take() {
+                    self.cancel(current, &channels);
+                }
+                self.update(|s| s.state = WatchState::Stopped);
+                return;
+            }
+
+            let next = snapshot(&self.directory);
+            if next != files {
+                let test_file = match Path::new(&self.runnable.filepath).file_name() {
+                    Some(name) => self.directory.join(name),
+                    None => self.directory.clone(),
+                };
+                let rediscover = files.get(&test_file) != next.get(&test_file);
+                let rediscover = rediscover || due.is_some_and(|(_, r)| r);
+                due = Some((Instant::now(), rediscover));
+                files = next;
+            }
+
+            if let Some((changed, rediscover)) = due
+                && changed.elapsed() >= self.debounce
+            {
+                due = None;
+                if let Some(current) = current.take() {
+                    self.cancel(current, &channels);
+                }
+                if rediscover
+                    && self.scope == WatchScope::Runnable
+                    && !self.rediscover(framework.as_ref())
+                {
+                    continue;
+                }
+                current = self.start(framework.as_ref(), &channels);
+            }
+            thread::sleep(SCAN_INTERVAL);
+        }
+    }
+
+    // rediscover
+    //
+    // Finds the runnable back in its changed file, false when it is gone.
+    fn rediscover(&mut self, framework: &dyn Framework) -> bool {
+        let found = discover(framework, &self.runnable.filepath)
+            .ok()
+            .and_then(|runnables| follow(&self.runnable, runnables));
+        let Some(found) = found else {
+            (self.on_event)(WatchEvent::Error(format!(
+                "`{}` is no longer in {}",
+                self.runnable.name, self.runnable.filepath
+            )));
+            return false;
+        };
+        if found.name != self.runnable.name {
+            (self.on_event)(WatchEvent::Renamed {
+                from: self.runnable.name.to_string(),
+                to: found.name.to_string(),
+            });
+        }
+        self.runnable = found;
+        let id = self.runnable.id();
+        self.update(|s| s.runnable_id = id);
+        true
+    }
+
+    fn start(&mut self, framework: &dyn Framework, channels: &Channels) -> Option<(usize, JobId)> {
+        let target = self.scope.target(&self.runnable);
+        let runnable_id = target.id();
+        let mut command = framework.generate_command(target);
+        if let Some(config) = &self.config {
+            command = command.with_config(config);
+        }
+        let execution = TerminalExecution::from(command);
+        let mut run = 0;
+        self.update(|s| {
+            s.runs += 1;
+            s.state = WatchState::Running;
+            run = s.runs;
+        });
+        (self.on_event)(WatchEvent::Started {
+            run,
+            runnable_id,
+            command: execution.to_string(),
+        });
+        let (sender, exit) = (channels.sender.clone(), channels.exit.clone());
+        // the package of the watched file, that of a package target being
+        // the directory above
+        let mut request = JobRequest::new(&self.runnable, execution)
+            .with_options(self.options.clone())
+            .with_on_line(Box::new(move |line| {
+                let _ = sender.send(line);
+            }))
+            .with_on_exit(Box::new(move |result| {
+                let _ = exit.send(result);
+            }));
+        if let Some(config) = &self.config {
+            request = request.with_executor(executor(&config.executor));
+        }
+        match self.jobs.submit(request) {
+            Ok(id) => Some((run, id)),
+            Err(e) => {
+                self.update(|s| s.state = WatchState::Idle);
+                (self.on_event)(WatchEvent::Finished {
+                    run,
+                    result: Err(e),
+                });
+                None
+            }
+        }
+    }
+
+    // cancel
+    //
+    // Cancels the run in progress and waits for it to end.
+    fn cancel(&mut self, (run, id): (usize, JobId), channels: &Channels) {
+        self.jobs.cancel(id);
+        let result = channels.exits.recv().unwrap_or_else(|_| {
+            Err(ExecutionError::CancelledError(format!(
+                "run {} was dropped",
+                run
+            )))
+        });
+        self.finish(run, result, &channels.lines);
+    }
+
+    fn finish(
+        &mut self,
+        run: usize,
+        result: Result<ExitStatus, ExecutionError>,
+        lines: &Receiver<OutputLine>,
+    ) {
+        self.forward(lines);
+        let code = result.as_ref().ok().and_then(|s| s.code());
+        self.update(|s| {
+            s.state = WatchState::Idle;
+            if code.is_some() {
+                s.last_code = code;
+            }
+        });
+        (self.on_event)(WatchEvent::Finished { run, result });
+    }
+
+    fn forward(&mut self, lines: &Receiver<OutputLine>) {
+        for line in lines.try_iter() {
+            (self.on_event)(WatchEvent::Output(line));
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut WatchStatus)) {
+        f(&mut self.status.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::{self, Receiver};
+    use std::time::Duration;
+
+    use googletest::prelude::*;
+
+    use rstest::rstest;
+
+    use super::{WatchEvent, WatchScope, WatchSession, WatchSpec, WatchState, discover};
+    use crate::core::config::{ConfigSource, ExecutorConfig};
+    use crate::core::errors::FrameworkError;
+    use crate::core::metadata::RunnableMeta;
+    use crate::core::traits::Framework;
+    use crate::core::types::{CapabilityDetails, Command, CursorPosition, Runnable, Target};
+    use crate::framework::golang::gotest::GotestProvider;
+    use crate::terminal::backend::ProcessExecutor;
+    use crate::terminal::jobs::ExecutionRegistry;
+
+    // EchoFramework
+    //
+    // Finds go tests but runs `echo <test>` instead of `go test`.
+    struct EchoFramework(GotestProvider);
+
+    impl Framework for EchoFramework {
+        fn detect(&self, target: &Target) -> bool {
+            self.0.detect(target)
+        }
+        fn runnables(&self, target: &Target) -> std::result::Result<Vec<Runnable>, FrameworkError> {
+            self.0.runnables(target)
+        }
+        fn all_runnables(&self, filepath: &str, content: &str) -> Vec<Runnable> {
+            self.0.all_runnables(filepath, content)
+        }
+        fn generate_command(&self, runnable: Runnable) -> Command {
+            Command {
+                command: "echo".to_string(),
+                args: vec![runnable.name],
+                ..Default::default()
+            }
+        }
+        fn capabilities(&self) -> HashSet<CapabilityDetails> {
+            self.0.capabilities()
+        }
+        fn search_for_capability(&self, description: &str) -> Option<CapabilityDetails> {
+            self.0.search_for_capability(description)
+        }
+    }
+
+    const TEST_FILE: &str =
+        "package calc\n\nimport \"testing\"\n\nfunc TestAdd(t *testing.T) {\n\tt.Log(\"add\")\n}\n";
+
+    fn package(name: &str) -> PathBuf {
+        let res =
+            std::env::temp_dir().join(format!("examen-watch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&res);
+        fs::create_dir_all(&res).unwrap();
+        fs::write(res.join("calc_test.go"), TEST_FILE).unwrap();
+        fs::write(res.join("calc.go"), "package calc\n").unwrap();
+        res
+    }
+
+    fn start(directory: &Path) -> (WatchSession, Receiver<WatchEvent>) {
+        let filepath = directory.join("calc_test.go").display().to_string();
+        let runnable = discover(
+            &EchoFramework(GotestProvider::with_config_source(ConfigSource::default())),
+            &filepath,
+        )
+        .unwrap()
+        .remove(0);
+        let mut spec = WatchSpec::new(runnable, WatchScope::Runnable);
+        spec.debounce = Duration::from_millis(150);
+        let (sender, events) = mpsc::channel();
+        let session = WatchSession::start(
+            spec,
+            Box::new(|| {
+                Box::new(EchoFramework(GotestProvider::with_config_source(
+                    ConfigSource::default(),
+                )))
+            }),
+            ExecutionRegistry::new(Box::new(ProcessExecutor), &ExecutorConfig::default()),
+            Box::new(move |event| {
+                let _ = sender.send(event);
+            }),
+        )
+        .unwrap();
+        (session, events)
+    }
+
+    // until_finished
+    //
+    // The events up to the end of the next run, in short.
+    fn until_finished(events: &Receiver<WatchEvent>) -> Vec<String> {
+        let mut res = vec![];
+        loop {
+            let event = events.recv_timeout(Duration::from_secs(10)).unwrap();
+            let (summary, finished) = match event {
+                WatchEvent::Started {
+                    run,
+                    runnable_id,
+                    command,
+                } => (
+                    format!("started {} {} `{}`", run, runnable_id, command),
+                    false,
+                ),
+                WatchEvent::Output(line) => (format!("output {}", line.line), false),
+                WatchEvent::Finished { run, result } => (
+                    format!("finished {} {:?}", run, result.map(|s| s.code())),
+                    true,
+                ),
+                WatchEvent::Renamed { from, to } => (format!("renamed {} {}", from, to), false),
+                WatchEvent::Error(message) => (format!("error {}", message), false),
+            };
+            res.push(summary);
+            if finished {
+                return res;
+            }
+        }
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case(WatchScope::Runnable, "pkg/calc/calc_test.go", "TestAdd")]
+    #[case(WatchScope::File, "pkg/calc/calc_test.go", "")]
+    #[case(WatchScope::Package, "./pkg/calc", "")]
+    fn scope_targets(#[case] scope: WatchScope, #[case] filepath: &str, #[case] name: &str) {
+        // arrange
+        let runnable = Runnable {
+            name: "TestAdd".to_string(),
+            filepath: "pkg/calc/calc_test.go".to_string(),
+            range: CursorPosition::new(4, 0)..CursorPosition::new(6, 1),
+            meta: RunnableMeta::default_golang(),
+        };
+
+        // act
+        let res = scope.target(&runnable);
+
+        // assert
+        assert_that!(res.filepath, eq(filepath));
+        assert_that!(res.name, eq(name));
+    }
+
+    #[gtest]
+    fn reruns_once_per_burst_of_writes() {
+        // arrange
+        let directory = package("burst");
+        let (mut session, events) = start(&directory);
+        let first = until_finished(&events);
+
+        // act
+        for i in 0..3 {
+            fs::write(
+                directory.join("calc.go"),
+                format!("package calc\n// {}\n", i),
+            )
+            .unwrap();
+            std::thread::sleep(Duration::from_millis(30));
+        }
+        let second = until_finished(&events);
+        let idle = events.recv_timeout(Duration::from_millis(500));
+        session.stop();
+
+        // assert
+        let id = format!("{}::TestAdd", directory.join("calc_test.go").display());
+        assert_that!(
+            first,
+            container_eq(vec![
+                format!("started 1 {} `echo TestAdd`", id),
+                "output TestAdd".to_string(),
+                "finished 1 Ok(Some(0))".to_string(),
+            ])
+        );
+        assert_that!(
+            second,
+            container_eq(vec![
+                format!("started 2 {} `echo TestAdd`", id),
+                "output TestAdd".to_string(),
+                "finished 2 Ok(Some(0))".to_string(),
+            ])
+        );
+        assert_that!(idle.is_err(), eq(true));
+        let status = session.status();
+        assert_that!(status.state, eq(WatchState::Stopped));
+        assert_that!(status.runs, eq(2));
+        assert_that!(status.last_code, some(eq(0)));
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[gtest]
+    #[rstest]
+    #[case("notes.md", false)]
+    #[case(".calc.go.swp", false)]
+    #[case("calc.go~", false)]
+    #[case("go.mod", true)]
+    #[case("testdata/input.txt", true)]
+    fn reruns_on_package_files(#[case] file: &str, #[case] reruns: bool) {
+        // arrange
+        let directory = package(&format!("files-{}", file.replace('/', "-")));
+        fs::create_dir_all(directory.join("testdata")).unwrap();
+        let (mut session, events) = start(&directory);
+        until_finished(&events);
+
+        // act
+        fs::write(directory.join(file), "changed\n").unwrap();
+        let res = events.recv_timeout(Duration::from_millis(1000));
+        session.stop();
+
+        // assert
+        assert_that!(
+            matches!(res, Ok(WatchEvent::Started { run: 2, .. })),
+            eq(reruns)
+        );
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[gtest]
+    fn follows_renamed_test() {
+        // arrange
+        let directory = package("rename");
+        let (mut session, events) = start(&directory);
+        until_finished(&events);
+
+        // act
+        fs::write(
+            directory.join("calc_test.go"),
+            TEST_FILE.replace("TestAdd", "TestSum"),
+        )
+        .unwrap();
+        let res = until_finished(&events);
+        session.stop();
+
+        // assert
+        let id = format!("{}::TestSum", directory.join("calc_test.go").display());
+        assert_that!(
+            res,
+            container_eq(vec![
+                "renamed TestAdd TestSum".to_string(),
+                format!("started 2 {} `echo TestSum`", id),
+                "output TestSum".to_string(),
+                "finished 2 Ok(Some(0))".to_string(),
+            ])
+        );
+        assert_that!(session.status().runnable_id, ends_with("::TestSum"));
+        let _ = fs::remove_dir_all(&directory);
+    }
+}